@@ -1,65 +1,132 @@
 use std::{
     any::Any,
     cell::{ Ref },
-    collections::{
-        HashMap,
-    },
 };
 
 use crate::{
     core::{
         ecs::{
             containers::{
-                SystemDataContainer
+                ComponentAccess,
+                SystemDataContainer,
+                SystemTicks
             },
-            Entity,
-            EntityId,
+            storage::ArchetypeStorage,
+            Commands,
+            ParallelCommands,
+            ParallelSystem,
+            ParallelSystemContext,
+            Resources,
             System,
+            SystemContext,
         },
         GameState
     }
 };
 
+pub(super) trait SystemJob : Send {
+    fn run(&mut self);
+    fn give_back(self: Box<Self>, storage: &mut ArchetypeStorage, resources: &mut Resources, commands: &mut Commands);
+}
+
+struct ParallelSystemJob<'s, U: SystemDataContainer + Send, T: ParallelSystem<DataType = U>> {
+    system: &'s mut T,
+    container: U,
+    commands: ParallelCommands
+}
+
+impl<'s, U: SystemDataContainer + Send, T: ParallelSystem<DataType = U>> SystemJob for ParallelSystemJob<'s, U, T> {
+    fn run(&mut self) {
+        let mut context = ParallelSystemContext::new(&mut self.commands);
+        self.system.run(&mut self.container, &mut context);
+    }
+
+    fn give_back(mut self: Box<Self>, storage: &mut ArchetypeStorage, resources: &mut Resources, commands: &mut Commands) {
+        // return each borrowed column to it's own archetype
+        self.container.give_back(storage);
+        self.container.give_back_resources(resources);
+        self.commands.append_to(commands);
+    }
+}
+
+type JobPreparer = for<'s> fn(&'s mut Box<dyn Any>, &mut ArchetypeStorage, &mut Resources, &Commands, SystemTicks) -> Box<dyn SystemJob + 's>;
+
+type LocalRunner = Box<dyn FnMut(&mut Box<dyn Any>, &mut ArchetypeStorage, &mut Resources, SystemTicks, &mut SystemContext)>;
+
+type SystemSetup = Box<dyn FnMut(&mut Box<dyn Any>, &mut Ref<GameState>)>;
+
+enum SystemRunner {
+    Local(LocalRunner),
+    Parallel(JobPreparer)
+}
+
 pub struct AnySystem {
     sys: Option<Box<dyn Any>>,
-    setup: Box<dyn FnMut(&mut Box<dyn Any>, &mut Ref<GameState>)>,
-    runner: Box<dyn FnMut(&mut Box<dyn Any>, &mut HashMap<EntityId, Entity>, &mut Ref<GameState>)>
+    access: ComponentAccess,
+    last_run_tick: u64,
+    setup: SystemSetup,
+    runner: SystemRunner
 }
 
 impl AnySystem {
     pub fn new<U: 'static + SystemDataContainer, T: System<DataType = U> + Any>(system: T) -> AnySystem {
         AnySystem {
             sys: Some(Box::new(system)),
+            access: U::access(),
+            last_run_tick: 0,
             setup: Box::new(|s, game_utilities| {
                 match s.downcast_mut::<T>() {
                     Some(reconstructed_system) => reconstructed_system.setup(game_utilities),
                     None => panic!("Can't reconstruct system type.")
                 };
             }),
-            runner: Box::new(|s, entities, game_utilities| {
+            runner: SystemRunner::Local(Box::new(|s, storage, resources, ticks, context| {
                 let mut container = U::new();
+                container.prepare(storage, ticks);
 
                 // prepare container
-                for (entity_id, entity) in entities.iter_mut() {
-                    container.try_add(*entity_id, entity.get_mut_components());
+                for archetype in storage.archetypes_mut() {
+                    container.try_add(archetype);
                 }
 
+                container.take_resources(resources);
+
                 // call system
                 match s.downcast_mut::<T>() {
-                    Some(reconstructed_system) => reconstructed_system.run(&mut container, game_utilities),
+                    Some(reconstructed_system) => reconstructed_system.run(&mut container, context),
                     None => panic!("Can't reconstruct system type.")
                 };
 
-                // return each borrowed components to it's own entity
-                for (entity_id, mut components) in container.drain() {
-                    match entities.get_mut(&entity_id) {
-                        Some(entity) => entity.get_mut_components()
-                                              .extend(components.drain(..)),
+                // return each borrowed column to it's own archetype
+                container.give_back(storage);
+                container.give_back_resources(resources);
+            }))
+        }
+    }
 
-                        None => eprintln!("Entity (with id: {}) not found! Can't return borrowed components to it's entity.", entity_id)
-                    };
-                }
-            })
+    pub fn new_parallel<U: 'static + SystemDataContainer + Send, T: ParallelSystem<DataType = U> + Any>(system: T) -> AnySystem {
+        AnySystem {
+            sys: Some(Box::new(system)),
+            access: U::access(),
+            last_run_tick: 0,
+            setup: Box::new(|s, game_utilities| {
+                match s.downcast_mut::<T>() {
+                    Some(reconstructed_system) => reconstructed_system.setup(game_utilities),
+                    None => panic!("Can't reconstruct system type.")
+                };
+            }),
+            runner: SystemRunner::Parallel(AnySystem::prepare_parallel_job::<U, T>)
+        }
+    }
+
+    pub fn access(&self) -> &ComponentAccess {
+        &self.access
+    }
+
+    pub fn is_parallel(&self) -> bool {
+        match self.runner {
+            SystemRunner::Local(_) => false,
+            SystemRunner::Parallel(_) => true
         }
     }
 
@@ -74,17 +141,46 @@ impl AnySystem {
         self.sys = sys;
     }
 
-    pub fn run<'a>(&mut self, entities: &'a mut HashMap<EntityId, Entity>, game_utilities: &mut Ref<GameState>) {
+    pub fn last_run_tick(&self) -> u64 {
+        self.last_run_tick
+    }
+
+    pub fn run(&mut self, storage: &mut ArchetypeStorage, resources: &mut Resources, context: &mut SystemContext) {
         let mut sys = self.sys.take();
+        let ticks = self.next_ticks(storage);
 
         match &mut sys {
-            Some(s) => (self.runner)(s, entities, game_utilities),
+            Some(s) => {
+                match &mut self.runner {
+                    SystemRunner::Local(runner) => runner(s, storage, resources, ticks, context),
+                    SystemRunner::Parallel(prepare) => {
+                        // alone, there is no need to leave current thread
+                        let mut job = prepare(s, storage, resources, context.commands(), ticks);
+                        job.run();
+                        job.give_back(storage, resources, context.commands());
+                    }
+                }
+            },
             None => panic!("Impossible to run, system is None.")
         }
 
         self.sys = sys;
     }
 
+    pub(super) fn prepare_job<'s>(&'s mut self, storage: &mut ArchetypeStorage, resources: &mut Resources, commands: &Commands) -> Box<dyn SystemJob + 's> {
+        let prepare = match self.runner {
+            SystemRunner::Parallel(prepare) => prepare,
+            SystemRunner::Local(_) => panic!("Impossible to prepare job, system can't run in parallel.")
+        };
+
+        let ticks = self.next_ticks(storage);
+
+        match &mut self.sys {
+            Some(s) => prepare(s, storage, resources, commands, ticks),
+            None => panic!("Impossible to prepare job, system is None.")
+        }
+    }
+
     pub fn get_underlying_system(&self) -> &dyn Any {
         match self.sys {
             Some(ref system) => system.as_ref(),
@@ -99,17 +195,46 @@ impl AnySystem {
         }
     }
 
-    pub fn get_system<T: 'static + System>(&self) -> Option<&T> {
+    pub fn get_system<T: 'static>(&self) -> Option<&T> {
         match self.sys {
             Some(ref system) => system.as_ref().downcast_ref::<T>(),
             None => panic!("Impossible to run, system is None.")
         }
     }
 
-    pub fn get_mut_system<T: 'static + System>(&mut self) -> Option<&mut T> {
+    pub fn get_mut_system<T: 'static>(&mut self) -> Option<&mut T> {
         match self.sys {
             Some(ref mut system) => system.as_mut().downcast_mut::<T>(),
             None => panic!("Impossible to run, system is None.")
         }
     }
+
+    fn next_ticks(&mut self, storage: &ArchetypeStorage) -> SystemTicks {
+        let ticks = SystemTicks::new(self.last_run_tick, storage.change_tick());
+        self.last_run_tick = ticks.current();
+        ticks
+    }
+
+    fn prepare_parallel_job<'s, U: 'static + SystemDataContainer + Send, T: ParallelSystem<DataType = U> + Any>(s: &'s mut Box<dyn Any>, storage: &mut ArchetypeStorage, resources: &mut Resources, commands: &Commands, ticks: SystemTicks) -> Box<dyn SystemJob + 's> {
+        let mut container = U::new();
+        container.prepare(storage, ticks);
+
+        // prepare container
+        for archetype in storage.archetypes_mut() {
+            container.try_add(archetype);
+        }
+
+        container.take_resources(resources);
+
+        match s.downcast_mut::<T>() {
+            Some(reconstructed_system) => {
+                Box::new(ParallelSystemJob {
+                    system: reconstructed_system,
+                    container,
+                    commands: ParallelCommands::forked_from(commands)
+                })
+            },
+            None => panic!("Can't reconstruct system type.")
+        }
+    }
 }
@@ -0,0 +1,159 @@
+use std::{
+    any,
+    sync::{
+        Arc,
+        Mutex
+    }
+};
+
+use crate::core::ecs::{
+    storage::ComponentBundle,
+    Component,
+    Entity,
+    EntityAllocator,
+    EntityError,
+    EntityId,
+    Realm
+};
+
+type Command = Box<dyn FnOnce(&mut Realm) -> Result<(), EntityError>>;
+
+pub struct Commands {
+    queue: Vec<Command>,
+    entity_allocator: Arc<Mutex<EntityAllocator>>
+}
+
+impl Commands {
+    pub(super) fn new(entity_allocator: Arc<Mutex<EntityAllocator>>) -> Commands {
+        Commands {
+            queue: Vec::new(),
+            entity_allocator
+        }
+    }
+
+    pub fn add<F: 'static + FnOnce(&mut Realm) -> Result<(), EntityError>>(&mut self, command: F) {
+        self.queue.push(Box::new(command));
+    }
+
+    pub fn spawn<'c>(&'c mut self) -> CommandEntityBuilder<'c> {
+        CommandEntityBuilder::new(self)
+    }
+
+    pub fn despawn(&mut self, entity_id: EntityId) {
+        self.add(move |realm| realm.despawn(entity_id));
+    }
+
+    pub fn despawn_recursive(&mut self, entity_id: EntityId) {
+        self.add(move |realm| realm.despawn_recursive(entity_id));
+    }
+
+    pub fn set_parent(&mut self, entity_id: EntityId, parent_id: EntityId) {
+        self.add(move |realm| realm.set_parent(entity_id, parent_id));
+    }
+
+    pub fn remove_parent(&mut self, entity_id: EntityId) {
+        self.add(move |realm| realm.remove_parent(entity_id));
+    }
+
+    pub fn add_component<T: Component + 'static>(&mut self, entity_id: EntityId, component: T) {
+        self.add(move |realm| realm.add_component(entity_id, component));
+    }
+
+    pub fn remove_component<T: Component + 'static>(&mut self, entity_id: EntityId) {
+        self.add(move |realm| realm.remove_component::<T>(entity_id));
+    }
+
+    pub fn insert_resource<T: 'static>(&mut self, resource: T) {
+        self.add(move |realm| {
+            realm.insert_resource(resource);
+            Ok(())
+        });
+    }
+
+    pub fn remove_resource<T: 'static>(&mut self) {
+        self.add(move |realm| {
+            realm.remove_resource::<T>();
+            Ok(())
+        });
+    }
+
+    pub fn send_event<T: 'static>(&mut self, event: T) {
+        self.add(move |realm| {
+            realm.send_event(event);
+            Ok(())
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// An empty queue which reserves entities from the same realm.
+    pub(super) fn fork(&self) -> Commands {
+        Commands::new(Arc::clone(&self.entity_allocator))
+    }
+
+    pub(super) fn append(&mut self, other: &mut Commands) {
+        self.queue.append(&mut other.queue);
+    }
+
+    pub(super) fn apply(&mut self, realm: &mut Realm) -> Vec<EntityError> {
+        self.queue.drain(..)
+                  .filter_map(|command| command(realm).err())
+                  .collect()
+    }
+
+    fn reserve_entity_id(&mut self) -> EntityId {
+        match self.entity_allocator.lock() {
+            Ok(mut entity_allocator) => entity_allocator.allocate(),
+            Err(_) => panic!("Entity allocator was poisoned.")
+        }
+    }
+}
+
+pub struct CommandEntityBuilder<'c> {
+    components: ComponentBundle,
+    commands: &'c mut Commands
+}
+
+impl<'c> CommandEntityBuilder<'c> {
+    pub fn new<'a>(commands: &'a mut Commands) -> CommandEntityBuilder<'a> {
+        CommandEntityBuilder {
+            components: ComponentBundle::new(),
+            commands
+        }
+    }
+
+    pub fn with_component<T: Component + 'static>(mut self, component: T) -> CommandEntityBuilder<'c> {
+        if self.components.has_component::<T>() {
+            panic!("Entity already has a component '{}'.", any::type_name::<T>());
+        }
+
+        self.components.push(component);
+        self
+    }
+
+    /// Entity id is reserved right away, so it can be used by other commands before it's spawned.
+    pub fn build(self) -> EntityId {
+        let entity_id = self.commands.reserve_entity_id();
+        let components = self.components;
+
+        self.commands.add(move |realm| {
+            let entity = Entity::with_components(entity_id, components);
+
+            match realm.add_entity(entity) {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    realm.free_entity_id(entity_id);
+                    Err(e)
+                }
+            }
+        });
+
+        entity_id
+    }
+}
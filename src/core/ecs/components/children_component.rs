@@ -0,0 +1,71 @@
+use std::{
+    any::Any,
+    slice::Iter
+};
+
+use crate::{
+    core::ecs::{
+        components::Updatable,
+        Component,
+        EntityId
+    }
+};
+
+pub struct ChildrenComponent {
+    children: Vec<EntityId>
+}
+
+impl Component for ChildrenComponent {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Updatable for ChildrenComponent {
+    fn before_update(&mut self) {
+    }
+
+    fn update(&mut self) {
+    }
+
+    fn late_update(&mut self) {
+    }
+}
+
+impl ChildrenComponent {
+    pub(in crate::core::ecs) fn new() -> ChildrenComponent {
+        ChildrenComponent {
+            children: Vec::new()
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, EntityId> {
+        self.children.iter()
+    }
+
+    pub fn contains(&self, entity_id: EntityId) -> bool {
+        self.children.contains(&entity_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub(in crate::core::ecs) fn push(&mut self, entity_id: EntityId) {
+        if !self.children.contains(&entity_id) {
+            self.children.push(entity_id);
+        }
+    }
+
+    pub(in crate::core::ecs) fn remove(&mut self, entity_id: EntityId) {
+        self.children.retain(|child| *child != entity_id);
+    }
+}
@@ -4,10 +4,6 @@ use crate::{
     core::ecs::{
         components::Updatable,
         Component
-    },
-    rendering::{
-        GraphicsDevice,
-        ResourceDisposable
     }
 };
 
@@ -35,15 +31,6 @@ impl Updatable for EmptyComponent {
     }
 }
 
-impl ResourceDisposable for EmptyComponent {
-    fn is_disposed(&self) -> bool {
-        false
-    }
-
-    fn dispose(&mut self, device: &GraphicsDevice) {
-    }
-}
-
 impl Drop for EmptyComponent {
     fn drop(&mut self) {
     }
@@ -0,0 +1,125 @@
+use std::any::Any;
+
+use crate::{
+    core::ecs::{
+        components::{
+            TransformComponent,
+            Updatable
+        },
+        Component
+    },
+    math::{
+        Vector2
+    }
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GlobalTransformComponent {
+    position: Vector2<f32>,
+    rotation: f32,
+    scale: Vector2<f32>
+}
+
+impl Component for GlobalTransformComponent {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Updatable for GlobalTransformComponent {
+    fn before_update(&mut self) {
+    }
+
+    fn update(&mut self) {
+    }
+
+    fn late_update(&mut self) {
+    }
+}
+
+impl Default for GlobalTransformComponent {
+    fn default() -> Self {
+        GlobalTransformComponent::new()
+    }
+}
+
+impl From<&TransformComponent> for GlobalTransformComponent {
+    fn from(transform: &TransformComponent) -> Self {
+        GlobalTransformComponent {
+            position: transform.position,
+            rotation: transform.rotation,
+            scale: transform.scale
+        }
+    }
+}
+
+impl GlobalTransformComponent {
+    pub fn new() -> GlobalTransformComponent {
+        GlobalTransformComponent {
+            position: Vector2::new(),
+            rotation: 0f32,
+            scale: Vector2::with(1f32, 1f32)
+        }
+    }
+
+    pub fn position(&self) -> Vector2<f32> {
+        self.position
+    }
+
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    pub fn scale(&self) -> Vector2<f32> {
+        self.scale
+    }
+
+    pub fn mul_transform(&self, local: &TransformComponent) -> GlobalTransformComponent {
+        let scaled = self.scale * local.position;
+        let (sin, cos) = self.rotation.sin_cos();
+
+        GlobalTransformComponent {
+            position: self.position + Vector2::with(
+                scaled.x() * cos - scaled.y() * sin,
+                scaled.x() * sin + scaled.y() * cos
+            ),
+            rotation: self.rotation + local.rotation,
+            scale: self.scale * local.scale
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    fn assert_near(expected: Vector2<f32>, actual: Vector2<f32>) {
+        assert!((expected.x() - actual.x()).abs() < 1e-4 && (expected.y() - actual.y()).abs() < 1e-4, "expected {:?}, got {:?}", expected, actual);
+    }
+
+    #[test]
+    fn child_is_scaled_rotated_then_translated_by_parent() {
+        let parent = GlobalTransformComponent::from(&TransformComponent::with(Vector2::with(10.0, 0.0), FRAC_PI_2, Vector2::with(2.0, 2.0)));
+        let child = TransformComponent::with(Vector2::with(1.0, 0.0), 0.5, Vector2::with(3.0, 1.0));
+
+        let global = parent.mul_transform(&child);
+
+        // (1, 0) scaled to (2, 0), rotated a quarter turn to (0, 2)
+        assert_near(Vector2::with(10.0, 2.0), global.position());
+        assert!((global.rotation() - (FRAC_PI_2 + 0.5)).abs() < 1e-6);
+        assert_eq!(Vector2::with(6.0, 2.0), global.scale());
+    }
+
+    #[test]
+    fn identity_parent_keeps_local_transform() {
+        let child = TransformComponent::with(Vector2::with(3.0, -4.0), 1.0, Vector2::with(0.5, 2.0));
+
+        assert_eq!(GlobalTransformComponent::from(&child), GlobalTransformComponent::new().mul_transform(&child));
+    }
+}
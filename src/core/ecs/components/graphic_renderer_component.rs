@@ -1,30 +1,29 @@
 use std::{
     any::Any,
-    borrow::BorrowMut,
-    cell::RefMut,
+    borrow::BorrowMut
 };
 
 use crate::{
     core::ecs::{
         components::Updatable,
+        serialization::{
+            SceneError,
+            SceneLoadContext,
+            SceneSaveContext,
+            Serializable
+        },
         Component
     },
     graphics::{
         Drawable,
-        Graphic
+        Graphic,
+        GraphicData
     },
-    rendering::{
-        GraphicsDevice,
-        Renderer,
-        ResourceDisposable,
-        panic_if_resource_isnt_disposed,
-        panic_if_resources_isnt_disposed
-    }
+    rendering::Renderer
 };
 
 pub struct GraphicRendererComponent {
-    graphics: Vec<Box<dyn Graphic>>,
-    disposed: bool
+    graphics: Vec<Box<dyn Graphic>>
 }
 
 impl Component for GraphicRendererComponent {
@@ -56,36 +55,43 @@ impl Drawable for GraphicRendererComponent {
     }
 }
 
-impl ResourceDisposable for GraphicRendererComponent {
-    fn is_disposed(&self) -> bool {
-        self.disposed
-    }
+impl Serializable for GraphicRendererComponent {
+    type Data = Vec<GraphicData>;
 
-    fn dispose(&mut self, device: &GraphicsDevice) {
-        if self.disposed {
-            return;
-        }
+    fn save(&self, _context: &SceneSaveContext) -> Result<Self::Data, SceneError> {
+        self.graphics
+            .iter()
+            .map(|graphic| {
+                graphic.graphic_data()
+                       .ok_or_else(|| SceneError::Unserializable(String::from("Graphic")))
+            })
+            .collect()
+    }
 
-        self.disposed = true;
+    fn load(data: Self::Data, context: &mut SceneLoadContext) -> Result<Self, SceneError> {
+        let mut component = GraphicRendererComponent::new();
 
-        for graphic in self.graphics.iter_mut() {
-            graphic.dispose(device);
+        for graphic_data in data.iter() {
+            match graphic_data.load(context.device()) {
+                Ok(graphic) => component.register(graphic),
+                Err(e) => return Err(SceneError::Texture(e))
+            }
         }
+
+        Ok(component)
     }
 }
 
-impl Drop for GraphicRendererComponent {
-    fn drop(&mut self) {
-        panic_if_resource_isnt_disposed!(self);
-        panic_if_resources_isnt_disposed!(self.graphics.iter());
+impl Default for GraphicRendererComponent {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl GraphicRendererComponent {
     pub fn new() -> GraphicRendererComponent {
         GraphicRendererComponent {
-            graphics: Vec::new(),
-            disposed: false
+            graphics: Vec::new()
         }
     }
 
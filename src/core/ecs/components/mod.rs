@@ -10,3 +10,13 @@ pub use graphic_renderer_component::GraphicRendererComponent;
 
 mod updatable;
 pub use updatable::Updatable;
+
+// hierarchy related
+mod global_transform_component;
+pub use global_transform_component::GlobalTransformComponent;
+
+mod parent_component;
+pub use parent_component::ParentComponent;
+
+mod children_component;
+pub use children_component::ChildrenComponent;
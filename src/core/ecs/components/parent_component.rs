@@ -0,0 +1,46 @@
+use std::any::Any;
+
+use crate::{
+    core::ecs::{
+        components::Updatable,
+        Component,
+        EntityId
+    }
+};
+
+pub struct ParentComponent {
+    parent: EntityId
+}
+
+impl Component for ParentComponent {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Updatable for ParentComponent {
+    fn before_update(&mut self) {
+    }
+
+    fn update(&mut self) {
+    }
+
+    fn late_update(&mut self) {
+    }
+}
+
+impl ParentComponent {
+    pub(in crate::core::ecs) fn new(parent: EntityId) -> ParentComponent {
+        ParentComponent {
+            parent
+        }
+    }
+
+    pub fn get(&self) -> EntityId {
+        self.parent
+    }
+}
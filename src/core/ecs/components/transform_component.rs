@@ -1,22 +1,38 @@
 use std::any::Any;
 
+use serde::{
+    Deserialize,
+    Serialize
+};
+
 use crate::{
     core::ecs::{
         components::Updatable,
+        serialization::{
+            SceneError,
+            SceneLoadContext,
+            SceneSaveContext,
+            Serializable
+        },
         Component
     },
     math::{
         Vector2
-    },
-    rendering::{
-        GraphicsDevice,
-        ResourceDisposable
     }
 };
 
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TransformComponent {
     pub position: Vector2<f32>,
-    pub rotation: f32
+    pub rotation: f32,
+    pub scale: Vector2<f32>
+}
+
+impl Default for TransformComponent {
+    fn default() -> Self {
+        TransformComponent::new()
+    }
 }
 
 impl Component for TransformComponent {
@@ -44,17 +60,15 @@ impl Updatable for TransformComponent {
     }
 }
 
-impl ResourceDisposable for TransformComponent {
-    fn is_disposed(&self) -> bool {
-        false
-    }
+impl Serializable for TransformComponent {
+    type Data = TransformComponent;
 
-    fn dispose(&mut self, device: &GraphicsDevice) {
+    fn save(&self, _context: &SceneSaveContext) -> Result<Self::Data, SceneError> {
+        Ok(self.clone())
     }
-}
 
-impl Drop for TransformComponent {
-    fn drop(&mut self) {
+    fn load(data: Self::Data, _context: &mut SceneLoadContext) -> Result<Self, SceneError> {
+        Ok(data)
     }
 }
 
@@ -62,7 +76,16 @@ impl TransformComponent {
     pub fn new() -> TransformComponent {
         TransformComponent {
             position: Vector2::new(),
-            rotation: 0f32
+            rotation: 0f32,
+            scale: Vector2::with(1f32, 1f32)
+        }
+    }
+
+    pub fn with(position: Vector2<f32>, rotation: f32, scale: Vector2<f32>) -> TransformComponent {
+        TransformComponent {
+            position,
+            rotation,
+            scale
         }
     }
 }
@@ -1,24 +1,26 @@
-use std::{
-    collections::{
-        hash_map::{
-            Drain,
-            Iter,
-            IterMut,
-            Values,
-            ValuesMut
-        },
-        HashMap,
-    }
-};
-
 use crate::core::ecs::{
-    containers::SystemDataContainer,
+    containers::{
+        ComponentAccess,
+        SystemDataContainer
+    },
+    storage::{
+        AnyComponentColumn,
+        Archetype,
+        ArchetypeId,
+        ArchetypeStorage
+    },
     Component,
     EntityId,
 };
 
+struct BorrowedColumns {
+    archetype: ArchetypeId,
+    entities: Vec<EntityId>,
+    columns: Vec<Box<dyn AnyComponentColumn>>
+}
+
 pub struct AnyDataContainer {
-    components: HashMap<EntityId, Vec<Box<dyn Component>>>,
+    archetypes: Vec<BorrowedColumns>,
 }
 
 impl SystemDataContainer for AnyDataContainer {
@@ -26,46 +28,110 @@ impl SystemDataContainer for AnyDataContainer {
 
     fn new() -> Self {
         AnyDataContainer {
-            components: HashMap::new()
+            archetypes: Vec::new()
         }
     }
 
-    fn try_add(&mut self, entity_id: EntityId, components: &mut Vec<Box<dyn Component>>) {
-        let mut captured_components = Vec::new();
-        captured_components.reserve_exact(components.len());
-        captured_components.extend(components.drain(..));
-        self.components.insert(entity_id, captured_components);
+    fn access() -> ComponentAccess {
+        // every column is borrowed, nothing else can run alongside it
+        ComponentAccess::exclusive()
     }
 
-    fn drain(&mut self) -> Drain<EntityId, Vec<Box<dyn Component>>> {
-        self.components.drain()
+    fn try_add(&mut self, archetype: &mut Archetype) {
+        if archetype.is_empty() {
+            return;
+        }
+
+        self.archetypes.push(BorrowedColumns {
+            archetype: archetype.id(),
+            entities: archetype.entities().to_vec(),
+            columns: archetype.take_any_columns()
+        });
     }
 
+    fn give_back(&mut self, storage: &mut ArchetypeStorage) {
+        for borrowed in self.archetypes.drain(..) {
+            match storage.archetype_mut(borrowed.archetype) {
+                Some(archetype) => {
+                    for column in borrowed.columns {
+                        archetype.give_back_column(column);
+                    }
+                },
+                None => panic!("Archetype {} not found! Can't give back borrowed columns.", borrowed.archetype)
+            }
+        }
+    }
 }
 
 impl AnyDataContainer {
-    pub fn get<'a>(&'a self, entity_id: EntityId) -> Result<&'a Vec<Box<dyn Component>>, &'static str> {
-        let boxed_components = match self.components.get(&entity_id) {
-            Some(c) => c,
-            None => return Err("Boxed component is empty.")
-        };
+    pub fn get(&self, entity_id: EntityId) -> Result<Vec<&dyn Component>, &'static str> {
+        for borrowed in self.archetypes.iter() {
+            if let Some(row) = borrowed.entities.iter().position(|id| *id == entity_id) {
+                return Ok(
+                    borrowed.columns
+                            .iter()
+                            .map(|column| column.get_component(row))
+                            .collect()
+                );
+            }
+        }
 
-        Ok(boxed_components.as_ref())
+        Err("Components not found.")
     }
 
-    pub fn iter(&self) -> Iter<EntityId, Vec<Box<dyn Component>>> {
-        self.components.iter()
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, Vec<&dyn Component>)> {
+        self.archetypes
+            .iter()
+            .flat_map(|borrowed| {
+                let columns = &borrowed.columns;
+
+                borrowed.entities
+                        .iter()
+                        .enumerate()
+                        .map(move |(row, entity_id)| {
+                            (
+                                *entity_id,
+                                columns.iter()
+                                       .map(|column| column.get_component(row))
+                                       .collect()
+                            )
+                        })
+            })
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<EntityId, Vec<Box<dyn Component>>> {
-        self.components.iter_mut()
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, Vec<&mut dyn Component>)> {
+        self.archetypes
+            .iter_mut()
+            .flat_map(|borrowed| {
+                let mut column_iters: Vec<_> = borrowed.columns
+                                                       .iter_mut()
+                                                       .map(|column| column.components_mut())
+                                                       .collect();
+
+                borrowed.entities
+                        .iter()
+                        .map(move |entity_id| {
+                            (
+                                *entity_id,
+                                column_iters.iter_mut()
+                                            .filter_map(|components| components.next())
+                                            .collect()
+                            )
+                        })
+            })
     }
 
-    pub fn components(&self) -> Values<EntityId, Vec<Box<dyn Component>>> {
-        self.components.values()
+    pub fn components(&self) -> impl Iterator<Item = &dyn Component> {
+        self.archetypes
+            .iter()
+            .flat_map(|borrowed| borrowed.columns.iter())
+            .flat_map(|column| column.components())
     }
 
-    pub fn components_mut(&mut self) -> ValuesMut<EntityId, Vec<Box<dyn Component>>> {
-        self.components.values_mut()
+    pub fn components_mut(&mut self) -> impl Iterator<Item = &mut dyn Component> {
+        self.archetypes
+            .iter_mut()
+            .flat_map(|borrowed| borrowed.columns.iter_mut())
+            .flat_map(|column| column.components_mut())
     }
 }
@@ -0,0 +1,33 @@
+use std::{
+    any::{
+        self,
+        TypeId
+    },
+    marker::PhantomData
+};
+
+use crate::core::ecs::{
+    containers::{
+        QueryFilter,
+        SystemTicks
+    },
+    storage::Archetype,
+    Component
+};
+
+pub struct Changed<T: Component + 'static> {
+    phantom: PhantomData<T>
+}
+
+impl<T: Component + 'static> QueryFilter for Changed<T> {
+    fn matches(archetype: &Archetype) -> bool {
+        archetype.has_component::<T>()
+    }
+
+    fn matches_row(archetype: &Archetype, row: usize, ticks: SystemTicks) -> bool {
+        match archetype.column_ticks(TypeId::of::<T>()) {
+            Some(column_ticks) => column_ticks[row].is_changed(ticks.last_run()),
+            None => panic!("Column of component '{}' is already borrowed.", any::type_name::<T>())
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    any::{
+        self,
+        TypeId
+    },
+    collections::HashMap
+};
+
+use crate::core::ecs::Component;
+
+#[derive(Debug, Default, Clone)]
+pub struct ComponentAccess {
+    reads: Vec<TypeId>,
+    writes: Vec<TypeId>,
+    resource_reads: Vec<TypeId>,
+    resource_writes: Vec<TypeId>,
+    exclusive: bool,
+    names: HashMap<TypeId, &'static str>,
+
+    // data written and also read or written again by the same system
+    duplicated: Vec<&'static str>
+}
+
+impl ComponentAccess {
+    pub fn new() -> ComponentAccess {
+        ComponentAccess {
+            reads: Vec::new(),
+            writes: Vec::new(),
+            resource_reads: Vec::new(),
+            resource_writes: Vec::new(),
+            exclusive: false,
+            names: HashMap::new(),
+            duplicated: Vec::new()
+        }
+    }
+
+    pub fn exclusive() -> ComponentAccess {
+        ComponentAccess {
+            exclusive: true,
+            ..ComponentAccess::new()
+        }
+    }
+
+    pub fn add_read<T: Component + 'static>(&mut self) {
+        let type_id = self.register_name::<T>();
+
+        if self.writes.contains(&type_id) {
+            self.add_duplicated(type_id);
+        }
+
+        if !self.reads.contains(&type_id) {
+            self.reads.push(type_id);
+        }
+    }
+
+    pub fn add_write<T: Component + 'static>(&mut self) {
+        let type_id = self.register_name::<T>();
+
+        if self.contains(&type_id) {
+            self.add_duplicated(type_id);
+        }
+
+        if !self.writes.contains(&type_id) {
+            self.writes.push(type_id);
+        }
+    }
+
+    pub fn add_resource_read<T: 'static>(&mut self) {
+        let type_id = self.register_name::<T>();
+
+        if self.resource_writes.contains(&type_id) {
+            self.add_duplicated(type_id);
+        }
+
+        if !self.resource_reads.contains(&type_id) {
+            self.resource_reads.push(type_id);
+        }
+    }
+
+    pub fn add_resource_write<T: 'static>(&mut self) {
+        let type_id = self.register_name::<T>();
+
+        if self.contains_resource(&type_id) {
+            self.add_duplicated(type_id);
+        }
+
+        if !self.resource_writes.contains(&type_id) {
+            self.resource_writes.push(type_id);
+        }
+    }
+
+    pub fn extend(&mut self, other: &ComponentAccess) {
+        self.names.extend(other.names.iter());
+
+        // an access from other overlapping a write from this one, or the opposite
+        let overlaps: Vec<TypeId> = other.writes.iter().filter(|type_id| self.contains(type_id))
+            .chain(self.writes.iter().filter(|type_id| other.reads.contains(type_id)))
+            .chain(other.resource_writes.iter().filter(|type_id| self.contains_resource(type_id)))
+            .chain(self.resource_writes.iter().filter(|type_id| other.resource_reads.contains(type_id)))
+            .copied()
+            .collect();
+
+        for type_id in overlaps {
+            self.add_duplicated(type_id);
+        }
+
+        for name in other.duplicated.iter() {
+            if !self.duplicated.contains(name) {
+                self.duplicated.push(name);
+            }
+        }
+
+        ComponentAccess::merge(&mut self.reads, &other.reads);
+        ComponentAccess::merge(&mut self.writes, &other.writes);
+        ComponentAccess::merge(&mut self.resource_reads, &other.resource_reads);
+        ComponentAccess::merge(&mut self.resource_writes, &other.resource_writes);
+        self.exclusive |= other.exclusive;
+    }
+
+    pub fn reads(&self) -> &[TypeId] {
+        &self.reads
+    }
+
+    pub fn writes(&self) -> &[TypeId] {
+        &self.writes
+    }
+
+    pub fn resource_reads(&self) -> &[TypeId] {
+        &self.resource_reads
+    }
+
+    pub fn resource_writes(&self) -> &[TypeId] {
+        &self.resource_writes
+    }
+
+    pub fn is_exclusive(&self) -> bool {
+        self.exclusive
+    }
+
+    /// Names of components and resources written while also being read or written elsewhere by the same system,
+    /// it's data can't be handed to the system twice.
+    pub fn duplicated(&self) -> &[&'static str] {
+        &self.duplicated
+    }
+
+    pub fn contains(&self, type_id: &TypeId) -> bool {
+        self.reads.contains(type_id) || self.writes.contains(type_id)
+    }
+
+    pub fn contains_resource(&self, type_id: &TypeId) -> bool {
+        self.resource_reads.contains(type_id) || self.resource_writes.contains(type_id)
+    }
+
+    pub fn conflicts_with(&self, other: &ComponentAccess) -> bool {
+        if self.exclusive || other.exclusive {
+            return true;
+        }
+
+        // readers share columns and resources, only a writer needs them alone
+        self.writes.iter().any(|type_id| other.contains(type_id))
+        || other.writes.iter().any(|type_id| self.contains(type_id))
+        || self.resource_writes.iter().any(|type_id| other.contains_resource(type_id))
+        || other.resource_writes.iter().any(|type_id| self.contains_resource(type_id))
+    }
+
+    fn register_name<T: 'static>(&mut self) -> TypeId {
+        let type_id = TypeId::of::<T>();
+        self.names.insert(type_id, any::type_name::<T>());
+        type_id
+    }
+
+    fn add_duplicated(&mut self, type_id: TypeId) {
+        let name = self.names.get(&type_id).copied().unwrap_or("unknown");
+
+        if !self.duplicated.contains(&name) {
+            self.duplicated.push(name);
+        }
+    }
+
+    fn merge(types: &mut Vec<TypeId>, other: &[TypeId]) {
+        for type_id in other {
+            if !types.contains(type_id) {
+                types.push(*type_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        ecs::{
+            components::TransformComponent,
+            containers::{
+                QueryDataContainer,
+                Read,
+                Res,
+                ResMut,
+                SystemDataContainer,
+                Write
+            }
+        },
+        time::Time
+    };
+
+    #[test]
+    fn query_reading_and_writing_a_component_is_duplicated() {
+        let access = QueryDataContainer::<(Read<TransformComponent>, Write<TransformComponent>)>::access();
+        assert_eq!(&[any::type_name::<TransformComponent>()], access.duplicated());
+
+        let access = QueryDataContainer::<(Write<TransformComponent>, Write<TransformComponent>)>::access();
+        assert_eq!(1, access.duplicated().len());
+    }
+
+    #[test]
+    fn data_of_different_containers_is_duplicated() {
+        let access = <(QueryDataContainer<Read<TransformComponent>>, QueryDataContainer<Write<TransformComponent>>)>::access();
+        assert_eq!(1, access.duplicated().len());
+
+        let access = <(Res<Time>, ResMut<Time>)>::access();
+        assert_eq!(&[any::type_name::<Time>()], access.duplicated());
+    }
+
+    #[test]
+    fn shared_reads_are_not_duplicated() {
+        let access = <(QueryDataContainer<Read<TransformComponent>>, QueryDataContainer<Read<TransformComponent>>, Res<Time>, Res<Time>)>::access();
+        assert!(access.duplicated().is_empty());
+    }
+
+    #[test]
+    fn resource_readers_share_and_writers_conflict() {
+        let reader = Res::<Time>::access();
+        let writer = ResMut::<Time>::access();
+
+        assert!(!reader.conflicts_with(&Res::<Time>::access()));
+        assert!(reader.conflicts_with(&writer));
+        assert!(writer.conflicts_with(&reader));
+        assert!(writer.conflicts_with(&ResMut::<Time>::access()));
+    }
+
+    #[test]
+    fn resources_only_conflict_with_the_same_resource() {
+        let time_writer = ResMut::<Time>::access();
+
+        assert!(!time_writer.conflicts_with(&ResMut::<TransformComponent>::access()));
+
+        // a resource and a component of the same type are different data
+        assert!(!time_writer.conflicts_with(&QueryDataContainer::<Write<TransformComponent>>::access()));
+        assert!(!ResMut::<TransformComponent>::access().conflicts_with(&QueryDataContainer::<Write<TransformComponent>>::access()));
+
+        let mixed = <(QueryDataContainer<Read<TransformComponent>>, Res<Time>)>::access();
+        assert!(mixed.conflicts_with(&time_writer));
+        assert!(!mixed.conflicts_with(&Res::<Time>::access()));
+    }
+}
@@ -0,0 +1,83 @@
+use std::any;
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        SystemDataContainer,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ArchetypeStorage
+    },
+    Events,
+    ResourceRef,
+    Resources
+};
+
+// only events sent after system's last run are read, which works as it's cursor
+pub struct EventReader<T: 'static> {
+    events: Option<ResourceRef<Events<T>>>,
+    last_run: u64
+}
+
+impl<T: 'static> SystemDataContainer for EventReader<T> {
+    type ComponentType = T;
+
+    fn new() -> Self {
+        EventReader {
+            events: None,
+            last_run: 0
+        }
+    }
+
+    fn access() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        access.add_resource_read::<Events<T>>();
+        access
+    }
+
+    fn prepare(&mut self, _storage: &ArchetypeStorage, ticks: SystemTicks) {
+        self.last_run = ticks.last_run();
+    }
+
+    fn try_add(&mut self, _archetype: &mut Archetype) {
+    }
+
+    fn give_back(&mut self, _storage: &mut ArchetypeStorage) {
+    }
+
+    fn take_resources(&mut self, resources: &mut Resources) {
+        match resources.lend::<Events<T>>() {
+            Some(events) => self.events = Some(events),
+            None => panic!("Event '{}' isn't available, it should be added to realm first.", any::type_name::<T>())
+        }
+    }
+
+    fn give_back_resources(&mut self, resources: &mut Resources) {
+        if let Some(events) = self.events.take() {
+            resources.return_lent(events);
+        }
+    }
+}
+
+impl<T: 'static> EventReader<T> {
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.events().iter_since_tick(self.last_run)
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    fn events(&self) -> &Events<T> {
+        match &self.events {
+            Some(events) => events,
+            None => panic!("Event '{}' isn't available.", any::type_name::<T>())
+        }
+    }
+}
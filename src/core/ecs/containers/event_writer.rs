@@ -0,0 +1,77 @@
+use std::any;
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        SystemDataContainer,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ArchetypeStorage
+    },
+    Events,
+    Resources
+};
+
+pub struct EventWriter<T: 'static> {
+    events: Option<Box<Events<T>>>,
+    tick: u64
+}
+
+impl<T: 'static> SystemDataContainer for EventWriter<T> {
+    type ComponentType = T;
+
+    fn new() -> Self {
+        EventWriter {
+            events: None,
+            tick: 0
+        }
+    }
+
+    fn access() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        access.add_resource_write::<Events<T>>();
+        access
+    }
+
+    fn prepare(&mut self, _storage: &ArchetypeStorage, ticks: SystemTicks) {
+        self.tick = ticks.current();
+    }
+
+    fn try_add(&mut self, _archetype: &mut Archetype) {
+    }
+
+    fn give_back(&mut self, _storage: &mut ArchetypeStorage) {
+    }
+
+    fn take_resources(&mut self, resources: &mut Resources) {
+        match resources.take::<Events<T>>() {
+            Some(events) => self.events = Some(events),
+            None => panic!("Event '{}' isn't available, it should be added to realm first.", any::type_name::<T>())
+        }
+    }
+
+    fn give_back_resources(&mut self, resources: &mut Resources) {
+        if let Some(events) = self.events.take() {
+            resources.give_back(events);
+        }
+    }
+}
+
+impl<T: 'static> EventWriter<T> {
+    pub fn send(&mut self, event: T) {
+        let tick = self.tick;
+
+        match &mut self.events {
+            Some(events) => events.send(event, tick),
+            None => panic!("Event '{}' isn't available.", any::type_name::<T>())
+        }
+    }
+
+    pub fn send_batch<I: IntoIterator<Item = T>>(&mut self, events: I) {
+        for event in events {
+            self.send(event);
+        }
+    }
+}
@@ -7,3 +7,72 @@ pub use simple_data_container::SimpleDataContainer;
 
 mod system_data_container;
 pub use system_data_container::SystemDataContainer;
+
+mod component_access;
+pub use component_access::ComponentAccess;
+
+mod system_data_tuple;
+
+mod system_ticks;
+pub use system_ticks::SystemTicks;
+
+// resource related
+mod res;
+pub use res::Res;
+
+mod res_mut;
+pub use res_mut::ResMut;
+
+// event related
+mod event_reader;
+pub use event_reader::EventReader;
+
+mod event_writer;
+pub use event_writer::EventWriter;
+
+// query related
+mod query;
+pub use query::{
+    Query,
+    QueryIter
+};
+
+mod query_data_container;
+pub use query_data_container::QueryDataContainer;
+
+mod query_filter;
+pub use query_filter::QueryFilter;
+
+mod query_tuple;
+pub use query_tuple::QueryTupleIter;
+
+mod optional_iter;
+pub use optional_iter::OptionalIter;
+
+mod read;
+pub use read::Read;
+
+mod write;
+pub use write::{
+    Write,
+    WriteIter
+};
+
+mod mut_ref;
+pub use mut_ref::Mut;
+
+mod with;
+pub use with::With;
+
+mod without;
+pub use without::Without;
+
+// change detection related
+mod added;
+pub use added::Added;
+
+mod changed;
+pub use changed::Changed;
+
+mod removed_components;
+pub use removed_components::RemovedComponents;
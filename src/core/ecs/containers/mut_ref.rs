@@ -0,0 +1,50 @@
+use std::ops::{
+    Deref,
+    DerefMut
+};
+
+use crate::core::ecs::storage::ComponentTicks;
+
+pub struct Mut<'a, T> {
+    value: &'a mut T,
+    ticks: &'a mut ComponentTicks,
+    change_tick: u64
+}
+
+impl<'a, T> Mut<'a, T> {
+    pub fn new(value: &'a mut T, ticks: &'a mut ComponentTicks, change_tick: u64) -> Mut<'a, T> {
+        Mut {
+            value,
+            ticks,
+            change_tick
+        }
+    }
+
+    pub fn ticks(&self) -> &ComponentTicks {
+        self.ticks
+    }
+
+    pub fn set_changed(&mut self) {
+        self.ticks.set_changed(self.change_tick);
+    }
+
+    pub fn into_inner(self) -> &'a mut T {
+        self.ticks.set_changed(self.change_tick);
+        self.value
+    }
+}
+
+impl<'a, T> Deref for Mut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<'a, T> DerefMut for Mut<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.ticks.set_changed(self.change_tick);
+        self.value
+    }
+}
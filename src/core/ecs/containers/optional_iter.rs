@@ -0,0 +1,30 @@
+pub struct OptionalIter<I: Iterator> {
+    inner: Option<I>,
+    remaining: usize
+}
+
+impl<I: Iterator> Iterator for OptionalIter<I> {
+    type Item = Option<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        match self.inner {
+            Some(ref mut iter) => iter.next().map(Some),
+            None => Some(None)
+        }
+    }
+}
+
+impl<I: Iterator> OptionalIter<I> {
+    pub fn new(inner: Option<I>, len: usize) -> OptionalIter<I> {
+        OptionalIter {
+            inner,
+            remaining: len
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        SystemTicks
+    },
+    storage::Archetype,
+    EntityId
+};
+
+pub trait Query : 'static {
+    type Fetch;
+
+    fn matches(archetype: &Archetype) -> bool;
+    fn access(access: &mut ComponentAccess);
+    fn take(archetype: &mut Archetype, ticks: SystemTicks) -> Self::Fetch;
+    fn give_back(fetch: Self::Fetch, archetype: &mut Archetype);
+}
+
+pub trait QueryIter<'a> : Query {
+    type Item;
+    type EntityItem;
+    type Iter: Iterator<Item = Self::Item>;
+
+    fn iter(fetch: &'a mut Self::Fetch, len: usize) -> Self::Iter;
+    fn get(fetch: &'a mut Self::Fetch, row: usize) -> Option<Self::Item>;
+    fn with_entity(entity_id: EntityId, item: Self::Item) -> Self::EntityItem;
+}
@@ -0,0 +1,235 @@
+use std::marker::PhantomData;
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        Query,
+        QueryFilter,
+        QueryIter,
+        SystemDataContainer,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ArchetypeId,
+        ArchetypeStorage
+    },
+    EntityId
+};
+
+struct BorrowedFetch<F> {
+    archetype: ArchetypeId,
+    entities: Vec<EntityId>,
+    matched: Vec<bool>,
+    fetch: F
+}
+
+pub struct QueryDataContainer<Q: Query, F: QueryFilter = ()> {
+    archetypes: Vec<BorrowedFetch<Q::Fetch>>,
+    ticks: SystemTicks,
+    phantom: PhantomData<F>
+}
+
+impl<Q: Query, F: QueryFilter> SystemDataContainer for QueryDataContainer<Q, F> {
+    type ComponentType = Q;
+
+    fn new() -> Self {
+        QueryDataContainer {
+            archetypes: Vec::new(),
+            ticks: SystemTicks::default(),
+            phantom: PhantomData
+        }
+    }
+
+    fn access() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        Q::access(&mut access);
+        access
+    }
+
+    fn prepare(&mut self, _storage: &ArchetypeStorage, ticks: SystemTicks) {
+        self.ticks = ticks;
+    }
+
+    fn try_add(&mut self, archetype: &mut Archetype) {
+        if archetype.is_empty() || !Q::matches(archetype) || !F::matches(archetype) {
+            return;
+        }
+
+        // row filters must be evaluated before query borrows it's columns
+        let matched: Vec<bool> = (0..archetype.len()).map(|row| F::matches_row(archetype, row, self.ticks))
+                                                     .collect();
+
+        if !matched.contains(&true) {
+            return;
+        }
+
+        self.archetypes.push(BorrowedFetch {
+            archetype: archetype.id(),
+            entities: archetype.entities().to_vec(),
+            matched,
+            fetch: Q::take(archetype, self.ticks)
+        });
+    }
+
+    fn give_back(&mut self, storage: &mut ArchetypeStorage) {
+        for borrowed in self.archetypes.drain(..) {
+            match storage.archetype_mut(borrowed.archetype) {
+                Some(archetype) => Q::give_back(borrowed.fetch, archetype),
+                None => panic!("Archetype {} not found! Can't give back borrowed columns.", borrowed.archetype)
+            }
+        }
+    }
+}
+
+impl<Q: Query, F: QueryFilter> QueryDataContainer<Q, F> {
+    pub fn get_mut<'a>(&'a mut self, entity_id: EntityId) -> Result<<Q as QueryIter<'a>>::Item, &'static str> where
+      Q: QueryIter<'a>
+    {
+        for borrowed in self.archetypes.iter_mut() {
+            if let Some(row) = borrowed.entities.iter().position(|id| *id == entity_id) {
+                if !borrowed.matched[row] {
+                    break;
+                }
+
+                return Q::get(&mut borrowed.fetch, row)
+                         .ok_or("Component row is out of bounds.");
+            }
+        }
+
+        Err("Entity doesn't match query.")
+    }
+
+    pub fn contains(&self, entity_id: EntityId) -> bool {
+        self.entities().any(|id| id == entity_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.archetypes
+            .iter()
+            .map(|borrowed| borrowed.matched.iter().filter(|matched| **matched).count())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn entities<'a>(&'a self) -> impl Iterator<Item = EntityId> + 'a {
+        self.archetypes
+            .iter()
+            .flat_map(|borrowed| {
+                borrowed.entities
+                        .iter()
+                        .zip(borrowed.matched.iter())
+                        .filter(|(_, matched)| **matched)
+                        .map(|(entity_id, _)| *entity_id)
+            })
+    }
+
+    pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = <Q as QueryIter<'a>>::EntityItem> where
+      Q: QueryIter<'a>
+    {
+        self.archetypes
+            .iter_mut()
+            .flat_map(|borrowed| {
+                let len = borrowed.entities.len();
+
+                borrowed.entities
+                        .iter()
+                        .copied()
+                        .zip(borrowed.matched.iter())
+                        .zip(Q::iter(&mut borrowed.fetch, len))
+                        .filter(|((_, matched), _)| **matched)
+                        .map(|((entity_id, _), item)| Q::with_entity(entity_id, item))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ecs::{
+        containers::{
+            Read,
+            Without,
+            Write
+        },
+        test_components::{
+            Frozen,
+            Health,
+            Position,
+            Velocity
+        },
+        Entity,
+        EntityAllocator
+    };
+
+    type Movement = QueryDataContainer<(Write<Position>, Read<Velocity>, Option<Read<Health>>), Without<Frozen>>;
+
+    fn spawn(storage: &mut ArchetypeStorage, allocator: &mut EntityAllocator, add: impl FnOnce(&mut Entity)) -> EntityId {
+        let mut entity = Entity::new(allocator.allocate());
+        add(&mut entity);
+
+        let entity_id = entity.get_id();
+        storage.insert(entity).unwrap();
+        entity_id
+    }
+
+    #[test]
+    fn mixed_tuple_yields_only_matching_entities() {
+        let mut storage = ArchetypeStorage::new();
+        let mut allocator = EntityAllocator::new();
+
+        let moving = spawn(&mut storage, &mut allocator, |entity| {
+            entity.add_component(Position(0.0));
+            entity.add_component(Velocity(1.0));
+        });
+        let healthy = spawn(&mut storage, &mut allocator, |entity| {
+            entity.add_component(Position(0.0));
+            entity.add_component(Velocity(2.0));
+            entity.add_component(Health(5));
+        });
+        let frozen = spawn(&mut storage, &mut allocator, |entity| {
+            entity.add_component(Position(0.0));
+            entity.add_component(Velocity(3.0));
+            entity.add_component(Frozen);
+        });
+        let still = spawn(&mut storage, &mut allocator, |entity| {
+            entity.add_component(Position(0.0));
+        });
+        let unplaced = spawn(&mut storage, &mut allocator, |entity| {
+            entity.add_component(Velocity(4.0));
+            entity.add_component(Health(1));
+        });
+
+        let mut container = Movement::new();
+        container.prepare(&storage, SystemTicks::new(0, storage.change_tick()));
+
+        for archetype in storage.archetypes_mut() {
+            container.try_add(archetype);
+        }
+
+        let mut yielded: Vec<(EntityId, Option<u32>)> = container.iter_mut()
+                                                                 .map(|(entity_id, mut position, velocity, health)| {
+                                                                     position.0 += velocity.0;
+                                                                     (entity_id, health.map(|health| health.0))
+                                                                 })
+                                                                 .collect();
+        yielded.sort();
+
+        let mut expected = vec!((moving, None), (healthy, Some(5)));
+        expected.sort();
+        assert_eq!(expected, yielded);
+        assert_eq!(2, container.len());
+        assert!(!container.contains(frozen));
+
+        container.give_back(&mut storage);
+
+        assert_eq!(storage.get::<Position>(moving), Some(&Position(1.0)));
+        assert_eq!(storage.get::<Position>(healthy), Some(&Position(2.0)));
+        assert_eq!(storage.get::<Position>(frozen), Some(&Position(0.0)));
+        assert_eq!(storage.get::<Position>(still), Some(&Position(0.0)));
+        assert_eq!(storage.get::<Velocity>(unplaced), Some(&Velocity(4.0)));
+    }
+}
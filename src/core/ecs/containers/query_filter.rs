@@ -0,0 +1,40 @@
+use crate::core::ecs::{
+    containers::SystemTicks,
+    storage::Archetype
+};
+
+pub trait QueryFilter : 'static {
+    fn matches(archetype: &Archetype) -> bool;
+
+    fn matches_row(_archetype: &Archetype, _row: usize, _ticks: SystemTicks) -> bool {
+        true
+    }
+}
+
+impl QueryFilter for () {
+    fn matches(_archetype: &Archetype) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_query_filter_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: QueryFilter),+> QueryFilter for ($($name,)+) {
+            fn matches(archetype: &Archetype) -> bool {
+                $($name::matches(archetype))&&+
+            }
+
+            fn matches_row(archetype: &Archetype, row: usize, ticks: SystemTicks) -> bool {
+                $($name::matches_row(archetype, row, ticks))&&+
+            }
+        }
+    }
+}
+
+impl_query_filter_tuple!(A, B);
+impl_query_filter_tuple!(A, B, C);
+impl_query_filter_tuple!(A, B, C, D);
+impl_query_filter_tuple!(A, B, C, D, E);
+impl_query_filter_tuple!(A, B, C, D, E, F);
+impl_query_filter_tuple!(A, B, C, D, E, F, G);
+impl_query_filter_tuple!(A, B, C, D, E, F, G, H);
@@ -0,0 +1,88 @@
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        Query,
+        QueryIter,
+        SystemTicks
+    },
+    storage::Archetype,
+    EntityId
+};
+
+pub struct QueryTupleIter<I> {
+    iters: I
+}
+
+impl<I> QueryTupleIter<I> {
+    pub fn new(iters: I) -> QueryTupleIter<I> {
+        QueryTupleIter {
+            iters
+        }
+    }
+}
+
+macro_rules! impl_query_tuple {
+    ($($name:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($name: Query),+> Query for ($($name,)+) {
+            type Fetch = ($($name::Fetch,)+);
+
+            fn matches(archetype: &Archetype) -> bool {
+                $($name::matches(archetype))&&+
+            }
+
+            fn access(access: &mut ComponentAccess) {
+                $($name::access(access);)+
+            }
+
+            fn take(archetype: &mut Archetype, ticks: SystemTicks) -> Self::Fetch {
+                ($($name::take(archetype, ticks),)+)
+            }
+
+            fn give_back(fetch: Self::Fetch, archetype: &mut Archetype) {
+                let ($($name,)+) = fetch;
+                $($name::give_back($name, archetype);)+
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<'a, $($name: QueryIter<'a>),+> QueryIter<'a> for ($($name,)+) {
+            type Item = ($($name::Item,)+);
+            type EntityItem = (EntityId, $($name::Item,)+);
+            type Iter = QueryTupleIter<($($name::Iter,)+)>;
+
+            fn iter(fetch: &'a mut Self::Fetch, len: usize) -> Self::Iter {
+                let ($($name,)+) = fetch;
+                QueryTupleIter::new(($($name::iter($name, len),)+))
+            }
+
+            fn get(fetch: &'a mut Self::Fetch, row: usize) -> Option<Self::Item> {
+                let ($($name,)+) = fetch;
+                Some(($($name::get($name, row)?,)+))
+            }
+
+            fn with_entity(entity_id: EntityId, item: Self::Item) -> Self::EntityItem {
+                let ($($name,)+) = item;
+                (entity_id, $($name,)+)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($name: Iterator),+> Iterator for QueryTupleIter<($($name,)+)> {
+            type Item = ($($name::Item,)+);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let ($($name,)+) = &mut self.iters;
+                Some(($($name.next()?,)+))
+            }
+        }
+    }
+}
+
+impl_query_tuple!(A, B);
+impl_query_tuple!(A, B, C);
+impl_query_tuple!(A, B, C, D);
+impl_query_tuple!(A, B, C, D, E);
+impl_query_tuple!(A, B, C, D, E, F);
+impl_query_tuple!(A, B, C, D, E, F, G);
+impl_query_tuple!(A, B, C, D, E, F, G, H);
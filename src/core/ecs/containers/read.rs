@@ -0,0 +1,114 @@
+use std::{
+    any,
+    marker::PhantomData,
+    slice::Iter
+};
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        OptionalIter,
+        Query,
+        QueryIter,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ColumnRef
+    },
+    Component,
+    EntityId
+};
+
+pub struct Read<T: Component + 'static> {
+    phantom: PhantomData<T>
+}
+
+impl<T: Component + 'static> Query for Read<T> {
+    type Fetch = ColumnRef<T>;
+
+    fn matches(archetype: &Archetype) -> bool {
+        archetype.has_component::<T>()
+    }
+
+    fn access(access: &mut ComponentAccess) {
+        access.add_read::<T>();
+    }
+
+    fn take(archetype: &mut Archetype, _ticks: SystemTicks) -> Self::Fetch {
+        // readers only borrow a view, so they can share a column
+        match archetype.lend_column::<T>() {
+            Some(column) => column,
+            None => panic!("Column of component '{}' is already borrowed.", any::type_name::<T>())
+        }
+    }
+
+    fn give_back(fetch: Self::Fetch, archetype: &mut Archetype) {
+        archetype.return_column(fetch);
+    }
+}
+
+impl<'a, T: Component + 'static> QueryIter<'a> for Read<T> {
+    type Item = &'a T;
+    type EntityItem = (EntityId, &'a T);
+    type Iter = Iter<'a, T>;
+
+    fn iter(fetch: &'a mut Self::Fetch, _len: usize) -> Self::Iter {
+        fetch.iter()
+    }
+
+    fn get(fetch: &'a mut Self::Fetch, row: usize) -> Option<Self::Item> {
+        fetch.get(row)
+    }
+
+    fn with_entity(entity_id: EntityId, item: Self::Item) -> Self::EntityItem {
+        (entity_id, item)
+    }
+}
+
+impl<T: Component + 'static> Query for Option<Read<T>> {
+    type Fetch = Option<ColumnRef<T>>;
+
+    fn matches(_archetype: &Archetype) -> bool {
+        true
+    }
+
+    fn access(access: &mut ComponentAccess) {
+        access.add_read::<T>();
+    }
+
+    fn take(archetype: &mut Archetype, ticks: SystemTicks) -> Self::Fetch {
+        if archetype.has_component::<T>() {
+            Some(Read::<T>::take(archetype, ticks))
+        } else {
+            None
+        }
+    }
+
+    fn give_back(fetch: Self::Fetch, archetype: &mut Archetype) {
+        if let Some(column) = fetch {
+            archetype.return_column(column);
+        }
+    }
+}
+
+impl<'a, T: Component + 'static> QueryIter<'a> for Option<Read<T>> {
+    type Item = Option<&'a T>;
+    type EntityItem = (EntityId, Option<&'a T>);
+    type Iter = OptionalIter<Iter<'a, T>>;
+
+    fn iter(fetch: &'a mut Self::Fetch, len: usize) -> Self::Iter {
+        OptionalIter::new(fetch.as_ref().map(|column| column.iter()), len)
+    }
+
+    fn get(fetch: &'a mut Self::Fetch, row: usize) -> Option<Self::Item> {
+        match fetch {
+            Some(column) => column.get(row).map(Some),
+            None => Some(None)
+        }
+    }
+
+    fn with_entity(entity_id: EntityId, item: Self::Item) -> Self::EntityItem {
+        (entity_id, item)
+    }
+}
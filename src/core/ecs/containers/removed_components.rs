@@ -0,0 +1,67 @@
+use std::{
+    any::TypeId,
+    marker::PhantomData,
+    slice::Iter
+};
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        SystemDataContainer,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ArchetypeStorage
+    },
+    Component,
+    EntityId
+};
+
+pub struct RemovedComponents<T: Component + 'static> {
+    entities: Vec<EntityId>,
+    phantom: PhantomData<T>
+}
+
+impl<T: Component + 'static> SystemDataContainer for RemovedComponents<T> {
+    type ComponentType = T;
+
+    fn new() -> Self {
+        RemovedComponents {
+            entities: Vec::new(),
+            phantom: PhantomData
+        }
+    }
+
+    fn access() -> ComponentAccess {
+        ComponentAccess::new()
+    }
+
+    fn prepare(&mut self, storage: &ArchetypeStorage, ticks: SystemTicks) {
+        self.entities = storage.removed_components(TypeId::of::<T>())
+                               .iter()
+                               .filter(|(_, tick)| *tick > ticks.last_run())
+                               .map(|(entity_id, _)| *entity_id)
+                               .collect();
+    }
+
+    fn try_add(&mut self, _archetype: &mut Archetype) {
+    }
+
+    fn give_back(&mut self, _storage: &mut ArchetypeStorage) {
+    }
+}
+
+impl<T: Component + 'static> RemovedComponents<T> {
+    pub fn iter(&self) -> Iter<'_, EntityId> {
+        self.entities.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
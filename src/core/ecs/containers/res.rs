@@ -0,0 +1,67 @@
+use std::{
+    any,
+    ops::Deref
+};
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        SystemDataContainer
+    },
+    storage::{
+        Archetype,
+        ArchetypeStorage
+    },
+    ResourceRef,
+    Resources
+};
+
+pub struct Res<T: 'static> {
+    resource: Option<ResourceRef<T>>
+}
+
+impl<T: 'static> SystemDataContainer for Res<T> {
+    type ComponentType = T;
+
+    fn new() -> Self {
+        Res {
+            resource: None
+        }
+    }
+
+    fn access() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        access.add_resource_read::<T>();
+        access
+    }
+
+    fn try_add(&mut self, _archetype: &mut Archetype) {
+    }
+
+    fn give_back(&mut self, _storage: &mut ArchetypeStorage) {
+    }
+
+    fn take_resources(&mut self, resources: &mut Resources) {
+        match resources.lend::<T>() {
+            Some(resource) => self.resource = Some(resource),
+            None => panic!("Resource '{}' isn't available.", any::type_name::<T>())
+        }
+    }
+
+    fn give_back_resources(&mut self, resources: &mut Resources) {
+        if let Some(resource) = self.resource.take() {
+            resources.return_lent(resource);
+        }
+    }
+}
+
+impl<T: 'static> Deref for Res<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.resource {
+            Some(resource) => resource,
+            None => panic!("Resource '{}' isn't available.", any::type_name::<T>())
+        }
+    }
+}
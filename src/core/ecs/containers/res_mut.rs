@@ -0,0 +1,78 @@
+use std::{
+    any,
+    ops::{
+        Deref,
+        DerefMut
+    }
+};
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        SystemDataContainer
+    },
+    storage::{
+        Archetype,
+        ArchetypeStorage
+    },
+    Resources
+};
+
+pub struct ResMut<T: 'static> {
+    resource: Option<Box<T>>
+}
+
+impl<T: 'static> SystemDataContainer for ResMut<T> {
+    type ComponentType = T;
+
+    fn new() -> Self {
+        ResMut {
+            resource: None
+        }
+    }
+
+    fn access() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        access.add_resource_write::<T>();
+        access
+    }
+
+    fn try_add(&mut self, _archetype: &mut Archetype) {
+    }
+
+    fn give_back(&mut self, _storage: &mut ArchetypeStorage) {
+    }
+
+    fn take_resources(&mut self, resources: &mut Resources) {
+        match resources.take::<T>() {
+            Some(resource) => self.resource = Some(resource),
+            None => panic!("Resource '{}' isn't available.", any::type_name::<T>())
+        }
+    }
+
+    fn give_back_resources(&mut self, resources: &mut Resources) {
+        if let Some(resource) = self.resource.take() {
+            resources.give_back(resource);
+        }
+    }
+}
+
+impl<T: 'static> Deref for ResMut<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.resource {
+            Some(resource) => resource,
+            None => panic!("Resource '{}' isn't available.", any::type_name::<T>())
+        }
+    }
+}
+
+impl<T: 'static> DerefMut for ResMut<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match &mut self.resource {
+            Some(resource) => resource,
+            None => panic!("Resource '{}' isn't available.", any::type_name::<T>())
+        }
+    }
+}
@@ -1,29 +1,34 @@
 use std::{
-    collections::{
-        hash_map::{
-            Drain,
-            Iter,
-            IterMut,
-            Values,
-            ValuesMut
-        },
-        HashMap,
-    },
-    iter::{
-        Iterator,
-        Map
-    },
+    iter::Iterator,
     marker::PhantomData
 };
 
 use crate::core::ecs::{
-    containers::SystemDataContainer,
+    containers::{
+        ComponentAccess,
+        Mut,
+        SystemDataContainer,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ArchetypeId,
+        ArchetypeStorage,
+        ComponentColumn
+    },
     Component,
     EntityId,
 };
 
+struct BorrowedColumn<T: Component + 'static> {
+    archetype: ArchetypeId,
+    entities: Vec<EntityId>,
+    column: Box<ComponentColumn<T>>
+}
+
 pub struct SimpleDataContainer<T: Component + 'static> {
-    components: HashMap<EntityId, Vec<Box<dyn Component>>>,
+    columns: Vec<BorrowedColumn<T>>,
+    change_tick: u64,
     phantom: PhantomData<T>
 }
 
@@ -32,98 +37,203 @@ impl<T: Component + 'static> SystemDataContainer for SimpleDataContainer<T> {
 
     fn new() -> Self {
         SimpleDataContainer {
-            components: HashMap::new(),
+            columns: Vec::new(),
+            change_tick: 0,
             phantom: PhantomData
         }
     }
 
-    fn try_add(&mut self, entity_id: EntityId, components: &mut Vec<Box<dyn Component>>) {
-        let mut captured_components = Vec::new();
+    fn access() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        access.add_write::<T>();
+        access
+    }
 
-        let mut index: usize = 0;
-        while index != components.len() {
-            if let Some(_) = components[index].as_any().downcast_ref::<T>() {
-                let component = components.remove(index);
-                captured_components.push(component);
-            } else {
-                index += 1;
-            }
+    fn prepare(&mut self, _storage: &ArchetypeStorage, ticks: SystemTicks) {
+        self.change_tick = ticks.current();
+    }
+
+    fn try_add(&mut self, archetype: &mut Archetype) {
+        if archetype.is_empty() {
+            return;
         }
 
-        self.components.insert(entity_id, captured_components);
+        if let Some(column) = archetype.take_column::<T>() {
+            self.columns.push(BorrowedColumn {
+                archetype: archetype.id(),
+                entities: archetype.entities().to_vec(),
+                column
+            });
+        }
     }
 
-    fn drain(&mut self) -> Drain<EntityId, Vec<Box<dyn Component>>> {
-        self.components.drain()
+    fn give_back(&mut self, storage: &mut ArchetypeStorage) {
+        for borrowed in self.columns.drain(..) {
+            match storage.archetype_mut(borrowed.archetype) {
+                Some(archetype) => archetype.give_back_column(borrowed.column),
+                None => panic!("Archetype {} not found! Can't give back borrowed column.", borrowed.archetype)
+            }
+        }
     }
 }
 
 impl<T: Component> SimpleDataContainer<T> {
-    pub fn get<'a>(&'a self, entity_id: EntityId) -> Result<Vec<&'a T>, &'static str> {
-        let boxed_components = match self.components.get(&entity_id) {
-            Some(c) => c,
-            None => return Err("Boxed component is empty.")
-        };
-
-        let mut components = Vec::new();
-        for component in boxed_components.iter() {
-            components.push(
-                component.as_any()
-                         .downcast_ref::<T>()
-                         .expect("Conversion from boxed component into concrete type is impossible.")
-            );
+    pub fn get(&self, entity_id: EntityId) -> Result<&T, &'static str> {
+        for borrowed in self.columns.iter() {
+            if let Some(row) = borrowed.entities.iter().position(|id| *id == entity_id) {
+                return borrowed.column
+                               .get(row)
+                               .ok_or("Component row is out of bounds.");
+            }
+        }
+
+        Err("Component not found.")
+    }
+
+    /// Component is only marked as changed when it's written through.
+    pub fn get_mut<'a>(&'a mut self, entity_id: EntityId) -> Result<Mut<'a, T>, &'static str> {
+        let change_tick = self.change_tick;
+
+        for borrowed in self.columns.iter_mut() {
+            if let Some(row) = borrowed.entities.iter().position(|id| *id == entity_id) {
+                return borrowed.column
+                               .get_mut_with_ticks(row)
+                               .map(|(component, ticks)| Mut::new(component, ticks, change_tick))
+                               .ok_or("Component row is out of bounds.");
+            }
         }
 
-        Ok(components)
+        Err("Component not found.")
     }
 
-    //pub fn iter<'a>(&self) -> Map<Iter<'_, EntityId, Vec<Box<dyn Component>>>, FnMut((EntityId, Vec<Box<impl Component>>)) -> (EntityId, Vec<&'a T>)> {
-    pub fn iter<'a>(&'a self) -> impl Iterator<Item = (EntityId, Vec<&'a T>)> {
-        self.components
+    pub fn len(&self) -> usize {
+        self.columns
             .iter()
-            .map(|(k, v)| {
-                let mut components = Vec::new();
-                for component in v.iter() {
-                    components.push(
-                        component.as_any()
-                                 .downcast_ref::<T>()
-                                 .expect("Conversion from boxed component into concrete type is impossible.")
-                    );
-                }
+            .map(|borrowed| borrowed.entities.len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 
-                (*k, components)
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.columns
+            .iter()
+            .flat_map(|borrowed| {
+                borrowed.entities
+                        .iter()
+                        .copied()
+                        .zip(borrowed.column.iter())
             })
-            .into_iter()
     }
 
-    /*
-    pub fn iter_mut<'a>(&mut self) -> IterMut<EntityId, Vec<&'a T>> {
-        self.components.iter_mut()
+    pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (EntityId, Mut<'a, T>)> {
+        let change_tick = self.change_tick;
+
+        self.columns
+            .iter_mut()
+            .flat_map(move |borrowed| {
+                borrowed.entities
+                        .iter()
+                        .copied()
+                        .zip(borrowed.column.iter_mut_with_ticks())
+                        .map(move |(entity_id, (component, ticks))| (entity_id, Mut::new(component, ticks, change_tick)))
+            })
     }
-    */
 
-    /*
-    pub fn components<'a>(&self) -> Values<EntityId, Vec<&'a T>> {
-        self.components.values()
+    pub fn components(&self) -> impl Iterator<Item = &T> {
+        self.columns
+            .iter()
+            .flat_map(|borrowed| borrowed.column.iter())
     }
-    */
 
-    pub fn components_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T> {
-        self.components
-            .values_mut()
-            .map(|v| {
-                let mut components = Vec::new();
-                for component in v.iter_mut() {
-                    components.push(
-                        component.as_any_mut()
-                                 .downcast_mut::<T>()
-                                 .expect("Conversion from boxed component into concrete type is impossible.")
-                    );
+    pub fn components_mut<'a>(&'a mut self) -> impl Iterator<Item = Mut<'a, T>> {
+        self.iter_mut().map(|(_, component)| component)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::{
+            Any,
+            TypeId
+        },
+        cell::{
+            Ref,
+            RefCell
+        },
+        rc::Rc
+    };
+
+    use super::*;
+    use crate::core::{
+        ecs::{
+            components::TransformComponent,
+            Realm,
+            System,
+            SystemContext
+        },
+        GameState
+    };
+
+    struct RotateOne {
+        target: Option<EntityId>
+    }
+
+    impl System for RotateOne {
+        type DataType = SimpleDataContainer<TransformComponent>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, transforms: &mut Self::DataType, _context: &mut SystemContext) {
+            for (entity_id, mut transform) in transforms.iter_mut() {
+                if Some(entity_id) == self.target {
+                    transform.rotation += 1.0;
                 }
+            }
+        }
 
-                components
-            })
-            .flatten()
-            .into_iter()
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    fn changed_tick(realm: &Realm, entity_id: EntityId) -> u64 {
+        let location = realm.storage().location(entity_id).unwrap();
+
+        realm.storage()
+             .archetype(location.archetype)
+             .and_then(|archetype| archetype.column_ticks(TypeId::of::<TransformComponent>()))
+             .map(|ticks| ticks[location.row].changed())
+             .unwrap()
+    }
+
+    #[test]
+    fn only_written_components_are_changed() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = Realm::new();
+        realm.game_state = Rc::downgrade(&game_state);
+
+        let written = realm.create_entity().with_component(TransformComponent::new()).build();
+        let untouched = realm.create_entity().with_component(TransformComponent::new()).build();
+        let added_tick = changed_tick(&realm, untouched);
+
+        // entities are added at current tick, so it's moved past it before writing
+        realm.register_system("rotate", RotateOne { target: None });
+        realm.run_system("rotate");
+
+        realm.get_mut_system::<RotateOne, _>("rotate").unwrap().target = Some(written);
+        realm.run_system("rotate");
+
+        assert!(changed_tick(&realm, written) > added_tick);
+        assert_eq!(added_tick, changed_tick(&realm, untouched));
+        assert_eq!(1.0, realm.get_component::<TransformComponent>(written).unwrap().rotation);
     }
 }
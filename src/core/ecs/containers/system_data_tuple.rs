@@ -0,0 +1,64 @@
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        SystemDataContainer,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ArchetypeStorage
+    },
+    Resources
+};
+
+macro_rules! impl_system_data_tuple {
+    ($($name:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($name: SystemDataContainer),+> SystemDataContainer for ($($name,)+) {
+            type ComponentType = ($($name::ComponentType,)+);
+
+            fn new() -> Self {
+                ($($name::new(),)+)
+            }
+
+            fn access() -> ComponentAccess {
+                let mut access = ComponentAccess::new();
+                $(access.extend(&$name::access());)+
+                access
+            }
+
+            fn prepare(&mut self, storage: &ArchetypeStorage, ticks: SystemTicks) {
+                let ($($name,)+) = self;
+                $($name.prepare(storage, ticks);)+
+            }
+
+            fn try_add(&mut self, archetype: &mut Archetype) {
+                let ($($name,)+) = self;
+                $($name.try_add(archetype);)+
+            }
+
+            fn give_back(&mut self, storage: &mut ArchetypeStorage) {
+                let ($($name,)+) = self;
+                $($name.give_back(storage);)+
+            }
+
+            fn take_resources(&mut self, resources: &mut Resources) {
+                let ($($name,)+) = self;
+                $($name.take_resources(resources);)+
+            }
+
+            fn give_back_resources(&mut self, resources: &mut Resources) {
+                let ($($name,)+) = self;
+                $($name.give_back_resources(resources);)+
+            }
+        }
+    }
+}
+
+impl_system_data_tuple!(A, B);
+impl_system_data_tuple!(A, B, C);
+impl_system_data_tuple!(A, B, C, D);
+impl_system_data_tuple!(A, B, C, D, E);
+impl_system_data_tuple!(A, B, C, D, E, F);
+impl_system_data_tuple!(A, B, C, D, E, F, G);
+impl_system_data_tuple!(A, B, C, D, E, F, G, H);
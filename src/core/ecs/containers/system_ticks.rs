@@ -0,0 +1,22 @@
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct SystemTicks {
+    last_run: u64,
+    current: u64
+}
+
+impl SystemTicks {
+    pub fn new(last_run: u64, current: u64) -> SystemTicks {
+        SystemTicks {
+            last_run,
+            current
+        }
+    }
+
+    pub fn last_run(&self) -> u64 {
+        self.last_run
+    }
+
+    pub fn current(&self) -> u64 {
+        self.current
+    }
+}
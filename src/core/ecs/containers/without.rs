@@ -0,0 +1,17 @@
+use std::marker::PhantomData;
+
+use crate::core::ecs::{
+    containers::QueryFilter,
+    storage::Archetype,
+    Component
+};
+
+pub struct Without<T: Component + 'static> {
+    phantom: PhantomData<T>
+}
+
+impl<T: Component + 'static> QueryFilter for Without<T> {
+    fn matches(archetype: &Archetype) -> bool {
+        !archetype.has_component::<T>()
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    any,
+    iter::Zip,
+    marker::PhantomData,
+    slice::IterMut
+};
+
+use crate::core::ecs::{
+    containers::{
+        ComponentAccess,
+        Mut,
+        OptionalIter,
+        Query,
+        QueryIter,
+        SystemTicks
+    },
+    storage::{
+        Archetype,
+        ComponentColumn,
+        ComponentTicks
+    },
+    Component,
+    EntityId
+};
+
+pub struct Write<T: Component + 'static> {
+    phantom: PhantomData<T>
+}
+
+pub struct WriteIter<'a, T: Component + 'static> {
+    inner: Zip<IterMut<'a, T>, IterMut<'a, ComponentTicks>>,
+    change_tick: u64
+}
+
+impl<'a, T: Component + 'static> Iterator for WriteIter<'a, T> {
+    type Item = Mut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let change_tick = self.change_tick;
+
+        self.inner
+            .next()
+            .map(|(component, ticks)| Mut::new(component, ticks, change_tick))
+    }
+}
+
+impl<T: Component + 'static> Query for Write<T> {
+    type Fetch = (Box<ComponentColumn<T>>, u64);
+
+    fn matches(archetype: &Archetype) -> bool {
+        archetype.has_component::<T>()
+    }
+
+    fn access(access: &mut ComponentAccess) {
+        access.add_write::<T>();
+    }
+
+    fn take(archetype: &mut Archetype, ticks: SystemTicks) -> Self::Fetch {
+        match archetype.take_column::<T>() {
+            Some(column) => (column, ticks.current()),
+            None => panic!("Column of component '{}' is already borrowed.", any::type_name::<T>())
+        }
+    }
+
+    fn give_back(fetch: Self::Fetch, archetype: &mut Archetype) {
+        let (column, _) = fetch;
+        archetype.give_back_column(column);
+    }
+}
+
+impl<'a, T: Component + 'static> QueryIter<'a> for Write<T> {
+    type Item = Mut<'a, T>;
+    type EntityItem = (EntityId, Mut<'a, T>);
+    type Iter = WriteIter<'a, T>;
+
+    fn iter(fetch: &'a mut Self::Fetch, _len: usize) -> Self::Iter {
+        let (column, change_tick) = fetch;
+
+        WriteIter {
+            inner: column.iter_mut_with_ticks(),
+            change_tick: *change_tick
+        }
+    }
+
+    fn get(fetch: &'a mut Self::Fetch, row: usize) -> Option<Self::Item> {
+        let (column, change_tick) = fetch;
+
+        column.get_mut_with_ticks(row)
+              .map(|(component, ticks)| Mut::new(component, ticks, *change_tick))
+    }
+
+    fn with_entity(entity_id: EntityId, item: Self::Item) -> Self::EntityItem {
+        (entity_id, item)
+    }
+}
+
+impl<T: Component + 'static> Query for Option<Write<T>> {
+    type Fetch = Option<(Box<ComponentColumn<T>>, u64)>;
+
+    fn matches(_archetype: &Archetype) -> bool {
+        true
+    }
+
+    fn access(access: &mut ComponentAccess) {
+        access.add_write::<T>();
+    }
+
+    fn take(archetype: &mut Archetype, ticks: SystemTicks) -> Self::Fetch {
+        if archetype.has_component::<T>() {
+            Some(Write::<T>::take(archetype, ticks))
+        } else {
+            None
+        }
+    }
+
+    fn give_back(fetch: Self::Fetch, archetype: &mut Archetype) {
+        if let Some(fetch) = fetch {
+            Write::<T>::give_back(fetch, archetype);
+        }
+    }
+}
+
+impl<'a, T: Component + 'static> QueryIter<'a> for Option<Write<T>> {
+    type Item = Option<Mut<'a, T>>;
+    type EntityItem = (EntityId, Option<Mut<'a, T>>);
+    type Iter = OptionalIter<WriteIter<'a, T>>;
+
+    fn iter(fetch: &'a mut Self::Fetch, len: usize) -> Self::Iter {
+        OptionalIter::new(fetch.as_mut().map(|fetch| Write::<T>::iter(fetch, len)), len)
+    }
+
+    fn get(fetch: &'a mut Self::Fetch, row: usize) -> Option<Self::Item> {
+        match fetch {
+            Some(fetch) => Write::<T>::get(fetch, row).map(Some),
+            None => Some(None)
+        }
+    }
+
+    fn with_entity(entity_id: EntityId, item: Self::Item) -> Self::EntityItem {
+        (entity_id, item)
+    }
+}
@@ -1,17 +1,63 @@
-use super::Component;
+use std::{
+    any,
+    fmt::{
+        self,
+        Display,
+        Formatter
+    }
+};
+
+use super::{
+    storage::ComponentBundle,
+    Component
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct EntityId {
+    index: u32,
+    generation: u32
+}
+
+impl Display for EntityId {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}v{}", self.index, self.generation)
+    }
+}
 
-pub type EntityId = u64;
+impl EntityId {
+    pub(super) fn new(index: u32, generation: u32) -> EntityId {
+        EntityId {
+            index,
+            generation
+        }
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
 
 pub struct Entity {
     pub(super) id: EntityId,
-    components: Vec<Box<dyn Component>>
+    components: ComponentBundle
 }
 
 impl Entity {
     pub fn new(id: EntityId) -> Entity {
         Entity {
             id,
-            components: Vec::new()
+            components: ComponentBundle::new()
+        }
+    }
+
+    pub(super) fn with_components(id: EntityId, components: ComponentBundle) -> Entity {
+        Entity {
+            id,
+            components
         }
     }
 
@@ -19,15 +65,31 @@ impl Entity {
         self.id
     }
 
-    pub fn get_components(&self) -> &Vec<Box<dyn Component>> {
-        &self.components
+    pub fn get_components(&self) -> impl Iterator<Item = &dyn Component> {
+        self.components.components()
+    }
+
+    pub fn get_mut_components(&mut self) -> impl Iterator<Item = &mut dyn Component> {
+        self.components.components_mut()
     }
 
-    pub fn get_mut_components(&mut self) -> &mut Vec<Box<dyn Component>> {
-        &mut self.components
+    pub fn has_component<T: Component + 'static>(&self) -> bool {
+        self.components.has_component::<T>()
     }
 
     pub fn add_component<T: Component + 'static>(&mut self, component: T) {
-        self.components.push(Box::new(component))
+        if self.has_component::<T>() {
+            panic!("Entity (with id: {}) already has a component '{}'.", self.id, any::type_name::<T>());
+        }
+
+        self.components.push(component)
+    }
+
+    pub fn remove_component<T: Component + 'static>(&mut self) -> Option<T> {
+        self.components.remove::<T>()
+    }
+
+    pub(super) fn into_components(self) -> ComponentBundle {
+        self.components
     }
 }
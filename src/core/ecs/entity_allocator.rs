@@ -0,0 +1,83 @@
+use crate::core::ecs::EntityId;
+
+pub struct EntityAllocator {
+    generations: Vec<u32>,
+    alive: Vec<bool>,
+    free_indices: Vec<u32>
+}
+
+impl Default for EntityAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityAllocator {
+    pub fn new() -> EntityAllocator {
+        EntityAllocator {
+            generations: Vec::new(),
+            alive: Vec::new(),
+            free_indices: Vec::new()
+        }
+    }
+
+    pub fn allocate(&mut self) -> EntityId {
+        match self.free_indices.pop() {
+            Some(index) => {
+                let i = index as usize;
+                self.alive[i] = true;
+                EntityId::new(index, self.generations[i])
+            },
+            None => {
+                let index = self.generations.len() as u32;
+                self.generations.push(0);
+                self.alive.push(true);
+                EntityId::new(index, 0)
+            }
+        }
+    }
+
+    pub fn free(&mut self, entity_id: EntityId) -> bool {
+        if !self.is_alive(entity_id) {
+            return false;
+        }
+
+        let i = entity_id.index() as usize;
+        self.alive[i] = false;
+        self.generations[i] = self.generations[i].wrapping_add(1);
+        self.free_indices.push(entity_id.index());
+        true
+    }
+
+    pub fn is_alive(&self, entity_id: EntityId) -> bool {
+        let i = entity_id.index() as usize;
+
+        match self.generations.get(i) {
+            Some(generation) => self.alive[i] && *generation == entity_id.generation(),
+            None => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityAllocator;
+
+    #[test]
+    fn reused_index_gets_a_new_generation() {
+        let mut allocator = EntityAllocator::new();
+        let first = allocator.allocate();
+        let other = allocator.allocate();
+
+        assert!(allocator.free(first));
+        assert!(!allocator.is_alive(first));
+        assert!(!allocator.free(first));
+
+        let reused = allocator.allocate();
+        assert_eq!(first.index(), reused.index());
+        assert_eq!(first.generation() + 1, reused.generation());
+        assert!(allocator.is_alive(reused));
+        assert!(!allocator.is_alive(first));
+        assert!(allocator.is_alive(other));
+    }
+}
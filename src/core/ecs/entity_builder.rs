@@ -1,6 +1,7 @@
 pub use super::{
     Component,
     Entity,
+    EntityId,
     Realm
 };
 
@@ -10,7 +11,7 @@ pub struct EntityBuilder<'r> {
 }
 
 impl<'r> EntityBuilder<'r> {
-    pub fn new<'a>(id: u64, realm: &'a mut Realm) -> EntityBuilder<'a> {
+    pub fn new<'a>(id: EntityId, realm: &'a mut Realm) -> EntityBuilder<'a> {
         EntityBuilder {
             entity: Entity::new(id),
             realm
@@ -22,7 +23,10 @@ impl<'r> EntityBuilder<'r> {
         self
     }
 
-    pub fn build(self) {
-        self.realm.add_entity(self.entity);
+    pub fn build(self) -> EntityId {
+        match self.realm.add_entity(self.entity) {
+            Ok(entity_id) => entity_id,
+            Err(e) => panic!("Can't build entity: {}", e)
+        }
     }
 }
@@ -0,0 +1,51 @@
+use std::{
+    error::{
+        Error
+    },
+    fmt::{
+        self,
+        Display,
+        Formatter
+    }
+};
+
+use crate::core::ecs::EntityId;
+
+#[derive(Debug)]
+pub enum EntityError {
+    Dead(EntityId),
+    AlreadyExists(EntityId),
+    ComponentNotFound {
+        entity_id: EntityId,
+        component: &'static str
+    },
+    InvalidParent {
+        entity_id: EntityId,
+        parent_id: EntityId
+    }
+}
+
+impl Display for EntityError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EntityError::Dead(entity_id) => {
+                write!(fmt, "Entity {} is dead or was never spawned at this realm.", entity_id)
+            },
+            EntityError::AlreadyExists(entity_id) => {
+                write!(fmt, "Entity {} already exists.", entity_id)
+            },
+            EntityError::ComponentNotFound { entity_id, component } => {
+                write!(fmt, "Entity {} doesn't have a component '{}'.", entity_id, component)
+            },
+            EntityError::InvalidParent { entity_id, parent_id } => {
+                write!(fmt, "Entity {} can't have {} as parent, it would create a cycle.", entity_id, parent_id)
+            }
+        }
+    }
+}
+
+impl Error for EntityError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
@@ -0,0 +1,34 @@
+use std::marker::PhantomData;
+
+use crate::core::ecs::Events;
+
+// reads events outside systems, each cursor sees every event once
+pub struct EventCursor<T: 'static> {
+    next_id: usize,
+    phantom: PhantomData<T>
+}
+
+impl<T: 'static> Default for EventCursor<T> {
+    fn default() -> Self {
+        EventCursor::new(0)
+    }
+}
+
+impl<T: 'static> EventCursor<T> {
+    pub fn new(next_id: usize) -> EventCursor<T> {
+        EventCursor {
+            next_id,
+            phantom: PhantomData
+        }
+    }
+
+    pub fn read<'a>(&mut self, events: &'a Events<T>) -> impl Iterator<Item = &'a T> {
+        let id = self.next_id;
+        self.next_id = events.event_count();
+        events.iter_since_id(id)
+    }
+
+    pub fn has_unread(&self, events: &Events<T>) -> bool {
+        events.iter_since_id(self.next_id).next().is_some()
+    }
+}
@@ -0,0 +1,134 @@
+use std::mem;
+
+use crate::core::ecs::EventCursor;
+
+struct EventInstance<T> {
+    id: usize,
+    tick: u64,
+    event: T
+}
+
+// events are double buffered, so they are kept until the end of next frame
+pub struct Events<T: 'static> {
+    previous: Vec<EventInstance<T>>,
+    current: Vec<EventInstance<T>>,
+    event_count: usize
+}
+
+impl<T: 'static> Default for Events<T> {
+    fn default() -> Self {
+        Events::new()
+    }
+}
+
+impl<T: 'static> Events<T> {
+    pub fn new() -> Events<T> {
+        Events {
+            previous: Vec::new(),
+            current: Vec::new(),
+            event_count: 0
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.previous = mem::take(&mut self.current);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.instances().map(|instance| &instance.event)
+    }
+
+    pub fn cursor(&self) -> EventCursor<T> {
+        EventCursor::new(self.event_count)
+    }
+
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.current.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty() && self.current.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.previous.clear();
+        self.current.clear();
+    }
+
+    pub fn event_count(&self) -> usize {
+        self.event_count
+    }
+
+    pub(in crate::core::ecs) fn send(&mut self, event: T, tick: u64) {
+        self.current.push(EventInstance {
+            id: self.event_count,
+            tick,
+            event
+        });
+
+        self.event_count += 1;
+    }
+
+    pub(in crate::core::ecs) fn iter_since_id(&self, id: usize) -> impl Iterator<Item = &T> {
+        self.instances()
+            .filter(move |instance| instance.id >= id)
+            .map(|instance| &instance.event)
+    }
+
+    pub(in crate::core::ecs) fn iter_since_tick(&self, tick: u64) -> impl Iterator<Item = &T> {
+        self.instances()
+            .filter(move |instance| instance.tick > tick)
+            .map(|instance| &instance.event)
+    }
+
+    fn instances(&self) -> impl Iterator<Item = &EventInstance<T>> {
+        self.previous
+            .iter()
+            .chain(self.current.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_is_kept_for_two_updates() {
+        let mut events = Events::new();
+        events.send(7u32, 1);
+
+        events.update();
+        assert_eq!(vec![&7], events.iter().collect::<Vec<_>>());
+
+        events.send(8u32, 2);
+        events.update();
+        assert_eq!(vec![&8], events.iter().collect::<Vec<_>>());
+
+        events.update();
+        assert!(events.is_empty());
+        assert_eq!(2, events.event_count());
+    }
+
+    #[test]
+    fn cursors_read_independently() {
+        let mut events = Events::new();
+        let mut early = events.cursor();
+        events.send(1u32, 1);
+        events.send(2u32, 1);
+
+        let mut late = events.cursor();
+        assert_eq!(vec![&1, &2], early.read(&events).collect::<Vec<_>>());
+
+        events.send(3u32, 2);
+        assert_eq!(vec![&3], early.read(&events).collect::<Vec<_>>());
+        assert_eq!(vec![&3], late.read(&events).collect::<Vec<_>>());
+        assert!(!early.has_unread(&events));
+
+        // a cursor lagging behind only misses what was already dropped
+        let mut lagging = EventCursor::default();
+        events.update();
+        events.update();
+        events.send(4u32, 3);
+        assert_eq!(vec![&4], lagging.read(&events).collect::<Vec<_>>());
+    }
+}
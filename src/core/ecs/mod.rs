@@ -5,6 +5,12 @@ pub use entity::{
     EntityId 
 };
 
+mod entity_allocator;
+pub use entity_allocator::EntityAllocator;
+
+mod entity_error;
+pub use entity_error::EntityError;
+
 mod entity_builder;
 pub use entity_builder::EntityBuilder;
 
@@ -14,16 +20,57 @@ pub use component::Component;
 
 pub mod components;
 
+// storage related
+pub mod storage;
+
 // realm related
 mod realm;
 pub use realm::Realm;
 
+mod resources;
+pub use resources::Resources;
+
+mod resource_ref;
+pub use resource_ref::ResourceRef;
+
+// event related
+mod events;
+pub use events::Events;
+
+mod event_cursor;
+pub use event_cursor::EventCursor;
+
 // system related
 mod system;
 pub use system::System;
 
+mod parallel_system;
+pub use parallel_system::ParallelSystem;
+
+mod system_context;
+pub use system_context::SystemContext;
+
+mod parallel_system_context;
+pub use parallel_system_context::ParallelSystemContext;
+
+mod commands;
+pub use commands::{
+    CommandEntityBuilder,
+    Commands
+};
+
+mod parallel_commands;
+pub use parallel_commands::{
+    ParallelCommands,
+    ParallelEntityBuilder
+};
+
 pub mod containers;
 
+pub mod schedule;
+
+pub mod serialization;
+
 //mod boxed_data_container;
 //pub use boxed_data_container::BoxedDataContainer;
 
@@ -31,3 +78,6 @@ mod any_system;
 pub use any_system::AnySystem;
 
 pub mod systems;
+
+#[cfg(test)]
+mod test_components;
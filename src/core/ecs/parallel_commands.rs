@@ -0,0 +1,98 @@
+use crate::core::ecs::{
+    CommandEntityBuilder,
+    Commands,
+    Component,
+    EntityError,
+    EntityId,
+    Realm
+};
+
+/// Commands queued from a parallel system, everything it queues must be sendable between threads.
+pub struct ParallelCommands {
+    commands: Commands
+}
+
+// commands are only queued through methods which requires sendable values
+unsafe impl Send for ParallelCommands {}
+
+impl ParallelCommands {
+    pub(super) fn forked_from(commands: &Commands) -> ParallelCommands {
+        ParallelCommands {
+            commands: commands.fork()
+        }
+    }
+
+    pub fn add<F: 'static + Send + FnOnce(&mut Realm) -> Result<(), EntityError>>(&mut self, command: F) {
+        self.commands.add(command);
+    }
+
+    pub fn spawn<'c>(&'c mut self) -> ParallelEntityBuilder<'c> {
+        ParallelEntityBuilder {
+            builder: self.commands.spawn()
+        }
+    }
+
+    pub fn despawn(&mut self, entity_id: EntityId) {
+        self.commands.despawn(entity_id);
+    }
+
+    pub fn despawn_recursive(&mut self, entity_id: EntityId) {
+        self.commands.despawn_recursive(entity_id);
+    }
+
+    pub fn set_parent(&mut self, entity_id: EntityId, parent_id: EntityId) {
+        self.commands.set_parent(entity_id, parent_id);
+    }
+
+    pub fn remove_parent(&mut self, entity_id: EntityId) {
+        self.commands.remove_parent(entity_id);
+    }
+
+    pub fn add_component<T: Component + Send + 'static>(&mut self, entity_id: EntityId, component: T) {
+        self.commands.add_component(entity_id, component);
+    }
+
+    pub fn remove_component<T: Component + 'static>(&mut self, entity_id: EntityId) {
+        self.commands.remove_component::<T>(entity_id);
+    }
+
+    pub fn insert_resource<T: Send + 'static>(&mut self, resource: T) {
+        self.commands.insert_resource(resource);
+    }
+
+    pub fn remove_resource<T: 'static>(&mut self) {
+        self.commands.remove_resource::<T>();
+    }
+
+    pub fn send_event<T: Send + 'static>(&mut self, event: T) {
+        self.commands.send_event(event);
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub(super) fn append_to(&mut self, commands: &mut Commands) {
+        commands.append(&mut self.commands);
+    }
+}
+
+pub struct ParallelEntityBuilder<'c> {
+    builder: CommandEntityBuilder<'c>
+}
+
+impl<'c> ParallelEntityBuilder<'c> {
+    pub fn with_component<T: Component + Send + 'static>(self, component: T) -> ParallelEntityBuilder<'c> {
+        ParallelEntityBuilder {
+            builder: self.builder.with_component(component)
+        }
+    }
+
+    pub fn build(self) -> EntityId {
+        self.builder.build()
+    }
+}
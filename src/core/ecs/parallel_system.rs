@@ -0,0 +1,25 @@
+use std::{
+    any::Any,
+    cell::Ref
+};
+
+use crate::{
+    core::{
+        ecs::{
+            containers::{
+                SystemDataContainer
+            },
+            ParallelSystemContext
+        },
+        GameState
+    }
+};
+
+pub trait ParallelSystem : Send {
+    type DataType: SystemDataContainer + Send;
+
+    fn setup(&mut self, game_controller: &mut Ref<GameState>);
+    fn run(&mut self, component_type: &mut Self::DataType, context: &mut ParallelSystemContext);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
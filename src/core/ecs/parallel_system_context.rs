@@ -0,0 +1,18 @@
+use crate::core::ecs::ParallelCommands;
+
+/// What a parallel system can reach besides it's data, game state stays at main thread.
+pub struct ParallelSystemContext<'a> {
+    commands: &'a mut ParallelCommands
+}
+
+impl<'a> ParallelSystemContext<'a> {
+    pub fn new(commands: &'a mut ParallelCommands) -> ParallelSystemContext<'a> {
+        ParallelSystemContext {
+            commands
+        }
+    }
+
+    pub fn commands(&mut self) -> &mut ParallelCommands {
+        self.commands
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    any,
     borrow::BorrowMut,
     cell::RefCell,
     collections::{
@@ -7,35 +8,85 @@ use std::{
         },
         HashMap
     },
-    rc::Weak
+    mem,
+    path::Path,
+    rc::Weak,
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard
+    },
+    thread,
+    time::Duration
 };
 
+use scoped_threadpool::Pool;
+
 use crate::{
     core::{
         ecs::{
+            components::{
+                ChildrenComponent,
+                GraphicRendererComponent,
+                ParentComponent,
+                TransformComponent
+            },
             containers::{
                 SystemDataContainer
             },
+            schedule::{
+                Schedule,
+                ScheduleError,
+                Stage,
+                SystemDescriptor
+            },
+            systems::{
+                RenderingSystem,
+                TransformPropagationSystem,
+                UpdateSystem
+            },
+            serialization::{
+                Prefab,
+                PrefabOverrides,
+                Scene,
+                SceneError,
+                SceneLoadContext,
+                Serializable,
+                SerializationRegistry
+            },
+            storage::ArchetypeStorage,
             AnySystem,
+            Commands,
             Component,
             Entity,
+            EntityAllocator,
+            EntityError,
             EntityId,
             EntityBuilder,
+            Events,
+            ParallelSystem,
+            Resources,
             System,
+            SystemContext
+        },
+        time::{
+            Time,
+            TimerFinished
         },
         GameState
     },
     events::{
         Event,
-        EventListener
+        EventDispatcher,
+        EventListener,
+        ListenerId
     },
     input::{
         InputEvent,
     },
     rendering::{
         GraphicsDevice,
-        ResourceDisposable,
-        panic_if_resource_isnt_disposed
+        Renderer
     },
     window::{
         WindowEvent,
@@ -45,198 +96,1335 @@ use crate::{
 pub struct Realm {
     pub(in crate::core) game_state: Weak<RefCell<GameState>>,
     systems: HashMap<String, AnySystem>,
-    entities: Option<HashMap<EntityId, Entity>>,
-    next_entity_id: EntityId,
-    disposed: bool
+    schedule: Schedule,
+    storage: ArchetypeStorage,
+    resources: Resources,
+    event_updaters: Vec<fn(&mut Resources)>,
+    input_listeners: EventDispatcher<InputEvent>,
+    window_listeners: EventDispatcher<WindowEvent>,
+    serialization: SerializationRegistry,
+    prefabs: HashMap<String, Prefab>,
+    entity_allocator: Arc<Mutex<EntityAllocator>>,
+    commands: Commands,
+    thread_pool: Option<Pool>
 }
 
 impl EventListener<InputEvent> for Realm {
     fn notify(&mut self, event: &mut Event<InputEvent>) {
-        match event.kind() {
-            InputEvent::MouseButton(e) => {
-                println!(
-                    "state: {:?}, button: {:?}, modifiers: {:?}",
-                    e.state,
-                    e.button,
-                    e.modifiers
-                );
-            },
-            InputEvent::Keyboard(e) => {
-                println!(
-                    "scan code: {:?}, state: {:?}, key: {:?}, modifiers: {:?}",
-                    e.scan_code,
-                    e.state,
-                    e.key,
-                    e.modifiers
-                );
-            },
-            InputEvent::MouseWheel { delta, phase, modifiers } => {
-                println!(
-                    "mouse wheel (delta: {:?}, phase: {:?}, modifiers: {:?})",
-                    delta,
-                    phase,
-                    modifiers
-                );
-            },
-            _ => ()
+        self.input_listeners.dispatch(event);
+
+        // consumed events never reaches systems
+        if !event.is_consumed() {
+            self.send_event(event.kind().clone());
         }
     }
 }
 
 impl EventListener<WindowEvent> for Realm {
     fn notify(&mut self, event: &mut Event<WindowEvent>) {
-    }
-}
-
-impl ResourceDisposable for Realm {
-    fn is_disposed(&self) -> bool {
-        self.disposed
-    }
-
-    fn dispose(&mut self, device: &GraphicsDevice) {
-        if self.disposed {
-            return;
-        }
+        self.window_listeners.dispatch(event);
 
-        self.disposed = true;
-        match self.entities {
-            Some(ref mut entities) => {
-                for entity in entities.values_mut() {
-                    for component in entity.get_mut_components() {
-                        //println!("freeing resource from component '{:?}'", component);
-                        component.dispose(device);
-                    }
-                }
-            },
-            None => ()
+        if !event.is_consumed() {
+            self.send_event(event.kind().clone());
         }
     }
 }
 
-impl Drop for Realm {
-    fn drop(&mut self) {
-        panic_if_resource_isnt_disposed!(self);
+impl Default for Realm {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl Realm {
     pub fn new() -> Realm {
-        Realm {
+        // commands reserve entity ids while systems runs, even from other threads
+        let entity_allocator = Arc::new(Mutex::new(EntityAllocator::new()));
+
+        let mut realm = Realm {
             game_state: Weak::new(),
             systems: HashMap::new(),
-            entities: Some(HashMap::new()),
-            next_entity_id: 0u64,
-            disposed: false
-        }
+            schedule: Schedule::new(),
+            storage: ArchetypeStorage::new(),
+            resources: Resources::new(),
+            event_updaters: Vec::new(),
+            input_listeners: EventDispatcher::new(),
+            window_listeners: EventDispatcher::new(),
+            serialization: SerializationRegistry::new(),
+            prefabs: HashMap::new(),
+            commands: Commands::new(Arc::clone(&entity_allocator)),
+            entity_allocator,
+            thread_pool: None
+        };
+
+        // hierarchy is stored by scenes on their own, global transforms are always recalculated
+        realm.register_component::<TransformComponent>();
+        realm.register_component::<GraphicRendererComponent>();
+
+        realm.add_event::<InputEvent>();
+        realm.add_event::<WindowEvent>();
+        realm.add_event::<TimerFinished>();
+        realm.add_event::<EntityError>();
+        realm.insert_resource(Time::new());
+
+        realm
     }
 
-    pub fn setup_systems(&mut self) {
-        match self.game_state.upgrade() {
-            Some(ref mut game_state) => {
-                for system in self.systems.values_mut() {
+    pub fn setup_systems(&mut self) -> Result<(), ScheduleError> {
+        self.schedule.build()?;
+
+        if let Some(ref mut game_state) = self.game_state.upgrade() {
+            for label in self.schedule.systems() {
+                if let Some(system) = self.systems.get_mut(label) {
                     system.setup(game_state.borrow().borrow_mut());
                 }
-            },
-            None => ()
+            }
         }
+
+        Ok(())
     }
 
     pub fn run_systems(&mut self) {
-        let mut entities_map = self.entities.take();
-
-        match entities_map {
-            Some(ref mut entities) => {
-                match self.game_state.upgrade() {
-                    Some(ref mut game_state) => {
-                        for system in self.systems.values_mut() {
-                            system.run(entities, game_state.borrow().borrow_mut());
-                        }
-                    },
-                    None => ()
-                }
-            },
-            None => panic!("Entities not found.")
+        for stage in Stage::all().iter() {
+            self.run_stage(*stage);
+        }
+    }
+
+    pub fn run_stage(&mut self, stage: Stage) {
+        if !self.schedule.is_built() {
+            if let Err(e) = self.schedule.build() {
+                panic!("Invalid system schedule: {}", e);
+            }
         }
 
-        self.entities = entities_map;
+        let batches = self.schedule.stage_batches(stage).to_vec();
+
+        for batch in batches {
+            let labels: Vec<String> = batch.into_iter()
+                                           .filter(|label| self.should_run_system(label))
+                                           .collect();
+
+            if labels.len() > 1 {
+                self.run_parallel_batch(&labels);
+                continue;
+            }
+
+            for label in labels {
+                let mut system = match self.systems.remove(&label) {
+                    Some(s) => s,
+                    None => continue
+                };
+
+                self.run_any_system(&mut system);
+                self.systems.insert(label, system);
+            }
+        }
     }
 
     pub fn run_system<T: Into<String>>(&mut self, label: T) {
-        let mut entities_map = self.entities.take();
-
-        match entities_map {
-            Some(ref mut entities) => {
-                match self.game_state.upgrade() {
-                    Some(ref mut game_state) => {
-                        let l = label.into();
-                        match self.systems.get_mut::<String>(&l) {
-                            Some(s) => s.run(entities, game_state.borrow().borrow_mut()),
-                            None => panic!("System with label '{}' not found.", l)
-                        }
-                    },
-                    None => ()
-                }
-            },
-            None => panic!("Entities not found.")
-        }
+        let l = label.into();
+        let mut system = match self.systems.remove(&l) {
+            Some(s) => s,
+            None => panic!("System with label '{}' not found.", l)
+        };
 
-        self.entities = entities_map;
+        self.run_any_system(&mut system);
+        self.systems.insert(l, system);
     }
 
-    pub fn upkeep(&self) {
+    pub fn upkeep(&mut self) {
+        self.apply_commands();
+        self.update_events();
+        self.storage.flush_removed();
     }
 
-    pub fn iter_systems(&self) -> Values<String, AnySystem> {
+    pub fn iter_systems(&self) -> Values<'_, String, AnySystem> {
         self.systems.values()
     }
 
-    pub fn get_system<'a, S: 'static + System, T: Into<String>>(&'a self, label: T) -> Option<&'a S> {
+    pub fn get_system<S: 'static, T: Into<String>>(&self, label: T) -> Option<&S> {
         match self.systems.get::<String>(&label.into()) {
             Some(any_system) => any_system.get_system::<S>(),
             None => None
         }
     }
 
-    pub fn get_mut_system<'a, S: 'static + System, T: Into<String>>(&'a mut self, label: T) -> Option<&'a mut S> {
+    pub fn get_mut_system<S: 'static, T: Into<String>>(&mut self, label: T) -> Option<&mut S> {
         match self.systems.get_mut::<String>(&label.into()) {
             Some(any_system) => any_system.get_mut_system::<S>(),
             None => None
         }
     }
 
-    pub fn register_system<K: Into<String>, U: 'static + SystemDataContainer, T: 'static + System<DataType = U>>(&mut self, label: K, system: T) {
-        self.systems.insert(label.into(), AnySystem::new(system));
+    pub fn register_system<K: Into<String>, U: 'static + SystemDataContainer, T: 'static + System<DataType = U>>(&mut self, label: K, system: T) -> &mut SystemDescriptor {
+        self.insert_system(label.into(), AnySystem::new(system))
     }
 
-    pub fn register_component<C: Component>(&mut self) {
+    pub fn register_parallel_system<K: Into<String>, U: 'static + SystemDataContainer + Send, T: 'static + ParallelSystem<DataType = U>>(&mut self, label: K, system: T) -> &mut SystemDescriptor {
+        self.insert_system(label.into(), AnySystem::new_parallel(system))
     }
 
-    pub fn add_entity(&mut self, mut entity: Entity) {
-        let mut entities_map = self.entities.take();
+    pub fn system_descriptor<T: Into<String>>(&mut self, label: T) -> Option<&mut SystemDescriptor> {
+        self.schedule.descriptor_mut(&label.into())
+    }
+
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    pub fn register_component<C: Component + Serializable>(&mut self) {
+        self.serialization.register_component::<C>();
+    }
+
+    pub fn register_resource<R: Serializable>(&mut self) {
+        self.serialization.register_resource::<R>();
+    }
 
-        if let Some(entities) = &mut entities_map {
-            if entities.contains_key(&entity.get_id()) {
-                // get a new id to entity
-                entity.id = self.next_entity_id();
+    pub fn serialization(&self) -> &SerializationRegistry {
+        &self.serialization
+    }
+
+    pub fn save_scene(&self) -> Result<Scene, SceneError> {
+        self.serialization.save_scene(&self.storage, &self.resources)
+    }
+
+    pub fn load_scene(&mut self, scene: &Scene, device: &mut GraphicsDevice) -> Result<Vec<EntityId>, SceneError> {
+        let mut entity_ids = HashMap::new();
+
+        for scene_entity in scene.entities.iter() {
+            if entity_ids.contains_key(&scene_entity.id) {
+                self.free_entity_ids(entity_ids.values());
+                return Err(SceneError::DuplicatedEntity(scene_entity.id));
             }
 
-            println!("Adding entity with id: {}", entity.id);
-            entities.insert(entity.id, entity);
-        } else {
-            panic!("Entities collection is missing.")
+            entity_ids.insert(scene_entity.id, self.allocate_entity_id());
         }
 
-        self.entities = entities_map;
+        let mut context = SceneLoadContext::new(entity_ids.clone(), device);
+
+        let (entities, resources) = match self.serialization.load_scene(scene, &mut context) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                self.free_entity_ids(entity_ids.values());
+                return Err(e);
+            }
+        };
+
+        // a scene is either fully spawned or not at all
+        let spawned = match self.spawn_scene_entities(scene, &entity_ids, entities) {
+            Ok(spawned) => spawned,
+            Err(e) => {
+                self.discard_entities(entity_ids.values());
+                return Err(e);
+            }
+        };
+
+        for resource in resources {
+            resource.insert_into(&mut self.resources);
+        }
+
+        Ok(spawned)
+    }
+
+    pub fn insert_prefab<T: Into<String>>(&mut self, name: T, prefab: Prefab) -> Option<Prefab> {
+        self.prefabs.insert(name.into(), prefab)
+    }
+
+    // prefab is named after it's file, without extension
+    pub fn load_prefab<P: AsRef<Path>>(&mut self, filepath: P) -> Result<String, SceneError> {
+        let filepath = filepath.as_ref();
+        let name = match filepath.file_stem().and_then(|stem| stem.to_str()) {
+            Some(stem) => stem.to_string(),
+            None => return Err(SceneError::UnknownFormat(filepath.to_path_buf()))
+        };
+
+        let prefab = Prefab::from_file(filepath)?;
+        self.prefabs.insert(name.clone(), prefab);
+        Ok(name)
+    }
+
+    pub fn remove_prefab(&mut self, name: &str) -> Option<Prefab> {
+        self.prefabs.remove(name)
+    }
+
+    pub fn prefab(&self, name: &str) -> Option<&Prefab> {
+        self.prefabs.get(name)
+    }
+
+    pub fn spawn_prefab(&mut self, name: &str, overrides: PrefabOverrides, device: &mut GraphicsDevice) -> Result<EntityId, SceneError> {
+        let scene = match self.prefabs.get(name) {
+            Some(prefab) => prefab.instantiate(name, &self.prefabs, &overrides)?,
+            None => return Err(SceneError::UnknownPrefab(name.to_string()))
+        };
+
+        let spawned = self.load_scene(&scene, device)?;
+
+        match spawned.iter().find(|entity_id| self.parent(**entity_id).is_none()) {
+            Some(root) => Ok(*root),
+            None => {
+                self.discard_entities(spawned.iter());
+                Err(SceneError::EmptyPrefab(name.to_string()))
+            }
+        }
+    }
+
+    pub fn storage(&self) -> &ArchetypeStorage {
+        &self.storage
+    }
+
+    pub fn insert_resource<T: 'static>(&mut self, resource: T) -> Option<T> {
+        self.resources.insert(resource)
+    }
+
+    pub fn remove_resource<T: 'static>(&mut self) -> Option<T> {
+        self.resources.remove::<T>()
+    }
+
+    pub fn resource<T: 'static>(&self) -> Option<&T> {
+        self.resources.get::<T>()
+    }
+
+    pub fn resource_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.resources.get_mut::<T>()
+    }
+
+    pub fn resources(&self) -> &Resources {
+        &self.resources
+    }
+
+    pub fn add_event<T: 'static>(&mut self) {
+        if self.resources.contains::<Events<T>>() {
+            return;
+        }
+
+        self.resources.insert(Events::<T>::new());
+        self.event_updaters.push(Realm::update_event::<T>);
+    }
+
+    pub fn send_event<T: 'static>(&mut self, event: T) {
+        let tick = self.storage.change_tick();
+
+        match self.resources.get_mut::<Events<T>>() {
+            Some(events) => events.send(event, tick),
+            None => panic!("Event '{}' isn't available, it should be added to realm first.", any::type_name::<T>())
+        }
+    }
+
+    pub fn events<T: 'static>(&self) -> Option<&Events<T>> {
+        self.resources.get::<Events<T>>()
+    }
+
+    pub fn register_input_listener<L: 'static + EventListener<InputEvent>>(&mut self, priority: i32, listener: L) -> ListenerId {
+        self.input_listeners.register(priority, listener)
+    }
+
+    pub fn unregister_input_listener(&mut self, id: ListenerId) -> bool {
+        self.input_listeners.unregister(id)
+    }
+
+    pub fn register_window_listener<L: 'static + EventListener<WindowEvent>>(&mut self, priority: i32, listener: L) -> ListenerId {
+        self.window_listeners.register(priority, listener)
+    }
+
+    pub fn unregister_window_listener(&mut self, id: ListenerId) -> bool {
+        self.window_listeners.unregister(id)
+    }
+
+    pub fn add_entity(&mut self, entity: Entity) -> Result<EntityId, EntityError> {
+        let entity_id = entity.get_id();
+
+        if !self.entity_allocator().is_alive(entity_id) {
+            return Err(EntityError::Dead(entity_id));
+        }
+
+        self.storage.insert(entity)?;
+        Ok(entity_id)
     }
 
     pub fn create_entity<'a>(&'a mut self) -> EntityBuilder<'a> {
-        let builder = EntityBuilder::new(self.next_entity_id(), self);
+        let builder = EntityBuilder::new(self.allocate_entity_id(), self);
         builder
     }
 
-    fn next_entity_id(&mut self) -> EntityId {
-        let entity_id = self.next_entity_id;
-        self.next_entity_id += 1;
-        entity_id
+    pub fn despawn(&mut self, entity_id: EntityId) -> Result<(), EntityError> {
+        if !self.is_alive(entity_id) {
+            return Err(EntityError::Dead(entity_id));
+        }
+
+        self.detach_from_hierarchy(entity_id);
+
+        // removed components are dropped, their gpu resources are released by handles
+        self.storage.remove(entity_id)?;
+        self.free_entity_id(entity_id);
+        Ok(())
+    }
+
+    pub fn despawn_recursive(&mut self, entity_id: EntityId) -> Result<(), EntityError> {
+        if !self.is_alive(entity_id) {
+            return Err(EntityError::Dead(entity_id));
+        }
+
+        for child in self.children_of(entity_id) {
+            self.despawn_recursive(child)?;
+        }
+
+        self.despawn(entity_id)
+    }
+
+    pub fn set_parent(&mut self, entity_id: EntityId, parent_id: EntityId) -> Result<(), EntityError> {
+        if !self.is_alive(entity_id) {
+            return Err(EntityError::Dead(entity_id));
+        }
+
+        if !self.is_alive(parent_id) {
+            return Err(EntityError::Dead(parent_id));
+        }
+
+        if entity_id == parent_id || self.is_ancestor(entity_id, parent_id) {
+            return Err(EntityError::InvalidParent {
+                entity_id,
+                parent_id
+            });
+        }
+
+        match self.parent(entity_id) {
+            Some(current_parent_id) if current_parent_id == parent_id => return Ok(()),
+            Some(current_parent_id) => {
+                if let Some(children) = self.storage.get_mut::<ChildrenComponent>(current_parent_id) {
+                    children.remove(entity_id);
+                }
+            },
+            None => ()
+        }
+
+        self.add_component(entity_id, ParentComponent::new(parent_id))?;
+
+        match self.storage.get_mut::<ChildrenComponent>(parent_id) {
+            Some(children) => children.push(entity_id),
+            None => {
+                let mut children = ChildrenComponent::new();
+                children.push(entity_id);
+                self.add_component(parent_id, children)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_parent(&mut self, entity_id: EntityId) -> Result<(), EntityError> {
+        if !self.is_alive(entity_id) {
+            return Err(EntityError::Dead(entity_id));
+        }
+
+        if let Some(parent_id) = self.parent(entity_id) {
+            if let Some(children) = self.storage.get_mut::<ChildrenComponent>(parent_id) {
+                children.remove(entity_id);
+            }
+        }
+
+        self.remove_component::<ParentComponent>(entity_id)
+    }
+
+    pub fn parent(&self, entity_id: EntityId) -> Option<EntityId> {
+        self.storage
+            .get::<ParentComponent>(entity_id)
+            .map(|parent| parent.get())
+    }
+
+    pub fn get_component<T: Component + 'static>(&self, entity_id: EntityId) -> Option<&T> {
+        self.storage.get::<T>(entity_id)
+    }
+
+    pub fn get_mut_component<T: Component + 'static>(&mut self, entity_id: EntityId) -> Option<&mut T> {
+        self.storage.get_mut::<T>(entity_id)
+    }
+
+    pub fn is_alive(&self, entity_id: EntityId) -> bool {
+        self.entity_allocator().is_alive(entity_id) && self.storage.contains(entity_id)
+    }
+
+    pub fn add_component<T: Component + 'static>(&mut self, entity_id: EntityId, component: T) -> Result<(), EntityError> {
+        if !self.is_alive(entity_id) {
+            return Err(EntityError::Dead(entity_id));
+        }
+
+        self.storage.add_component(entity_id, component)?;
+        Ok(())
+    }
+
+    pub fn remove_component<T: Component + 'static>(&mut self, entity_id: EntityId) -> Result<(), EntityError> {
+        if !self.is_alive(entity_id) {
+            return Err(EntityError::Dead(entity_id));
+        }
+
+        self.storage.remove_component::<T>(entity_id)?;
+        Ok(())
+    }
+
+    pub(in crate::core) fn register_default_systems(&mut self, renderer: Weak<RefCell<Renderer>>) {
+        self.register_system("update", UpdateSystem::new())
+            .in_stage(Stage::Update);
+
+        self.register_system("transform_propagation", TransformPropagationSystem::new())
+            .in_stage(Stage::PostUpdate);
+
+        self.register_system("rendering", RenderingSystem::new(renderer))
+            .in_stage(Stage::Render);
+    }
+
+    // removing time resource just stops it from advancing
+    pub(in crate::core) fn advance_time(&mut self, delta: Duration) {
+        let finished = match self.resources.get_mut::<Time>() {
+            Some(time) => time.advance(delta),
+            None => return
+        };
+
+        for event in finished {
+            self.send_event(event);
+        }
+    }
+
+    pub(super) fn allocate_entity_id(&mut self) -> EntityId {
+        self.entity_allocator().allocate()
+    }
+
+    pub(super) fn free_entity_id(&mut self, entity_id: EntityId) {
+        self.entity_allocator().free(entity_id);
+    }
+
+    fn free_entity_ids<'a, I: Iterator<Item = &'a EntityId>>(&mut self, entity_ids: I) {
+        let mut entity_allocator = self.entity_allocator();
+
+        for entity_id in entity_ids {
+            entity_allocator.free(*entity_id);
+        }
+    }
+
+    fn entity_allocator(&self) -> MutexGuard<'_, EntityAllocator> {
+        match self.entity_allocator.lock() {
+            Ok(entity_allocator) => entity_allocator,
+            Err(_) => panic!("Entity allocator was poisoned.")
+        }
+    }
+
+    fn spawn_scene_entities(&mut self, scene: &Scene, entity_ids: &HashMap<u32, EntityId>, entities: Vec<Entity>) -> Result<Vec<EntityId>, SceneError> {
+        let mut spawned = Vec::with_capacity(entities.len());
+
+        for entity in entities {
+            spawned.push(self.add_entity(entity).map_err(SceneError::Entity)?);
+        }
+
+        for scene_entity in scene.entities.iter() {
+            if let Some(parent) = scene_entity.parent {
+                self.set_parent(entity_ids[&scene_entity.id], entity_ids[&parent])
+                    .map_err(SceneError::Entity)?;
+            }
+        }
+
+        Ok(spawned)
+    }
+
+    // despawns entities which were spawned, the others only had their ids allocated
+    fn discard_entities<'a, I: Iterator<Item = &'a EntityId>>(&mut self, entity_ids: I) {
+        for entity_id in entity_ids {
+            if self.despawn(*entity_id).is_err() {
+                self.free_entity_id(*entity_id);
+            }
+        }
+    }
+
+    fn children_of(&self, entity_id: EntityId) -> Vec<EntityId> {
+        match self.storage.get::<ChildrenComponent>(entity_id) {
+            Some(children) => children.iter().copied().collect(),
+            None => Vec::new()
+        }
+    }
+
+    fn is_ancestor(&self, ancestor_id: EntityId, entity_id: EntityId) -> bool {
+        let mut current = self.parent(entity_id);
+
+        while let Some(parent_id) = current {
+            if parent_id == ancestor_id {
+                return true;
+            }
+
+            current = self.parent(parent_id);
+        }
+
+        false
+    }
+
+    fn detach_from_hierarchy(&mut self, entity_id: EntityId) {
+        if let Some(parent_id) = self.parent(entity_id) {
+            if let Some(children) = self.storage.get_mut::<ChildrenComponent>(parent_id) {
+                children.remove(entity_id);
+            }
+        }
+
+        // orphaned children becomes roots
+        for child in self.children_of(entity_id) {
+            if let Err(e) = self.remove_component::<ParentComponent>(child) {
+                eprintln!("Failed to detach child entity: {}", e);
+            }
+        }
+    }
+
+    fn insert_system(&mut self, label: String, system: AnySystem) -> &mut SystemDescriptor {
+        let access = system.access().clone();
+        let parallel = system.is_parallel();
+
+        self.systems.insert(label.clone(), system);
+        self.schedule.insert(label, access, parallel)
+    }
+
+    fn run_parallel_batch(&mut self, labels: &[String]) {
+        let mut systems: Vec<(String, AnySystem)> = labels.iter()
+                                                          .filter_map(|label| self.systems.remove_entry(label))
+                                                          .collect();
+
+        {
+            // threads are started once, the first time a realm runs a parallel batch
+            let thread_pool = self.thread_pool.get_or_insert_with(|| {
+                let thread_count = thread::available_parallelism().map_or(1, |count| count.get());
+                Pool::new(thread_count as u32)
+            });
+
+            let storage = &mut self.storage;
+            let resources = &mut self.resources;
+            let commands = &mut self.commands;
+            let mut jobs: Vec<_> = systems.iter_mut()
+                                          .map(|(_, system)| system.prepare_job(storage, resources, commands))
+                                          .collect();
+
+            // systems at the same batch only share columns they read
+            thread_pool.scoped(|scope| {
+                for job in jobs.iter_mut() {
+                    scope.execute(move || job.run());
+                }
+            });
+
+            // commands keep batch order, whichever thread ended first
+            for job in jobs {
+                job.give_back(storage, resources, commands);
+            }
+
+            storage.increment_change_tick();
+        }
+
+        self.systems.extend(systems);
+        self.apply_commands();
+    }
+
+    fn should_run_system(&mut self, label: &str) -> bool {
+        match self.game_state.upgrade() {
+            Some(game_state_strong_ref) => {
+                let game_state = RefCell::borrow(&game_state_strong_ref);
+                self.schedule.should_run(label, &game_state, self.resources.get::<Time>())
+            },
+            None => false
+        }
+    }
+
+    fn run_any_system(&mut self, system: &mut AnySystem) {
+        if let Some(game_state_strong_ref) = self.game_state.upgrade() {
+            let mut game_state = RefCell::borrow_mut(&game_state_strong_ref);
+            let mut context = SystemContext::new(&mut game_state, &mut self.commands);
+
+            system.run(&mut self.storage, &mut self.resources, &mut context);
+        }
+
+        // anything changed from now on is newer than what this system has seen
+        self.storage.increment_change_tick();
+
+        // sync point, structural changes requested by system are applied here
+        self.apply_commands();
+    }
+
+    // failed commands are sent as entity errors, for systems to react on
+    fn apply_commands(&mut self) {
+        if self.commands.is_empty() {
+            return;
+        }
+
+        let empty = self.commands.fork();
+        let mut commands = mem::replace(&mut self.commands, empty);
+        let errors = commands.apply(self);
+        self.commands = commands;
+
+        for error in errors {
+            self.send_event(error);
+        }
+    }
+
+    fn update_events(&mut self) {
+        for update in self.event_updaters.iter() {
+            update(&mut self.resources);
+        }
+    }
+
+    fn update_event<T: 'static>(resources: &mut Resources) {
+        if let Some(events) = resources.get_mut::<Events<T>>() {
+            events.update();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::Any,
+        cell::{
+            Cell,
+            Ref
+        },
+        rc::Rc,
+        sync::{
+            atomic::{
+                AtomicUsize,
+                Ordering
+            },
+            Arc
+        }
+    };
+
+    use super::*;
+    use crate::core::{
+        ecs::{
+            components::Updatable,
+            containers::{
+                EventReader,
+                QueryDataContainer,
+                Read,
+                Res,
+                ResMut,
+                Write
+            },
+            serialization::SceneEntity,
+            ParallelSystemContext
+        },
+        GameState
+    };
+
+    struct Position(f32);
+
+    impl Component for Position {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Updatable for Position {
+        fn before_update(&mut self) {
+        }
+
+        fn update(&mut self) {
+        }
+
+        fn late_update(&mut self) {
+        }
+    }
+
+    struct CountPositions {
+        seen: Arc<AtomicUsize>
+    }
+
+    impl ParallelSystem for CountPositions {
+        type DataType = QueryDataContainer<Read<Position>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, positions: &mut Self::DataType, _context: &mut ParallelSystemContext) {
+            self.seen.fetch_add(positions.iter_mut().count(), Ordering::SeqCst);
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct RecordThread {
+        threads: Arc<Mutex<Vec<thread::ThreadId>>>
+    }
+
+    impl ParallelSystem for RecordThread {
+        type DataType = QueryDataContainer<Read<Position>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _positions: &mut Self::DataType, _context: &mut ParallelSystemContext) {
+            self.threads.lock().unwrap().push(thread::current().id());
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct ReadScore;
+
+    impl ParallelSystem for ReadScore {
+        type DataType = Res<u32>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _score: &mut Self::DataType, _context: &mut ParallelSystemContext) {
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct AddScore;
+
+    impl ParallelSystem for AddScore {
+        type DataType = ResMut<u32>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, score: &mut Self::DataType, _context: &mut ParallelSystemContext) {
+            **score += 1;
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct MovePositions;
+
+    impl ParallelSystem for MovePositions {
+        type DataType = QueryDataContainer<Write<Position>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, positions: &mut Self::DataType, _context: &mut ParallelSystemContext) {
+            for (_, mut position) in positions.iter_mut() {
+                position.0 += 1.0;
+            }
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct SpawnPosition;
+
+    impl ParallelSystem for SpawnPosition {
+        type DataType = QueryDataContainer<Read<Position>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _positions: &mut Self::DataType, context: &mut ParallelSystemContext) {
+            context.commands()
+                   .spawn()
+                   .with_component(Position(10.0))
+                   .build();
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct SpawnWithChild {
+        spawned: Option<(EntityId, EntityId)>
+    }
+
+    impl System for SpawnWithChild {
+        type DataType = QueryDataContainer<Read<Position>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _positions: &mut Self::DataType, context: &mut SystemContext) {
+            if self.spawned.is_some() {
+                return;
+            }
+
+            let commands = context.commands();
+            let parent = commands.spawn().with_component(Position(0.0)).build();
+            let child = commands.spawn().with_component(Position(1.0)).build();
+
+            // reserved ids are valid at the next commands of the same queue
+            commands.set_parent(child, parent);
+            self.spawned = Some((parent, child));
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct DespawnTwice {
+        target: EntityId
+    }
+
+    impl System for DespawnTwice {
+        type DataType = QueryDataContainer<Read<Position>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _positions: &mut Self::DataType, context: &mut SystemContext) {
+            context.commands().despawn(self.target);
+            context.commands().despawn(self.target);
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct ReadNumbers {
+        seen: Vec<u32>
+    }
+
+    impl System for ReadNumbers {
+        type DataType = EventReader<u32>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, numbers: &mut Self::DataType, _context: &mut SystemContext) {
+            self.seen.extend(numbers.iter());
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct ConsumeChars {
+        notified: Rc<Cell<u32>>
+    }
+
+    impl EventListener<InputEvent> for ConsumeChars {
+        fn notify(&mut self, event: &mut Event<InputEvent>) {
+            self.notified.set(self.notified.get() + 1);
+
+            if let InputEvent::ReceivedChar(_) = event.kind() {
+                event.consume();
+            }
+        }
+    }
+
+    fn realm_with_positions(game_state: &Rc<RefCell<GameState>>, count: usize) -> Realm {
+        let mut realm = Realm::new();
+        realm.game_state = Rc::downgrade(game_state);
+
+        for i in 0..count {
+            realm.create_entity()
+                 .with_component(Position(i as f32))
+                 .build();
+        }
+
+        realm
+    }
+
+    fn batch_of<'a>(realm: &'a Realm, label: &str) -> &'a [String] {
+        realm.schedule()
+             .stage_batches(Stage::Update)
+             .iter()
+             .find(|batch| batch.iter().any(|l| l == label))
+             .expect("system isn't scheduled")
+    }
+
+    #[test]
+    fn despawned_entity_is_not_alive() {
+        let mut realm = Realm::new();
+        let entity_id = realm.create_entity().with_component(Position(1.0)).build();
+
+        realm.despawn(entity_id).unwrap();
+
+        assert!(!realm.is_alive(entity_id));
+        assert_eq!(0, realm.storage().entity_count());
+    }
+
+    #[test]
+    fn stale_ids_are_rejected() {
+        let mut realm = Realm::new();
+        let stale = realm.create_entity().with_component(Position(1.0)).build();
+        realm.despawn(stale).unwrap();
+
+        let reused = realm.create_entity().with_component(Position(2.0)).build();
+        assert_eq!(stale.index(), reused.index());
+        assert_ne!(stale.generation(), reused.generation());
+
+        assert!(realm.get_component::<Position>(stale).is_none());
+        assert!(matches!(realm.add_component(stale, Position(3.0)), Err(EntityError::Dead(id)) if id == stale));
+        assert!(matches!(realm.remove_component::<Position>(stale), Err(EntityError::Dead(_))));
+        assert!(matches!(realm.despawn(stale), Err(EntityError::Dead(_))));
+
+        // the entity which took the index over is untouched
+        assert!(realm.is_alive(reused));
+        assert_eq!(2.0, realm.get_component::<Position>(reused).unwrap().0);
+    }
+
+    #[test]
+    fn despawn_recursive_removes_the_whole_subtree() {
+        let mut realm = Realm::new();
+        let root = realm.create_entity().with_component(Position(0.0)).build();
+        let parent = realm.create_entity().with_component(Position(1.0)).build();
+        let child = realm.create_entity().with_component(Position(2.0)).build();
+        let grandchild = realm.create_entity().with_component(Position(3.0)).build();
+        let sibling = realm.create_entity().with_component(Position(4.0)).build();
+
+        realm.set_parent(parent, root).unwrap();
+        realm.set_parent(child, parent).unwrap();
+        realm.set_parent(grandchild, child).unwrap();
+        realm.set_parent(sibling, root).unwrap();
+
+        realm.despawn_recursive(parent).unwrap();
+
+        assert!(!realm.is_alive(parent));
+        assert!(!realm.is_alive(child));
+        assert!(!realm.is_alive(grandchild));
+        assert!(realm.is_alive(root));
+        assert!(realm.is_alive(sibling));
+
+        // despawned subtree is detached from the rest of the hierarchy
+        assert_eq!(vec!(sibling), realm.children_of(root));
+        assert_eq!(2, realm.storage().entity_count());
+    }
+
+    #[test]
+    fn readers_of_the_same_component_share_a_batch() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 3);
+        let first = Arc::new(AtomicUsize::new(0));
+        let second = Arc::new(AtomicUsize::new(0));
+
+        realm.register_parallel_system("first", CountPositions { seen: first.clone() });
+        realm.register_parallel_system("second", CountPositions { seen: second.clone() });
+        realm.setup_systems().unwrap();
+
+        assert_eq!(2, batch_of(&realm, "first").len());
+
+        realm.run_stage(Stage::Update);
+        assert_eq!(3, first.load(Ordering::SeqCst));
+        assert_eq!(3, second.load(Ordering::SeqCst));
+
+        // shared column is back at it's archetype and still writable
+        realm.register_parallel_system("move", MovePositions);
+        realm.run_stage(Stage::Update);
+        assert_eq!(6, first.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn parallel_batches_reuse_pool_threads() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 1);
+        let threads = Arc::new(Mutex::new(Vec::new()));
+
+        realm.register_parallel_system("first", RecordThread { threads: threads.clone() });
+        realm.register_parallel_system("second", RecordThread { threads: threads.clone() });
+        realm.setup_systems().unwrap();
+
+        for _ in 0..10 {
+            realm.run_stage(Stage::Update);
+        }
+
+        let mut threads = threads.lock().unwrap().clone();
+        assert_eq!(20, threads.len());
+        assert!(!threads.contains(&thread::current().id()));
+
+        // every run went through the same few threads, none was started for a single system
+        threads.sort_by_key(|id| format!("{:?}", id));
+        threads.dedup();
+        assert!(threads.len() <= realm.thread_pool.as_ref().unwrap().thread_count() as usize);
+    }
+
+    #[test]
+    fn writer_runs_apart_from_readers() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 1);
+
+        realm.register_parallel_system("read", CountPositions { seen: Arc::new(AtomicUsize::new(0)) });
+        realm.register_parallel_system("write", MovePositions);
+        realm.setup_systems().unwrap();
+
+        assert_eq!(vec!["read".to_owned()], batch_of(&realm, "read"));
+        assert_eq!(vec!["write".to_owned()], batch_of(&realm, "write"));
+    }
+
+    #[test]
+    fn resource_writer_runs_apart_from_readers() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 0);
+        realm.insert_resource(0u32);
+
+        realm.register_parallel_system("first_reader", ReadScore);
+        realm.register_parallel_system("second_reader", ReadScore);
+        realm.register_parallel_system("writer", AddScore);
+        realm.setup_systems().unwrap();
+
+        assert_eq!(2, batch_of(&realm, "first_reader").len());
+        assert_eq!(vec!["writer".to_owned()], batch_of(&realm, "writer"));
+
+        realm.run_stage(Stage::Update);
+        assert_eq!(Some(&1), realm.resource::<u32>());
+    }
+
+    #[test]
+    fn parallel_systems_spawn_through_commands() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 2);
+        let seen = Arc::new(AtomicUsize::new(0));
+
+        realm.register_parallel_system("spawn", SpawnPosition);
+        realm.register_parallel_system("count", CountPositions { seen: seen.clone() });
+        realm.setup_systems().unwrap();
+        assert_eq!(2, batch_of(&realm, "spawn").len());
+
+        realm.run_stage(Stage::Update);
+        assert_eq!(2, seen.load(Ordering::SeqCst));
+        assert_eq!(3, realm.storage().entity_count());
+
+        // spawning alone runs at main thread, through the same commands
+        realm.system_descriptor("count").unwrap().after("spawn");
+        realm.setup_systems().unwrap();
+        realm.run_stage(Stage::Update);
+        assert_eq!(4, realm.storage().entity_count());
+    }
+
+    #[test]
+    fn spawn_command_returns_reserved_entity_id() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 0);
+
+        realm.register_system("spawn", SpawnWithChild { spawned: None });
+        realm.run_system("spawn");
+
+        let (parent, child) = realm.get_system::<SpawnWithChild, _>("spawn").unwrap().spawned.unwrap();
+        assert!(realm.is_alive(parent));
+        assert!(realm.is_alive(child));
+        assert_eq!(Some(parent), realm.parent(child));
+        assert!(realm.events::<EntityError>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn events_live_for_two_upkeeps() {
+        let mut realm = Realm::new();
+        realm.add_event::<u32>();
+        realm.send_event(5u32);
+
+        realm.upkeep();
+        assert_eq!(1, realm.events::<u32>().unwrap().len());
+
+        realm.upkeep();
+        assert!(realm.events::<u32>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn each_reader_keeps_its_own_cursor() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 0);
+        realm.add_event::<u32>();
+        realm.register_system("first", ReadNumbers { seen: Vec::new() });
+        realm.register_system("second", ReadNumbers { seen: Vec::new() });
+
+        realm.send_event(1u32);
+        realm.run_system("first");
+        realm.send_event(2u32);
+        realm.run_system("first");
+        realm.run_system("second");
+        realm.run_system("first");
+
+        assert_eq!(vec![1, 2], realm.get_system::<ReadNumbers, _>("first").unwrap().seen);
+        assert_eq!(vec![1, 2], realm.get_system::<ReadNumbers, _>("second").unwrap().seen);
+
+        // still buffered after one upkeep, so a reader running late doesn't miss it
+        realm.send_event(3u32);
+        realm.upkeep();
+        realm.run_system("second");
+        assert_eq!(vec![1, 2, 3], realm.get_system::<ReadNumbers, _>("second").unwrap().seen);
+    }
+
+    #[test]
+    fn only_unconsumed_input_reaches_systems() {
+        let mut realm = Realm::new();
+        let notified = Rc::new(Cell::new(0));
+        realm.register_input_listener(0, ConsumeChars { notified: Rc::clone(&notified) });
+
+        realm.notify(&mut Event::new(InputEvent::ReceivedChar('a')));
+        realm.notify(&mut Event::new(InputEvent::CursorEntered));
+
+        let events: Vec<_> = realm.events::<InputEvent>().unwrap().iter().collect();
+        assert_eq!(2, notified.get());
+        assert_eq!(1, events.len());
+        assert!(matches!(events[0], InputEvent::CursorEntered));
+    }
+
+    #[test]
+    fn consumed_input_skips_lower_priority_listeners() {
+        let mut realm = Realm::new();
+        let first = Rc::new(Cell::new(0));
+        let second = Rc::new(Cell::new(0));
+        realm.register_input_listener(10, ConsumeChars { notified: Rc::clone(&first) });
+        let id = realm.register_input_listener(0, ConsumeChars { notified: Rc::clone(&second) });
+
+        // lower priority listener never sees what the first one consumed
+        realm.notify(&mut Event::new(InputEvent::ReceivedChar('a')));
+        assert_eq!((1, 0), (first.get(), second.get()));
+
+        assert!(realm.unregister_input_listener(id));
+        realm.notify(&mut Event::new(InputEvent::CursorLeft));
+        assert_eq!((2, 0), (first.get(), second.get()));
+        assert_eq!(1, realm.events::<InputEvent>().unwrap().len());
+    }
+
+    #[test]
+    fn failed_commands_are_sent_as_events() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 1);
+        let target = realm.create_entity().with_component(Position(0.0)).build();
+
+        realm.register_system("despawn", DespawnTwice { target });
+        realm.run_system("despawn");
+
+        let errors: Vec<_> = realm.events::<EntityError>().unwrap().iter().collect();
+        assert_eq!(1, errors.len());
+        assert!(matches!(errors[0], EntityError::Dead(entity_id) if *entity_id == target));
+        assert_eq!(1, realm.storage().entity_count());
+    }
+
+    #[cfg(feature = "no-backend")]
+    fn headless_device() -> GraphicsDevice {
+        GraphicsDevice::new(crate::rendering::backend::DeviceAdapterBackend::new(), 0)
+    }
+
+    #[cfg(feature = "no-backend")]
+    #[test]
+    fn failed_scene_leaves_nothing_spawned() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 1);
+        let mut device = headless_device();
+
+        // each entity is parent of the other, hierarchy fails after both were spawned
+        let mut scene = Scene::new();
+        let mut first = SceneEntity::new(0);
+        first.parent = Some(1);
+        let mut second = SceneEntity::new(1);
+        second.parent = Some(0);
+        scene.entities.push(first);
+        scene.entities.push(second);
+
+        match realm.load_scene(&scene, &mut device) {
+            Err(SceneError::Entity(EntityError::InvalidParent { .. })) => (),
+            other => panic!("unexpected scene result: {:?}", other)
+        }
+
+        assert_eq!(1, realm.storage().entity_count());
+
+        // valid scenes still load afterwards
+        let mut scene = Scene::new();
+        scene.entities.push(SceneEntity::new(0));
+        let mut child = SceneEntity::new(1);
+        child.parent = Some(0);
+        scene.entities.push(child);
+
+        let spawned = realm.load_scene(&scene, &mut device).unwrap();
+        assert_eq!(3, realm.storage().entity_count());
+        assert_eq!(Some(spawned[0]), realm.parent(spawned[1]));
+    }
+
+    #[cfg(feature = "no-backend")]
+    #[test]
+    fn spawned_prefab_returns_root() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 0);
+        let mut device = headless_device();
+
+        let mut prefab = Prefab::new();
+        prefab.children.push(Prefab::new());
+        prefab.children.push(Prefab::new());
+        realm.insert_prefab("group", prefab);
+
+        let root = realm.spawn_prefab("group", PrefabOverrides::new(), &mut device).unwrap();
+        assert_eq!(None, realm.parent(root));
+        assert_eq!(2, realm.children_of(root).len());
+        assert_eq!(3, realm.storage().entity_count());
+    }
+
+    struct ReadAndWritePositions;
+
+    impl System for ReadAndWritePositions {
+        type DataType = QueryDataContainer<(Read<Position>, Write<Position>)>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _positions: &mut Self::DataType, _context: &mut SystemContext) {
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn duplicated_access_fails_at_setup() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm_with_positions(&game_state, 1);
+
+        realm.register_system("duplicated", ReadAndWritePositions);
+
+        match realm.setup_systems() {
+            Err(ScheduleError::DuplicatedAccess { system, .. }) => assert_eq!("duplicated", system),
+            other => panic!("unexpected schedule result: {:?}", other)
+        }
     }
 }
+
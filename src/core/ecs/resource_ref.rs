@@ -0,0 +1,29 @@
+use std::{
+    ops::Deref,
+    ptr::NonNull
+};
+
+/// Shared view of a resource lent by realm's resources, many readers may hold one at the same time.
+pub struct ResourceRef<T: 'static> {
+    resource: NonNull<T>
+}
+
+// resource is only read through it, and resources refuses to move or change a lent resource
+unsafe impl<T: Sync + 'static> Send for ResourceRef<T> {}
+
+impl<T: 'static> ResourceRef<T> {
+    pub(super) fn new(resource: &T) -> ResourceRef<T> {
+        ResourceRef {
+            resource: NonNull::from(resource)
+        }
+    }
+}
+
+impl<T: 'static> Deref for ResourceRef<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // resource is kept boxed and untouched at resources until this is returned
+        unsafe { self.resource.as_ref() }
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    any::{
+        self,
+        Any,
+        TypeId
+    },
+    collections::HashMap
+};
+
+use crate::core::ecs::ResourceRef;
+
+pub struct Resources {
+    resources: HashMap<TypeId, Box<dyn Any>>,
+    lent: HashMap<TypeId, usize>
+}
+
+impl Default for Resources {
+    fn default() -> Self {
+        Resources::new()
+    }
+}
+
+impl Resources {
+    pub fn new() -> Resources {
+        Resources {
+            resources: HashMap::new(),
+            lent: HashMap::new()
+        }
+    }
+
+    pub fn insert<T: 'static>(&mut self, resource: T) -> Option<T> {
+        if self.lent.contains_key(&TypeId::of::<T>()) {
+            panic!("Resource '{}' can't be replaced while it's lent.", any::type_name::<T>());
+        }
+
+        self.resources
+            .insert(TypeId::of::<T>(), Box::new(resource))
+            .and_then(|previous| previous.downcast::<T>().ok())
+            .map(|previous| *previous)
+    }
+
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.take::<T>().map(|resource| *resource)
+    }
+
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.resources.contains_key(&TypeId::of::<T>())
+    }
+
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.resources
+            .get(&TypeId::of::<T>())
+            .and_then(|resource| resource.downcast_ref::<T>())
+    }
+
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        if self.lent.contains_key(&TypeId::of::<T>()) {
+            return None;
+        }
+
+        self.resources
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|resource| resource.downcast_mut::<T>())
+    }
+
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    pub(in crate::core::ecs) fn take<T: 'static>(&mut self) -> Option<Box<T>> {
+        if self.lent.contains_key(&TypeId::of::<T>()) {
+            return None;
+        }
+
+        self.resources
+            .remove(&TypeId::of::<T>())
+            .and_then(|resource| resource.downcast::<T>().ok())
+    }
+
+    pub(in crate::core::ecs) fn give_back<T: 'static>(&mut self, resource: Box<T>) {
+        if self.resources.insert(TypeId::of::<T>(), resource).is_some() {
+            panic!("Resource '{}' was replaced while it was borrowed.", any::type_name::<T>());
+        }
+    }
+
+    /// Lends resource to be read, it can be lent many times while it isn't taken.
+    pub(in crate::core::ecs) fn lend<T: 'static>(&mut self) -> Option<ResourceRef<T>> {
+        let type_id = TypeId::of::<T>();
+
+        let resource = self.resources
+                           .get(&type_id)
+                           .and_then(|resource| resource.downcast_ref::<T>())
+                           .map(ResourceRef::new)?;
+
+        *self.lent.entry(type_id).or_insert(0) += 1;
+        Some(resource)
+    }
+
+    pub(in crate::core::ecs) fn return_lent<T: 'static>(&mut self, _resource: ResourceRef<T>) {
+        let type_id = TypeId::of::<T>();
+
+        match self.lent.get_mut(&type_id) {
+            Some(count) => {
+                *count -= 1;
+
+                if *count == 0 {
+                    self.lent.remove(&type_id);
+                }
+            },
+            None => panic!("Resource '{}' wasn't lent.", any::type_name::<T>())
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#[allow(clippy::module_inception)]
+mod schedule;
+pub use schedule::Schedule;
+
+mod schedule_error;
+pub use schedule_error::ScheduleError;
+
+mod stage;
+pub use stage::Stage;
+
+mod run_criteria;
+pub use run_criteria::RunCriteria;
+
+mod system_descriptor;
+pub use system_descriptor::SystemDescriptor;
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use crate::core::GameState;
+
+#[derive(Default)]
+pub enum RunCriteria {
+    #[default]
+    Always,
+    Every(Duration),
+    When(Box<dyn Fn(&GameState) -> bool>)
+}
+
+impl RunCriteria {
+    pub fn every(interval: Duration) -> RunCriteria {
+        RunCriteria::Every(interval)
+    }
+
+    pub fn when<F: 'static + Fn(&GameState) -> bool>(predicate: F) -> RunCriteria {
+        RunCriteria::When(Box::new(predicate))
+    }
+}
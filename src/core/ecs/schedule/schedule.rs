@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+
+use crate::core::{
+    ecs::{
+        containers::ComponentAccess,
+        schedule::{
+            ScheduleError,
+            Stage,
+            SystemDescriptor
+        }
+    },
+    time::Time,
+    GameState
+};
+
+pub struct Schedule {
+    descriptors: HashMap<String, SystemDescriptor>,
+    registration_order: Vec<String>,
+    stages: Vec<(Stage, Vec<Vec<String>>)>,
+    built: bool
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Schedule::new()
+    }
+}
+
+impl Schedule {
+    pub fn new() -> Schedule {
+        Schedule {
+            descriptors: HashMap::new(),
+            registration_order: Vec::new(),
+            stages: Vec::new(),
+            built: false
+        }
+    }
+
+    pub fn insert<T: Into<String>>(&mut self, label: T, access: ComponentAccess, parallel: bool) -> &mut SystemDescriptor {
+        let label = label.into();
+        self.built = false;
+
+        if !self.descriptors.contains_key(&label) {
+            self.registration_order.push(label.clone());
+        }
+
+        self.descriptors.insert(label.clone(), SystemDescriptor::with_access(label.clone(), access, parallel));
+
+        match self.descriptors.get_mut(&label) {
+            Some(descriptor) => descriptor,
+            None => unreachable!()
+        }
+    }
+
+    pub fn descriptor(&self, label: &str) -> Option<&SystemDescriptor> {
+        self.descriptors.get(label)
+    }
+
+    pub fn descriptor_mut(&mut self, label: &str) -> Option<&mut SystemDescriptor> {
+        // descriptor may have its stage or constraints changed
+        self.built = false;
+        self.descriptors.get_mut(label)
+    }
+
+    pub fn is_built(&self) -> bool {
+        self.built
+    }
+
+    pub fn build(&mut self) -> Result<(), ScheduleError> {
+        self.validate_access()?;
+        self.validate_constraints()?;
+
+        let mut stages = Vec::new();
+
+        for stage in Stage::all().iter() {
+            let sorted = self.sort_stage(*stage)?;
+            stages.push((*stage, self.batch_stage(sorted)));
+        }
+
+        self.stages = stages;
+        self.built = true;
+        Ok(())
+    }
+
+    pub fn stage_batches(&self, stage: Stage) -> &[Vec<String>] {
+        match self.stages.iter().find(|(s, _)| *s == stage) {
+            Some((_, batches)) => batches,
+            None => &[]
+        }
+    }
+
+    pub fn systems(&self) -> impl Iterator<Item = &String> {
+        self.stages
+            .iter()
+            .flat_map(|(_, batches)| batches.iter())
+            .flat_map(|labels| labels.iter())
+    }
+
+    pub fn should_run(&mut self, label: &str, game_state: &GameState, time: Option<&Time>) -> bool {
+        match self.descriptors.get_mut(label) {
+            Some(descriptor) => descriptor.should_run(game_state, time),
+            None => false
+        }
+    }
+
+    // a system can't be handed the same data twice if any of them is written
+    fn validate_access(&self) -> Result<(), ScheduleError> {
+        for label in &self.registration_order {
+            if let Some(data) = self.descriptors[label].access().duplicated().first() {
+                return Err(ScheduleError::DuplicatedAccess {
+                    system: label.clone(),
+                    data: (*data).to_owned()
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_constraints(&self) -> Result<(), ScheduleError> {
+        for label in &self.registration_order {
+            let descriptor = &self.descriptors[label];
+
+            for dependency in descriptor.get_before() {
+                let dependency_descriptor = self.dependency(label, dependency)?;
+
+                if descriptor.stage() > dependency_descriptor.stage() {
+                    return Err(ScheduleError::StageConflict {
+                        system: label.clone(),
+                        dependency: dependency.clone()
+                    });
+                }
+            }
+
+            for dependency in descriptor.get_after() {
+                let dependency_descriptor = self.dependency(label, dependency)?;
+
+                if descriptor.stage() < dependency_descriptor.stage() {
+                    return Err(ScheduleError::StageConflict {
+                        system: label.clone(),
+                        dependency: dependency.clone()
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn dependency(&self, label: &str, dependency: &str) -> Result<&SystemDescriptor, ScheduleError> {
+        self.descriptors
+            .get(dependency)
+            .ok_or_else(|| ScheduleError::UnknownSystem {
+                system: label.to_owned(),
+                dependency: dependency.to_owned()
+            })
+    }
+
+    fn batch_stage(&self, sorted: Vec<String>) -> Vec<Vec<String>> {
+        // consecutive systems are grouped while they can all run alongside each other,
+        // anything in between two ordered systems always ends up splitting them
+        let mut batches: Vec<Vec<String>> = Vec::new();
+
+        for label in sorted {
+            let descriptor = &self.descriptors[&label];
+
+            match batches.last_mut() {
+                Some(batch) if batch.iter().all(|l| descriptor.can_run_alongside(&self.descriptors[l])) => {
+                    batch.push(label);
+                },
+                _ => batches.push(vec![label])
+            }
+        }
+
+        batches
+    }
+
+    fn sort_stage(&self, stage: Stage) -> Result<Vec<String>, ScheduleError> {
+        // registration order is used to break ties, so the result is always the same
+        let labels: Vec<&String> = self.registration_order
+                                       .iter()
+                                       .filter(|label| self.descriptors[*label].stage() == stage)
+                                       .collect();
+
+        let index_of = |label: &String| labels.iter().position(|l| *l == label);
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); labels.len()];
+        let mut in_degree = vec![0usize; labels.len()];
+
+        for (index, label) in labels.iter().enumerate() {
+            let descriptor = &self.descriptors[*label];
+
+            for dependency in descriptor.get_before() {
+                if let Some(dependency_index) = index_of(dependency) {
+                    dependents[index].push(dependency_index);
+                    in_degree[dependency_index] += 1;
+                }
+            }
+
+            for dependency in descriptor.get_after() {
+                if let Some(dependency_index) = index_of(dependency) {
+                    dependents[dependency_index].push(index);
+                    in_degree[index] += 1;
+                }
+            }
+        }
+
+        let mut sorted = Vec::with_capacity(labels.len());
+        let mut visited = vec![false; labels.len()];
+
+        while sorted.len() < labels.len() {
+            let next = (0..labels.len()).find(|i| !visited[*i] && in_degree[*i] == 0);
+
+            match next {
+                Some(index) => {
+                    visited[index] = true;
+
+                    for dependent in &dependents[index] {
+                        in_degree[*dependent] -= 1;
+                    }
+
+                    sorted.push(labels[index].clone());
+                },
+                None => {
+                    return Err(ScheduleError::Cycle {
+                        stage,
+                        systems: (0..labels.len()).filter(|i| !visited[*i])
+                                                  .map(|i| labels[i].clone())
+                                                  .collect()
+                    });
+                }
+            }
+        }
+
+        Ok(sorted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ecs::{
+        test_components::{
+            Health,
+            Position,
+            Velocity
+        },
+        Component
+    };
+
+    fn reading<T: Component + 'static>() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        access.add_read::<T>();
+        access
+    }
+
+    fn writing<T: Component + 'static>() -> ComponentAccess {
+        let mut access = ComponentAccess::new();
+        access.add_write::<T>();
+        access
+    }
+
+    fn flattened(schedule: &Schedule, stage: Stage) -> Vec<&str> {
+        schedule.stage_batches(stage)
+                .iter()
+                .flat_map(|batch| batch.iter())
+                .map(String::as_str)
+                .collect()
+    }
+
+    #[test]
+    fn before_and_after_order_systems() {
+        let mut schedule = Schedule::new();
+        schedule.insert("render_prep", ComponentAccess::new(), false).after("physics");
+        schedule.insert("physics", ComponentAccess::new(), false);
+        schedule.insert("input", ComponentAccess::new(), false).before("physics");
+        schedule.insert("audio", ComponentAccess::new(), false);
+
+        schedule.build().unwrap();
+
+        // unconstrained systems keep their registration order
+        assert_eq!(vec!("input", "physics", "render_prep", "audio"), flattened(&schedule, Stage::Update));
+    }
+
+    #[test]
+    fn cyclic_constraints_fail_to_build() {
+        let mut schedule = Schedule::new();
+        schedule.insert("first", ComponentAccess::new(), false).before("second");
+        schedule.insert("second", ComponentAccess::new(), false).before("third");
+        schedule.insert("third", ComponentAccess::new(), false).before("first");
+        schedule.insert("free", ComponentAccess::new(), false);
+
+        match schedule.build() {
+            Err(ScheduleError::Cycle { stage, mut systems }) => {
+                systems.sort();
+                assert_eq!(Stage::Update, stage);
+                assert_eq!(vec!("first", "second", "third"), systems);
+            },
+            _ => panic!("Cyclic constraints should fail to build.")
+        }
+
+        assert!(!schedule.is_built());
+    }
+
+    #[test]
+    fn constraints_across_stages_must_follow_stage_order() {
+        let mut schedule = Schedule::new();
+        schedule.insert("late", ComponentAccess::new(), false).in_stage(Stage::PostUpdate).before("early");
+        schedule.insert("early", ComponentAccess::new(), false).in_stage(Stage::PreUpdate);
+
+        assert!(matches!(schedule.build(), Err(ScheduleError::StageConflict { system, dependency }) if system == "late" && dependency == "early"));
+
+        // same constraint is already satisfied the other way around
+        schedule.insert("late", ComponentAccess::new(), false).in_stage(Stage::PostUpdate).after("early");
+        schedule.build().unwrap();
+
+        schedule.insert("lost", ComponentAccess::new(), false).after("missing");
+        assert!(matches!(schedule.build(), Err(ScheduleError::UnknownSystem { .. })));
+    }
+
+    #[test]
+    fn non_conflicting_parallel_systems_share_a_batch() {
+        let mut schedule = Schedule::new();
+        schedule.insert("ai", reading::<Position>(), true);
+        schedule.insert("physics", writing::<Velocity>(), true);
+        schedule.insert("mover", writing::<Position>(), true);
+        schedule.insert("regen", writing::<Health>(), true);
+        schedule.insert("damage", writing::<Health>(), true).after("regen");
+        schedule.insert("ui", reading::<Health>(), false);
+
+        schedule.build().unwrap();
+
+        let batches: Vec<Vec<&str>> = schedule.stage_batches(Stage::Update)
+                                              .iter()
+                                              .map(|batch| batch.iter().map(String::as_str).collect())
+                                              .collect();
+
+        // writer of a read component and ordered systems are split, exclusive systems run alone
+        assert_eq!(vec!(vec!("ai", "physics"), vec!("mover", "regen"), vec!("damage"), vec!("ui")), batches);
+    }
+}
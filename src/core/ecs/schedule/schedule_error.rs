@@ -0,0 +1,57 @@
+use std::{
+    error::{
+        Error
+    },
+    fmt::{
+        self,
+        Display,
+        Formatter
+    }
+};
+
+use crate::core::ecs::schedule::Stage;
+
+#[derive(Debug)]
+pub enum ScheduleError {
+    Cycle {
+        stage: Stage,
+        systems: Vec<String>
+    },
+    UnknownSystem {
+        system: String,
+        dependency: String
+    },
+    StageConflict {
+        system: String,
+        dependency: String
+    },
+    DuplicatedAccess {
+        system: String,
+        data: String
+    }
+}
+
+impl Display for ScheduleError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleError::Cycle { stage, systems } => {
+                write!(fmt, "Systems at stage '{}' have cyclic ordering constraints: {}", stage, systems.join(", "))
+            },
+            ScheduleError::UnknownSystem { system, dependency } => {
+                write!(fmt, "System '{}' has an ordering constraint with unknown system '{}'.", system, dependency)
+            },
+            ScheduleError::StageConflict { system, dependency } => {
+                write!(fmt, "System '{}' ordering constraint with '{}' contradicts their stages order.", system, dependency)
+            },
+            ScheduleError::DuplicatedAccess { system, data } => {
+                write!(fmt, "System '{}' asks for '{}' more than once, while writing it.", system, data)
+            }
+        }
+    }
+}
+
+impl Error for ScheduleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum Stage {
+    PreUpdate,
+    Update,
+    PostUpdate,
+    Render
+}
+
+impl Display for Stage {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::PreUpdate => write!(fmt, "pre-update"),
+            Stage::Update => write!(fmt, "update"),
+            Stage::PostUpdate => write!(fmt, "post-update"),
+            Stage::Render => write!(fmt, "render")
+        }
+    }
+}
+
+impl Stage {
+    pub fn all() -> [Stage; 4] {
+        [
+            Stage::PreUpdate,
+            Stage::Update,
+            Stage::PostUpdate,
+            Stage::Render
+        ]
+    }
+}
@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use crate::core::{
+    ecs::{
+        containers::ComponentAccess,
+        schedule::{
+            RunCriteria,
+            Stage
+        }
+    },
+    time::Time,
+    GameState
+};
+
+pub struct SystemDescriptor {
+    label: String,
+    stage: Stage,
+    before: Vec<String>,
+    after: Vec<String>,
+    run_criteria: RunCriteria,
+    last_run: Option<Duration>,
+    access: ComponentAccess,
+    parallel: bool
+}
+
+impl SystemDescriptor {
+    pub fn new<T: Into<String>>(label: T) -> SystemDescriptor {
+        SystemDescriptor {
+            label: label.into(),
+            stage: Stage::Update,
+            before: Vec::new(),
+            after: Vec::new(),
+            run_criteria: RunCriteria::Always,
+            last_run: None,
+            access: ComponentAccess::exclusive(),
+            parallel: false
+        }
+    }
+
+    pub fn with_access(label: String, access: ComponentAccess, parallel: bool) -> SystemDescriptor {
+        SystemDescriptor {
+            access,
+            parallel,
+            ..SystemDescriptor::new(label)
+        }
+    }
+
+    pub fn in_stage(&mut self, stage: Stage) -> &mut SystemDescriptor {
+        self.stage = stage;
+        self
+    }
+
+    pub fn before<T: Into<String>>(&mut self, label: T) -> &mut SystemDescriptor {
+        self.before.push(label.into());
+        self
+    }
+
+    pub fn after<T: Into<String>>(&mut self, label: T) -> &mut SystemDescriptor {
+        self.after.push(label.into());
+        self
+    }
+
+    pub fn with_run_criteria(&mut self, run_criteria: RunCriteria) -> &mut SystemDescriptor {
+        self.run_criteria = run_criteria;
+        self
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn stage(&self) -> Stage {
+        self.stage
+    }
+
+    pub fn get_before(&self) -> &[String] {
+        &self.before
+    }
+
+    pub fn get_after(&self) -> &[String] {
+        &self.after
+    }
+
+    pub fn access(&self) -> &ComponentAccess {
+        &self.access
+    }
+
+    pub fn is_parallel(&self) -> bool {
+        self.parallel
+    }
+
+    pub fn is_ordered_with(&self, other: &SystemDescriptor) -> bool {
+        self.before.iter().chain(self.after.iter()).any(|label| *label == other.label)
+            || other.before.iter().chain(other.after.iter()).any(|label| *label == self.label)
+    }
+
+    pub fn can_run_alongside(&self, other: &SystemDescriptor) -> bool {
+        self.parallel
+            && other.parallel
+            && !self.is_ordered_with(other)
+            && !self.access.conflicts_with(&other.access)
+    }
+
+    /// Intervals are measured with realm's time, so they follow it's scale and pauses.
+    pub fn should_run(&mut self, game_state: &GameState, time: Option<&Time>) -> bool {
+        match &self.run_criteria {
+            RunCriteria::Always => true,
+            RunCriteria::Every(interval) => {
+                let now = time.map(Time::elapsed).unwrap_or_default();
+
+                match self.last_run {
+                    Some(last_run) if now.saturating_sub(last_run) < *interval => false,
+                    _ => {
+                        self.last_run = Some(now);
+                        true
+                    }
+                }
+            },
+            RunCriteria::When(predicate) => predicate(game_state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // advances time by `step` `count` times, returning how many times it would run
+    fn runs(descriptor: &mut SystemDescriptor, game_state: &GameState, time: &mut Time, step: Duration, count: u32) -> u32 {
+        (0..count).filter(|_| {
+                      time.advance(step);
+                      descriptor.should_run(game_state, Some(time))
+                  })
+                  .count() as u32
+    }
+
+    fn every(interval: Duration) -> SystemDescriptor {
+        let mut descriptor = SystemDescriptor::new("system");
+        descriptor.with_run_criteria(RunCriteria::every(interval));
+        descriptor
+    }
+
+    #[test]
+    fn every_follows_realm_time() {
+        let game_state = GameState::new();
+        let mut time = Time::new();
+        let mut descriptor = every(Duration::from_millis(100));
+
+        // first run happens right away, then once every 100ms
+        assert_eq!(4, runs(&mut descriptor, &game_state, &mut time, Duration::from_millis(25), 13));
+    }
+
+    #[test]
+    fn every_follows_time_scale_and_pause() {
+        let game_state = GameState::new();
+        let mut time = Time::new();
+        let mut descriptor = every(Duration::from_millis(100));
+
+        assert_eq!(1, runs(&mut descriptor, &game_state, &mut time, Duration::from_millis(50), 1));
+
+        time.set_time_scale(0.5);
+        assert_eq!(1, runs(&mut descriptor, &game_state, &mut time, Duration::from_millis(50), 4));
+
+        time.pause();
+        assert_eq!(0, runs(&mut descriptor, &game_state, &mut time, Duration::from_secs(10), 5));
+
+        time.resume();
+        assert_eq!(1, runs(&mut descriptor, &game_state, &mut time, Duration::from_millis(100), 2));
+    }
+}
@@ -0,0 +1,31 @@
+mod serializable;
+pub use serializable::Serializable;
+
+mod serialization_registry;
+pub use serialization_registry::SerializationRegistry;
+
+// scene related
+mod scene;
+pub use scene::{
+    Scene,
+    SceneEntity
+};
+
+mod scene_error;
+pub use scene_error::SceneError;
+
+mod scene_format;
+pub use scene_format::SceneFormat;
+
+mod scene_load_context;
+pub use scene_load_context::SceneLoadContext;
+
+mod scene_save_context;
+pub use scene_save_context::SceneSaveContext;
+
+// prefab related
+mod prefab;
+pub use prefab::Prefab;
+
+mod prefab_overrides;
+pub use prefab_overrides::PrefabOverrides;
@@ -0,0 +1,144 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap
+    },
+    path::Path
+};
+
+use serde::{
+    Deserialize,
+    Serialize
+};
+use serde_json::Value;
+
+use crate::core::ecs::serialization::{
+    PrefabOverrides,
+    Scene,
+    SceneEntity,
+    SceneError,
+    SceneFormat
+};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Prefab {
+    // prefab which this one is a variant of
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+
+    // identifies a child when overriding it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub components: BTreeMap<String, Value>,
+
+    #[serde(default)]
+    pub children: Vec<Prefab>
+}
+
+impl Prefab {
+    pub fn new() -> Prefab {
+        Prefab {
+            base: None,
+            name: None,
+            components: BTreeMap::new(),
+            children: Vec::new()
+        }
+    }
+
+    pub fn variant_of<T: Into<String>>(base: T) -> Prefab {
+        Prefab {
+            base: Some(base.into()),
+            ..Prefab::new()
+        }
+    }
+
+    pub fn from_text(source: &str, format: SceneFormat) -> Result<Prefab, SceneError> {
+        format.parse(source)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(filepath: P) -> Result<Prefab, SceneError> {
+        SceneFormat::read_file(filepath.as_ref())
+    }
+
+    pub fn to_text(&self, format: SceneFormat) -> Result<String, SceneError> {
+        format.write(self)
+    }
+
+    pub fn save_file<P: AsRef<Path>>(&self, filepath: P) -> Result<(), SceneError> {
+        SceneFormat::write_file(filepath.as_ref(), self)
+    }
+
+    // flattens prefab hierarchy into a scene, root entity is always the first one
+    pub(in crate::core::ecs) fn instantiate(&self, name: &str, prefabs: &HashMap<String, Prefab>, overrides: &PrefabOverrides) -> Result<Scene, SceneError> {
+        let mut scene = Scene::new();
+        self.instantiate_into(&mut scene, None, prefabs, Some(overrides), &[name.to_string()])?;
+        Ok(scene)
+    }
+
+    fn instantiate_into(&self, scene: &mut Scene, parent: Option<u32>, prefabs: &HashMap<String, Prefab>, overrides: Option<&PrefabOverrides>, ancestors: &[String]) -> Result<(), SceneError> {
+        // a child can't be an instance of any prefab it's nested into
+        let mut ancestors = ancestors.to_vec();
+        let (mut components, children) = self.resolve(prefabs, &mut ancestors)?;
+
+        if let Some(overrides) = overrides {
+            for (name, value) in overrides.components() {
+                Prefab::merge(components.entry(name.clone()).or_insert(Value::Null), value);
+            }
+        }
+
+        let id = scene.entities.len() as u32;
+        let mut scene_entity = SceneEntity::new(id);
+        scene_entity.parent = parent;
+        scene_entity.components = components;
+        scene.entities.push(scene_entity);
+
+        for child in children.iter() {
+            let child_overrides = match (overrides, &child.name) {
+                (Some(overrides), Some(name)) => overrides.child(name),
+                _ => None
+            };
+
+            child.instantiate_into(scene, Some(id), prefabs, child_overrides, &ancestors)?;
+        }
+
+        Ok(())
+    }
+
+    // applies every base this prefab is a variant of, registering them as visited
+    fn resolve(&self, prefabs: &HashMap<String, Prefab>, visited: &mut Vec<String>) -> Result<(BTreeMap<String, Value>, Vec<Prefab>), SceneError> {
+        let (mut components, mut children) = match &self.base {
+            Some(base_name) => {
+                if visited.contains(base_name) {
+                    return Err(SceneError::CyclicPrefab(base_name.clone()));
+                }
+
+                let base = prefabs.get(base_name)
+                                  .ok_or_else(|| SceneError::UnknownPrefab(base_name.clone()))?;
+
+                visited.push(base_name.clone());
+                base.resolve(prefabs, visited)?
+            },
+            None => (BTreeMap::new(), Vec::new())
+        };
+
+        for (name, value) in self.components.iter() {
+            Prefab::merge(components.entry(name.clone()).or_insert(Value::Null), value);
+        }
+
+        children.extend(self.children.iter().cloned());
+        Ok((components, children))
+    }
+
+    fn merge(target: &mut Value, value: &Value) {
+        match (target, value) {
+            (Value::Object(target_fields), Value::Object(fields)) => {
+                for (field, field_value) in fields {
+                    Prefab::merge(target_fields.entry(field.clone()).or_insert(Value::Null), field_value);
+                }
+            },
+            (target, value) => *target = value.clone()
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    any,
+    collections::{
+        BTreeMap,
+        HashMap
+    }
+};
+
+use serde_json::Value;
+
+use crate::core::ecs::serialization::{
+    SceneError,
+    SceneSaveContext,
+    Serializable
+};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PrefabOverrides {
+    components: BTreeMap<String, Value>,
+    children: HashMap<String, PrefabOverrides>
+}
+
+impl PrefabOverrides {
+    pub fn new() -> PrefabOverrides {
+        PrefabOverrides {
+            components: BTreeMap::new(),
+            children: HashMap::new()
+        }
+    }
+
+    pub fn with_component<C: Serializable>(mut self, component: C) -> PrefabOverrides {
+        let name = C::serialization_name();
+        let data = component.save(&SceneSaveContext::new(HashMap::new()))
+                            .and_then(|data| {
+                                serde_json::to_value(data).map_err(|e| SceneError::InvalidComponent {
+                                    name: name.clone(),
+                                    error: e
+                                })
+                            })
+                            .unwrap_or_else(|e| panic!("Can't override component '{}': {}", any::type_name::<C>(), e));
+
+        self.components.insert(name, data);
+        self
+    }
+
+    // only the fields present at value are overriden, the remaining ones keeps prefab's data
+    pub fn with_value<T: Into<String>>(mut self, component_name: T, value: Value) -> PrefabOverrides {
+        self.components.insert(component_name.into(), value);
+        self
+    }
+
+    pub fn with_child<T: Into<String>>(mut self, child_name: T, overrides: PrefabOverrides) -> PrefabOverrides {
+        self.children.insert(child_name.into(), overrides);
+        self
+    }
+
+    pub fn components(&self) -> &BTreeMap<String, Value> {
+        &self.components
+    }
+
+    pub fn child(&self, child_name: &str) -> Option<&PrefabOverrides> {
+        self.children.get(child_name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.children.is_empty()
+    }
+}
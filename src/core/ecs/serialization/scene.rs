@@ -0,0 +1,78 @@
+use std::{
+    collections::BTreeMap,
+    path::Path
+};
+
+use serde::{
+    Deserialize,
+    Serialize
+};
+use serde_json::Value;
+
+use crate::core::ecs::serialization::{
+    SceneError,
+    SceneFormat
+};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SceneEntity {
+    pub id: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<u32>,
+
+    #[serde(default)]
+    pub components: BTreeMap<String, Value>
+}
+
+impl SceneEntity {
+    pub fn new(id: u32) -> SceneEntity {
+        SceneEntity {
+            id,
+            parent: None,
+            components: BTreeMap::new()
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    #[serde(default)]
+    pub entities: Vec<SceneEntity>,
+
+    #[serde(default)]
+    pub resources: BTreeMap<String, Value>
+}
+
+impl Scene {
+    pub fn new() -> Scene {
+        Scene {
+            entities: Vec::new(),
+            resources: BTreeMap::new()
+        }
+    }
+
+    pub fn from_text(source: &str, format: SceneFormat) -> Result<Scene, SceneError> {
+        format.parse(source)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(filepath: P) -> Result<Scene, SceneError> {
+        SceneFormat::read_file(filepath.as_ref())
+    }
+
+    pub fn to_text(&self, format: SceneFormat) -> Result<String, SceneError> {
+        format.write(self)
+    }
+
+    pub fn save_file<P: AsRef<Path>>(&self, filepath: P) -> Result<(), SceneError> {
+        SceneFormat::write_file(filepath.as_ref(), self)
+    }
+
+    pub fn entity(&self, id: u32) -> Option<&SceneEntity> {
+        self.entities.iter().find(|entity| entity.id == id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty() && self.resources.is_empty()
+    }
+}
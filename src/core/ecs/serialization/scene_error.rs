@@ -0,0 +1,113 @@
+use std::{
+    error::{
+        Error
+    },
+    fmt::{
+        self,
+        Display,
+        Formatter
+    },
+    io,
+    path::PathBuf
+};
+
+use crate::{
+    core::ecs::EntityError,
+    graphics::error::TextureError
+};
+
+#[derive(Debug)]
+pub enum SceneError {
+    Io(io::Error),
+    Ron(ron::Error),
+    Json(serde_json::Error),
+    UnknownFormat(PathBuf),
+    UnregisteredComponent(String),
+    UnregisteredResource(String),
+    InvalidComponent {
+        name: String,
+        error: serde_json::Error
+    },
+    InvalidResource {
+        name: String,
+        error: serde_json::Error
+    },
+    Unserializable(String),
+    DuplicatedEntity(u32),
+    UnknownPrefab(String),
+    EmptyPrefab(String),
+    CyclicPrefab(String),
+    UnknownEntity(u32),
+    Texture(TextureError),
+    Entity(EntityError)
+}
+
+impl Display for SceneError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneError::Io(err) => {
+                write!(fmt, "Failed to access scene file: {}", err)
+            },
+            SceneError::Ron(err) => {
+                write!(fmt, "Invalid RON scene: {}", err)
+            },
+            SceneError::Json(err) => {
+                write!(fmt, "Invalid JSON scene: {}", err)
+            },
+            SceneError::UnknownFormat(path) => {
+                write!(fmt, "Can't infer scene format from file '{}'.", path.display())
+            },
+            SceneError::UnregisteredComponent(name) => {
+                write!(fmt, "Component '{}' isn't registered for serialization.", name)
+            },
+            SceneError::UnregisteredResource(name) => {
+                write!(fmt, "Resource '{}' isn't registered for serialization.", name)
+            },
+            SceneError::InvalidComponent { name, error } => {
+                write!(fmt, "Invalid data for component '{}': {}", name, error)
+            },
+            SceneError::InvalidResource { name, error } => {
+                write!(fmt, "Invalid data for resource '{}': {}", name, error)
+            },
+            SceneError::Unserializable(name) => {
+                write!(fmt, "'{}' can't be serialized.", name)
+            },
+            SceneError::DuplicatedEntity(id) => {
+                write!(fmt, "Scene entity {} is declared more than once.", id)
+            },
+            SceneError::UnknownEntity(id) => {
+                write!(fmt, "Scene entity {} is referenced but never declared.", id)
+            },
+            SceneError::UnknownPrefab(name) => {
+                write!(fmt, "Prefab '{}' isn't registered.", name)
+            },
+            SceneError::EmptyPrefab(name) => {
+                write!(fmt, "Prefab '{}' has no root entity.", name)
+            },
+            SceneError::CyclicPrefab(name) => {
+                write!(fmt, "Prefab '{}' is a variant or child of itself.", name)
+            },
+            SceneError::Texture(err) => {
+                write!(fmt, "Failed to load scene texture: {}", err)
+            },
+            SceneError::Entity(err) => {
+                write!(fmt, "Failed to spawn scene entity: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for SceneError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SceneError::Io(err) => Some(err),
+            SceneError::Ron(err) => Some(err),
+            SceneError::Json(err) => Some(err),
+            SceneError::InvalidComponent { error, .. } => Some(error),
+            SceneError::InvalidResource { error, .. } => Some(error),
+            SceneError::Texture(err) => Some(err),
+            SceneError::Entity(err) => Some(err),
+            _ => None
+        }
+    }
+}
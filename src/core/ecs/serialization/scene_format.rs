@@ -0,0 +1,78 @@
+use std::{
+    fmt::{
+        self,
+        Display,
+        Formatter
+    },
+    fs,
+    path::Path
+};
+
+use ron::ser::PrettyConfig;
+use serde::{
+    de::DeserializeOwned,
+    Serialize
+};
+
+use crate::core::ecs::serialization::SceneError;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SceneFormat {
+    Ron,
+    Json
+}
+
+impl Display for SceneFormat {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneFormat::Ron => write!(fmt, "ron"),
+            SceneFormat::Json => write!(fmt, "json")
+        }
+    }
+}
+
+impl SceneFormat {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<SceneFormat> {
+        let extension = path.as_ref()
+                            .extension()?
+                            .to_str()?
+                            .to_lowercase();
+
+        match extension.as_str() {
+            "ron" => Some(SceneFormat::Ron),
+            "json" => Some(SceneFormat::Json),
+            _ => None
+        }
+    }
+
+    pub(super) fn parse<T: DeserializeOwned>(&self, source: &str) -> Result<T, SceneError> {
+        match self {
+            SceneFormat::Ron => ron::de::from_str(source).map_err(SceneError::Ron),
+            SceneFormat::Json => serde_json::from_str(source).map_err(SceneError::Json)
+        }
+    }
+
+    pub(super) fn write<T: Serialize>(&self, value: &T) -> Result<String, SceneError> {
+        match self {
+            SceneFormat::Ron => ron::ser::to_string_pretty(value, PrettyConfig::default()).map_err(SceneError::Ron),
+            SceneFormat::Json => serde_json::to_string_pretty(value).map_err(SceneError::Json)
+        }
+    }
+
+    pub(super) fn read_file<T: DeserializeOwned>(filepath: &Path) -> Result<T, SceneError> {
+        let format = SceneFormat::of_file(filepath)?;
+        let source = fs::read_to_string(filepath).map_err(SceneError::Io)?;
+
+        format.parse(&source)
+    }
+
+    pub(super) fn write_file<T: Serialize>(filepath: &Path, value: &T) -> Result<(), SceneError> {
+        let text = SceneFormat::of_file(filepath)?.write(value)?;
+
+        fs::write(filepath, text).map_err(SceneError::Io)
+    }
+
+    fn of_file(filepath: &Path) -> Result<SceneFormat, SceneError> {
+        SceneFormat::from_path(filepath).ok_or_else(|| SceneError::UnknownFormat(filepath.to_path_buf()))
+    }
+}
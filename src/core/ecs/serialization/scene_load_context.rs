@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use crate::{
+    core::ecs::EntityId,
+    rendering::GraphicsDevice
+};
+
+pub struct SceneLoadContext<'d> {
+    entities: HashMap<u32, EntityId>,
+    device: &'d mut GraphicsDevice
+}
+
+impl<'d> SceneLoadContext<'d> {
+    pub fn new(entities: HashMap<u32, EntityId>, device: &'d mut GraphicsDevice) -> SceneLoadContext<'d> {
+        SceneLoadContext {
+            entities,
+            device
+        }
+    }
+
+    // entity spawned in place of a scene id
+    pub fn entity(&self, scene_id: u32) -> Option<EntityId> {
+        self.entities.get(&scene_id).copied()
+    }
+
+    pub fn device(&mut self) -> &mut GraphicsDevice {
+        self.device
+    }
+}
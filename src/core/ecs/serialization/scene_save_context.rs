@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use crate::core::ecs::EntityId;
+
+pub struct SceneSaveContext {
+    entities: HashMap<EntityId, u32>
+}
+
+impl SceneSaveContext {
+    pub fn new(entities: HashMap<EntityId, u32>) -> SceneSaveContext {
+        SceneSaveContext {
+            entities
+        }
+    }
+
+    // scene id which replaces an entity id at scene file
+    pub fn entity(&self, entity_id: EntityId) -> Option<u32> {
+        self.entities.get(&entity_id).copied()
+    }
+}
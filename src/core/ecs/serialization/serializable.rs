@@ -0,0 +1,33 @@
+use std::any;
+
+use serde::{
+    de::DeserializeOwned,
+    Serialize
+};
+
+use crate::core::ecs::serialization::{
+    SceneError,
+    SceneLoadContext,
+    SceneSaveContext
+};
+
+pub trait Serializable : Sized + 'static {
+    type Data: Serialize + DeserializeOwned;
+
+    // name used to identify it at scene files, type name without it's path by default
+    fn serialization_name() -> String {
+        let type_name = any::type_name::<Self>();
+        let name = match type_name.find('<') {
+            Some(generics_start) => &type_name[..generics_start],
+            None => type_name
+        };
+
+        name.rsplit("::")
+            .next()
+            .unwrap_or(name)
+            .to_string()
+    }
+
+    fn save(&self, context: &SceneSaveContext) -> Result<Self::Data, SceneError>;
+    fn load(data: Self::Data, context: &mut SceneLoadContext) -> Result<Self, SceneError>;
+}
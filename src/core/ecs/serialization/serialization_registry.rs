@@ -0,0 +1,261 @@
+use std::{
+    any::{
+        self,
+        Any,
+        TypeId
+    },
+    collections::BTreeMap
+};
+
+use serde_json::Value;
+
+use crate::core::ecs::{
+    components::ParentComponent,
+    serialization::{
+        Scene,
+        SceneEntity,
+        SceneError,
+        SceneLoadContext,
+        SceneSaveContext,
+        Serializable
+    },
+    storage::ArchetypeStorage,
+    Component,
+    Entity,
+    EntityId,
+    Resources
+};
+
+type SaveComponent = fn(&ArchetypeStorage, EntityId, &SceneSaveContext) -> Option<Result<Value, SceneError>>;
+type LoadComponent = fn(Value, &mut Entity, &mut SceneLoadContext) -> Result<(), SceneError>;
+type SaveResource = fn(&Resources, &SceneSaveContext) -> Option<Result<Value, SceneError>>;
+type LoadResource = fn(Value, &mut SceneLoadContext) -> Result<PendingResource, SceneError>;
+
+struct ComponentRegistration {
+    type_id: TypeId,
+    save: SaveComponent,
+    load: LoadComponent
+}
+
+struct ResourceRegistration {
+    type_id: TypeId,
+    save: SaveResource,
+    load: LoadResource
+}
+
+pub struct PendingResource {
+    resource: Box<dyn Any>,
+    insert: fn(&mut Resources, Box<dyn Any>)
+}
+
+impl PendingResource {
+    pub fn insert_into(self, resources: &mut Resources) {
+        (self.insert)(resources, self.resource)
+    }
+}
+
+pub struct SerializationRegistry {
+    components: BTreeMap<String, ComponentRegistration>,
+    resources: BTreeMap<String, ResourceRegistration>
+}
+
+impl Default for SerializationRegistry {
+    fn default() -> Self {
+        SerializationRegistry::new()
+    }
+}
+
+impl SerializationRegistry {
+    pub fn new() -> SerializationRegistry {
+        SerializationRegistry {
+            components: BTreeMap::new(),
+            resources: BTreeMap::new()
+        }
+    }
+
+    pub fn register_component<C: Component + Serializable>(&mut self) {
+        let name = C::serialization_name();
+
+        match self.components.get(&name) {
+            Some(registration) if registration.type_id == TypeId::of::<C>() => return,
+            Some(_) => panic!("Serialization name '{}' is already used by another component than '{}'.", name, any::type_name::<C>()),
+            None => ()
+        }
+
+        self.components.insert(name, ComponentRegistration {
+            type_id: TypeId::of::<C>(),
+            save: SerializationRegistry::save_component::<C>,
+            load: SerializationRegistry::load_component::<C>
+        });
+    }
+
+    pub fn register_resource<R: Serializable>(&mut self) {
+        let name = R::serialization_name();
+
+        match self.resources.get(&name) {
+            Some(registration) if registration.type_id == TypeId::of::<R>() => return,
+            Some(_) => panic!("Serialization name '{}' is already used by another resource than '{}'.", name, any::type_name::<R>()),
+            None => ()
+        }
+
+        self.resources.insert(name, ResourceRegistration {
+            type_id: TypeId::of::<R>(),
+            save: SerializationRegistry::save_resource::<R>,
+            load: SerializationRegistry::load_resource::<R>
+        });
+    }
+
+    pub fn is_component_registered<C: Component + 'static>(&self) -> bool {
+        self.components
+            .values()
+            .any(|registration| registration.type_id == TypeId::of::<C>())
+    }
+
+    pub fn is_resource_registered<R: 'static>(&self) -> bool {
+        self.resources
+            .values()
+            .any(|registration| registration.type_id == TypeId::of::<R>())
+    }
+
+    pub fn save_scene(&self, storage: &ArchetypeStorage, resources: &Resources) -> Result<Scene, SceneError> {
+        // keep scene ids stable between saves of the same realm
+        let mut entity_ids: Vec<EntityId> = storage.archetypes()
+                                                   .flat_map(|archetype| archetype.entities().iter().copied())
+                                                   .collect();
+
+        entity_ids.sort();
+
+        let context = SceneSaveContext::new(
+            entity_ids.iter()
+                      .enumerate()
+                      .map(|(scene_id, entity_id)| (*entity_id, scene_id as u32))
+                      .collect()
+        );
+
+        let mut scene = Scene::new();
+
+        for entity_id in entity_ids {
+            let mut scene_entity = SceneEntity::new(context.entity(entity_id).unwrap());
+            scene_entity.parent = storage.get::<ParentComponent>(entity_id)
+                                         .and_then(|parent| context.entity(parent.get()));
+
+            for (name, registration) in self.components.iter() {
+                if let Some(data) = (registration.save)(storage, entity_id, &context) {
+                    scene_entity.components.insert(name.clone(), data?);
+                }
+            }
+
+            scene.entities.push(scene_entity);
+        }
+
+        for (name, registration) in self.resources.iter() {
+            if let Some(data) = (registration.save)(resources, &context) {
+                scene.resources.insert(name.clone(), data?);
+            }
+        }
+
+        Ok(scene)
+    }
+
+    pub(in crate::core::ecs) fn load_scene(&self, scene: &Scene, context: &mut SceneLoadContext) -> Result<(Vec<Entity>, Vec<PendingResource>), SceneError> {
+        let mut entities = Vec::with_capacity(scene.entities.len());
+
+        for scene_entity in scene.entities.iter() {
+            entities.push(self.load_entity(scene_entity, context)?);
+        }
+
+        let mut resources = Vec::with_capacity(scene.resources.len());
+
+        for (name, data) in scene.resources.iter() {
+            let loaded = match self.resources.get(name) {
+                Some(registration) => (registration.load)(data.clone(), context),
+                None => Err(SceneError::UnregisteredResource(name.clone()))
+            };
+
+            resources.push(loaded?);
+        }
+
+        Ok((entities, resources))
+    }
+
+    fn load_entity(&self, scene_entity: &SceneEntity, context: &mut SceneLoadContext) -> Result<Entity, SceneError> {
+        let entity_id = context.entity(scene_entity.id)
+                               .ok_or(SceneError::UnknownEntity(scene_entity.id))?;
+
+        if let Some(parent) = scene_entity.parent {
+            if context.entity(parent).is_none() {
+                return Err(SceneError::UnknownEntity(parent));
+            }
+        }
+
+        let mut entity = Entity::new(entity_id);
+
+        for (name, data) in scene_entity.components.iter() {
+            let loaded = match self.components.get(name) {
+                Some(registration) => (registration.load)(data.clone(), &mut entity, context),
+                None => Err(SceneError::UnregisteredComponent(name.clone()))
+            };
+
+            loaded?;
+        }
+
+        Ok(entity)
+    }
+
+    fn save_component<C: Component + Serializable>(storage: &ArchetypeStorage, entity_id: EntityId, context: &SceneSaveContext) -> Option<Result<Value, SceneError>> {
+        let component = storage.get::<C>(entity_id)?;
+
+        Some(
+            component.save(context).and_then(|data| {
+                serde_json::to_value(data).map_err(|e| SceneError::InvalidComponent {
+                    name: C::serialization_name(),
+                    error: e
+                })
+            })
+        )
+    }
+
+    fn load_component<C: Component + Serializable>(data: Value, entity: &mut Entity, context: &mut SceneLoadContext) -> Result<(), SceneError> {
+        let data = serde_json::from_value::<C::Data>(data).map_err(|e| SceneError::InvalidComponent {
+            name: C::serialization_name(),
+            error: e
+        })?;
+
+        entity.add_component(C::load(data, context)?);
+        Ok(())
+    }
+
+    fn save_resource<R: Serializable>(resources: &Resources, context: &SceneSaveContext) -> Option<Result<Value, SceneError>> {
+        let resource = resources.get::<R>()?;
+
+        Some(
+            resource.save(context).and_then(|data| {
+                serde_json::to_value(data).map_err(|e| SceneError::InvalidResource {
+                    name: R::serialization_name(),
+                    error: e
+                })
+            })
+        )
+    }
+
+    fn load_resource<R: Serializable>(data: Value, context: &mut SceneLoadContext) -> Result<PendingResource, SceneError> {
+        let data = serde_json::from_value::<R::Data>(data).map_err(|e| SceneError::InvalidResource {
+            name: R::serialization_name(),
+            error: e
+        })?;
+
+        Ok(PendingResource {
+            resource: Box::new(R::load(data, context)?),
+            insert: SerializationRegistry::insert_resource::<R>
+        })
+    }
+
+    fn insert_resource<R: Serializable>(resources: &mut Resources, resource: Box<dyn Any>) {
+        match resource.downcast::<R>() {
+            Ok(resource) => {
+                resources.insert(*resource);
+            },
+            Err(_) => panic!("Loaded resource type doesn't match '{}'.", any::type_name::<R>())
+        }
+    }
+}
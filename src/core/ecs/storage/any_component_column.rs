@@ -0,0 +1,33 @@
+use std::any::{
+    Any,
+    TypeId
+};
+
+use crate::{
+    core::ecs::{
+        storage::ComponentTicks,
+        Component
+    }
+};
+
+pub trait AnyComponentColumn {
+    fn component_type(&self) -> TypeId;
+    fn component_type_name(&self) -> &'static str;
+    fn len(&self) -> usize;
+    fn new_empty(&self) -> Box<dyn AnyComponentColumn>;
+    fn swap_remove_into(&mut self, row: usize, destination: &mut dyn AnyComponentColumn);
+    fn swap_remove_drop(&mut self, row: usize);
+    fn get_component(&self, row: usize) -> &dyn Component;
+    fn get_mut_component(&mut self, row: usize) -> &mut dyn Component;
+    fn ticks(&self) -> &[ComponentTicks];
+    fn ticks_mut(&mut self) -> &mut [ComponentTicks];
+    fn components<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Component> + 'a>;
+    fn components_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut dyn Component> + 'a>;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
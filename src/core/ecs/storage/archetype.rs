@@ -0,0 +1,328 @@
+use std::{
+    any::{
+        self,
+        TypeId
+    },
+    collections::HashMap
+};
+
+use crate::{
+    core::ecs::{
+        storage::{
+            AnyComponentColumn,
+            ColumnRef,
+            ComponentBundle,
+            ComponentColumn,
+            ComponentTicks
+        },
+        Component,
+        EntityId
+    }
+};
+
+pub type ArchetypeId = usize;
+
+pub struct Archetype {
+    id: ArchetypeId,
+    component_types: Vec<TypeId>,
+    entities: Vec<EntityId>,
+    columns: HashMap<TypeId, Box<dyn AnyComponentColumn>>,
+    lent: HashMap<TypeId, usize>
+}
+
+impl Drop for Archetype {
+    fn drop(&mut self) {
+        // a reference to a lent column may still be around, so it's leaked rather than freed
+        for type_id in self.lent.keys() {
+            if let Some(column) = self.columns.remove(type_id) {
+                Box::leak(column);
+            }
+        }
+    }
+}
+
+impl Archetype {
+    pub(crate) fn new(id: ArchetypeId, columns: Vec<Box<dyn AnyComponentColumn>>) -> Archetype {
+        if columns.iter().any(|column| !column.is_empty()) {
+            panic!("Archetype {} can only be created from empty columns.", id);
+        }
+
+        Archetype {
+            id,
+            component_types: Archetype::sorted_types(&columns),
+            entities: Vec::new(),
+            columns: columns.into_iter()
+                            .map(|column| (column.component_type(), column))
+                            .collect(),
+            lent: HashMap::new()
+        }
+    }
+
+    pub fn id(&self) -> ArchetypeId {
+        self.id
+    }
+
+    pub fn component_types(&self) -> &[TypeId] {
+        &self.component_types
+    }
+
+    pub fn has_component<T: Component + 'static>(&self) -> bool {
+        self.contains_type(TypeId::of::<T>())
+    }
+
+    pub fn contains_type(&self, type_id: TypeId) -> bool {
+        self.component_types.binary_search(&type_id).is_ok()
+    }
+
+    pub fn entities(&self) -> &[EntityId] {
+        &self.entities
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn column_ticks(&self, type_id: TypeId) -> Option<&[ComponentTicks]> {
+        self.columns
+            .get(&type_id)
+            .map(|column| column.ticks())
+    }
+
+    pub fn get<T: Component + 'static>(&self, row: usize) -> Option<&T> {
+        self.columns
+            .get(&TypeId::of::<T>())
+            .and_then(|column| column.as_any().downcast_ref::<ComponentColumn<T>>())
+            .and_then(|column| column.get(row))
+    }
+
+    pub fn get_mut<T: Component + 'static>(&mut self, row: usize, change_tick: u64) -> Option<&mut T> {
+        if self.is_lent(TypeId::of::<T>()) {
+            return None;
+        }
+
+        self.columns
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|column| column.as_any_mut().downcast_mut::<ComponentColumn<T>>())
+            .and_then(|column| column.get_mut_with_ticks(row))
+            .map(|(component, ticks)| {
+                ticks.set_changed(change_tick);
+                component
+            })
+    }
+
+    /// Takes column away to be written, it fails while column is taken or lent.
+    pub(crate) fn take_column<T: Component + 'static>(&mut self) -> Option<Box<ComponentColumn<T>>> {
+        if self.is_lent(TypeId::of::<T>()) {
+            return None;
+        }
+
+        match self.columns.remove(&TypeId::of::<T>()) {
+            Some(column) => match column.into_any().downcast::<ComponentColumn<T>>() {
+                Ok(typed_column) => Some(typed_column),
+                Err(_) => panic!("Column type doesn't match it's component type '{}'.", any::type_name::<T>())
+            },
+            None => None
+        }
+    }
+
+    /// Lends column to be read, it can be lent many times while it isn't taken.
+    pub(crate) fn lend_column<T: Component + 'static>(&mut self) -> Option<ColumnRef<T>> {
+        let type_id = TypeId::of::<T>();
+
+        let column = match self.columns.get(&type_id) {
+            Some(column) => match column.as_any().downcast_ref::<ComponentColumn<T>>() {
+                Some(typed_column) => ColumnRef::new(typed_column),
+                None => panic!("Column type doesn't match it's component type '{}'.", any::type_name::<T>())
+            },
+            None => return None
+        };
+
+        *self.lent.entry(type_id).or_insert(0) += 1;
+        Some(column)
+    }
+
+    pub(crate) fn return_column<T: Component + 'static>(&mut self, _column: ColumnRef<T>) {
+        let type_id = TypeId::of::<T>();
+
+        match self.lent.get_mut(&type_id) {
+            Some(count) => {
+                *count -= 1;
+
+                if *count == 0 {
+                    self.lent.remove(&type_id);
+                }
+            },
+            None => panic!("Column of component '{}' wasn't lent by archetype {}.", any::type_name::<T>(), self.id)
+        }
+    }
+
+    pub(crate) fn take_any_columns(&mut self) -> Vec<Box<dyn AnyComponentColumn>> {
+        if !self.lent.is_empty() {
+            panic!("Can't take every column from archetype {}, one or more columns are lent.", self.id);
+        }
+
+        self.columns
+            .drain()
+            .map(|(_, column)| column)
+            .collect()
+    }
+
+    pub(crate) fn give_back_column(&mut self, column: Box<dyn AnyComponentColumn>) {
+        let type_id = column.component_type();
+
+        if !self.contains_type(type_id) {
+            panic!("Archetype {} doesn't stores component '{}'.", self.id, column.component_type_name());
+        }
+
+        // a lent column is still read through it's references, it can't be replaced
+        if self.is_lent(type_id) || self.columns.contains_key(&type_id) {
+            panic!("Column of component '{}' wasn't taken from archetype {}.", column.component_type_name(), self.id);
+        }
+
+        if column.len() != self.entities.len() {
+            panic!("Column of component '{}' was given back with a different length.", column.component_type_name());
+        }
+
+        self.columns.insert(type_id, column);
+    }
+
+    pub(super) fn push(&mut self, entity_id: EntityId, components: ComponentBundle, ticks: ComponentTicks) -> usize {
+        self.expect_unborrowed("push entity into");
+
+        components.move_into(&mut self.columns, ticks);
+        self.entities.push(entity_id);
+        self.entities.len() - 1
+    }
+
+    /// Drops every component of the entity at row, last entity takes it's place.
+    pub(super) fn swap_remove(&mut self, row: usize) {
+        self.expect_unborrowed("remove entity from");
+
+        for column in self.columns.values_mut() {
+            column.swap_remove_drop(row);
+        }
+
+        self.entities.swap_remove(row);
+    }
+
+    /// Moves entity at row straight into destination's columns, components destination doesn't store are dropped.
+    pub(super) fn move_row(&mut self, row: usize, destination: &mut Archetype) -> usize {
+        self.expect_unborrowed("move entity from");
+        destination.expect_unborrowed("move entity into");
+
+        for column in self.columns.values_mut() {
+            match destination.columns.get_mut(&column.component_type()) {
+                Some(destination_column) => column.swap_remove_into(row, destination_column.as_mut()),
+                None => column.swap_remove_drop(row)
+            }
+        }
+
+        let entity_id = self.entities.swap_remove(row);
+        destination.entities.push(entity_id);
+
+        if destination.columns.values().any(|column| column.len() != destination.entities.len()) {
+            panic!("Entity {} was moved into archetype {} without every component it stores.", entity_id, destination.id);
+        }
+
+        destination.entities.len() - 1
+    }
+
+    /// Moves entity at row into destination along with a component it didn't have.
+    pub(super) fn move_row_adding<T: Component + 'static>(&mut self, row: usize, destination: &mut Archetype, component: T, ticks: ComponentTicks) -> usize {
+        destination.expect_unborrowed("move entity into");
+
+        match destination.columns.get_mut(&TypeId::of::<T>()).and_then(|column| column.as_any_mut().downcast_mut::<ComponentColumn<T>>()) {
+            Some(column) => column.push_with_ticks(component, ticks),
+            None => panic!("Archetype {} doesn't stores component '{}'.", destination.id, any::type_name::<T>())
+        }
+
+        self.move_row(row, destination)
+    }
+
+    /// Replaces a component in place, it's considered as newly added.
+    pub(super) fn replace<T: Component + 'static>(&mut self, row: usize, component: T, ticks: ComponentTicks) {
+        self.expect_unborrowed("replace component in");
+
+        match self.columns
+                  .get_mut(&TypeId::of::<T>())
+                  .and_then(|column| column.as_any_mut().downcast_mut::<ComponentColumn<T>>())
+                  .and_then(|column| column.get_mut_with_ticks(row)) {
+            Some((current, current_ticks)) => {
+                *current = component;
+                *current_ticks = ticks;
+            },
+            None => panic!("Archetype {} doesn't stores component '{}' at row {}.", self.id, any::type_name::<T>(), row)
+        }
+    }
+
+    /// Empty columns of every component stored here.
+    pub(super) fn new_columns(&self) -> Vec<Box<dyn AnyComponentColumn>> {
+        self.expect_unborrowed("copy columns of");
+
+        self.columns
+            .values()
+            .map(|column| column.new_empty())
+            .collect()
+    }
+
+    fn expect_unborrowed(&self, action: &str) {
+        if self.columns.len() != self.component_types.len() || !self.lent.is_empty() {
+            panic!("Can't {} archetype {}, one or more columns are borrowed.", action, self.id);
+        }
+    }
+
+    fn is_lent(&self, type_id: TypeId) -> bool {
+        self.lent.contains_key(&type_id)
+    }
+
+    pub(super) fn sorted_types(columns: &[Box<dyn AnyComponentColumn>]) -> Vec<TypeId> {
+        let mut component_types: Vec<TypeId> = columns.iter()
+                                                      .map(|column| column.component_type())
+                                                      .collect();
+
+        component_types.sort();
+        component_types
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ecs::{
+        test_components::Position,
+        EntityAllocator
+    };
+
+    fn archetype_with_position(value: f32) -> Archetype {
+        let mut archetype = Archetype::new(0, vec!(Box::new(ComponentColumn::<Position>::new())));
+        let mut components = ComponentBundle::new();
+        components.push(Position(value));
+        archetype.push(EntityAllocator::new().allocate(), components, ComponentTicks::default());
+        archetype
+    }
+
+    #[test]
+    #[should_panic(expected = "wasn't taken")]
+    fn lent_column_cant_be_replaced() {
+        let mut archetype = archetype_with_position(1.0);
+        let _lent = archetype.lend_column::<Position>().unwrap();
+
+        archetype.give_back_column(Box::new(ComponentColumn::with(Position(2.0))));
+    }
+
+    #[test]
+    fn lent_column_outlives_its_archetype() {
+        let mut archetype = archetype_with_position(1.0);
+        let lent = archetype.lend_column::<Position>().unwrap();
+
+        assert!(archetype.take_column::<Position>().is_none());
+        assert!(archetype.get_mut::<Position>(0, 1).is_none());
+
+        drop(archetype);
+        assert_eq!(lent.get(0), Some(&Position(1.0)));
+    }
+}
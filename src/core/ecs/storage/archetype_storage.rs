@@ -0,0 +1,393 @@
+use std::{
+    any::{
+        self,
+        TypeId
+    },
+    collections::HashMap,
+    slice::{
+        Iter,
+        IterMut
+    }
+};
+
+use crate::{
+    core::ecs::{
+        storage::{
+            AnyComponentColumn,
+            Archetype,
+            ArchetypeId,
+            ComponentColumn,
+            ComponentTicks,
+            EntityLocation
+        },
+        Component,
+        Entity,
+        EntityError,
+        EntityId
+    }
+};
+
+pub struct ArchetypeStorage {
+    archetypes: Vec<Archetype>,
+    archetype_indices: HashMap<Vec<TypeId>, ArchetypeId>,
+    locations: HashMap<EntityId, EntityLocation>,
+    removed: HashMap<TypeId, Vec<(EntityId, u64)>>,
+    change_tick: u64,
+    removed_flush_tick: u64
+}
+
+impl Default for ArchetypeStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArchetypeStorage {
+    pub fn new() -> ArchetypeStorage {
+        ArchetypeStorage {
+            archetypes: Vec::new(),
+            archetype_indices: HashMap::new(),
+            locations: HashMap::new(),
+            removed: HashMap::new(),
+            change_tick: 1,
+            removed_flush_tick: 0
+        }
+    }
+
+    pub fn insert(&mut self, entity: Entity) -> Result<EntityLocation, EntityError> {
+        let entity_id = entity.get_id();
+
+        if self.locations.contains_key(&entity_id) {
+            return Err(EntityError::AlreadyExists(entity_id));
+        }
+
+        let components = entity.into_components();
+        let mut component_types: Vec<TypeId> = components.component_types().collect();
+        component_types.sort();
+
+        let archetype_id = self.find_or_create_archetype(component_types, |_| components.new_columns());
+        let ticks = ComponentTicks::new(self.change_tick);
+        let row = self.archetypes[archetype_id].push(entity_id, components, ticks);
+
+        let location = EntityLocation {
+            archetype: archetype_id,
+            row
+        };
+
+        self.locations.insert(entity_id, location);
+        Ok(location)
+    }
+
+    pub fn remove(&mut self, entity_id: EntityId) -> Result<(), EntityError> {
+        let location = self.locations
+                           .remove(&entity_id)
+                           .ok_or(EntityError::Dead(entity_id))?;
+
+        let archetype = &mut self.archetypes[location.archetype];
+        archetype.swap_remove(location.row);
+
+        let component_types = archetype.component_types().to_vec();
+        self.relocate_swapped(location);
+
+        for type_id in component_types {
+            self.log_removed(type_id, entity_id);
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the component if entity already has one, otherwise entity moves to the archetype storing it.
+    pub fn add_component<T: Component + 'static>(&mut self, entity_id: EntityId, component: T) -> Result<(), EntityError> {
+        let location = self.location(entity_id)
+                           .ok_or(EntityError::Dead(entity_id))?;
+
+        let ticks = ComponentTicks::new(self.change_tick);
+
+        if self.archetypes[location.archetype].has_component::<T>() {
+            self.archetypes[location.archetype].replace(location.row, component, ticks);
+            return Ok(());
+        }
+
+        let mut component_types = self.archetypes[location.archetype].component_types().to_vec();
+        component_types.push(TypeId::of::<T>());
+        component_types.sort();
+
+        let destination_id = self.find_or_create_archetype(component_types, |storage| {
+            let mut columns = storage.archetypes[location.archetype].new_columns();
+            columns.push(Box::new(ComponentColumn::<T>::new()));
+            columns
+        });
+
+        let (source, destination) = self.archetype_pair(location.archetype, destination_id);
+        let row = source.move_row_adding(location.row, destination, component, ticks);
+
+        self.relocate_swapped(location);
+        self.locations.insert(entity_id, EntityLocation {
+            archetype: destination_id,
+            row
+        });
+
+        Ok(())
+    }
+
+    pub fn remove_component<T: Component + 'static>(&mut self, entity_id: EntityId) -> Result<(), EntityError> {
+        let location = self.location(entity_id)
+                           .ok_or(EntityError::Dead(entity_id))?;
+
+        if !self.archetypes[location.archetype].has_component::<T>() {
+            return Err(EntityError::ComponentNotFound {
+                entity_id,
+                component: any::type_name::<T>()
+            });
+        }
+
+        let component_types = self.archetypes[location.archetype]
+                                  .component_types()
+                                  .iter()
+                                  .copied()
+                                  .filter(|type_id| *type_id != TypeId::of::<T>())
+                                  .collect();
+
+        let destination_id = self.find_or_create_archetype(component_types, |storage| {
+            storage.archetypes[location.archetype]
+                   .new_columns()
+                   .into_iter()
+                   .filter(|column| column.component_type() != TypeId::of::<T>())
+                   .collect()
+        });
+
+        let (source, destination) = self.archetype_pair(location.archetype, destination_id);
+        let row = source.move_row(location.row, destination);
+
+        self.relocate_swapped(location);
+        self.locations.insert(entity_id, EntityLocation {
+            archetype: destination_id,
+            row
+        });
+
+        self.log_removed(TypeId::of::<T>(), entity_id);
+        Ok(())
+    }
+
+    pub fn removed_components(&self, type_id: TypeId) -> &[(EntityId, u64)] {
+        match self.removed.get(&type_id) {
+            Some(removed) => removed,
+            None => &[]
+        }
+    }
+
+    pub fn change_tick(&self) -> u64 {
+        self.change_tick
+    }
+
+    pub fn get<T: Component + 'static>(&self, entity_id: EntityId) -> Option<&T> {
+        let location = self.location(entity_id)?;
+        self.archetypes[location.archetype].get::<T>(location.row)
+    }
+
+    pub fn get_mut<T: Component + 'static>(&mut self, entity_id: EntityId) -> Option<&mut T> {
+        let location = self.location(entity_id)?;
+        let change_tick = self.change_tick;
+        self.archetypes[location.archetype].get_mut::<T>(location.row, change_tick)
+    }
+
+    pub fn contains(&self, entity_id: EntityId) -> bool {
+        self.locations.contains_key(&entity_id)
+    }
+
+    pub fn location(&self, entity_id: EntityId) -> Option<EntityLocation> {
+        self.locations.get(&entity_id).copied()
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.locations.len()
+    }
+
+    pub fn archetype(&self, archetype_id: ArchetypeId) -> Option<&Archetype> {
+        self.archetypes.get(archetype_id)
+    }
+
+    pub(crate) fn archetype_mut(&mut self, archetype_id: ArchetypeId) -> Option<&mut Archetype> {
+        self.archetypes.get_mut(archetype_id)
+    }
+
+    pub fn archetypes(&self) -> Iter<'_, Archetype> {
+        self.archetypes.iter()
+    }
+
+    pub(crate) fn archetypes_mut(&mut self) -> IterMut<'_, Archetype> {
+        self.archetypes.iter_mut()
+    }
+
+    fn find_or_create_archetype<F: FnOnce(&Self) -> Vec<Box<dyn AnyComponentColumn>>>(&mut self, component_types: Vec<TypeId>, new_columns: F) -> ArchetypeId {
+        if let Some(archetype_id) = self.archetype_indices.get(&component_types) {
+            return *archetype_id;
+        }
+
+        // columns are only created once per archetype, moving entities reuses them
+        let archetype_id = self.archetypes.len();
+        let archetype = Archetype::new(archetype_id, new_columns(self));
+        self.archetypes.push(archetype);
+        self.archetype_indices.insert(component_types, archetype_id);
+        archetype_id
+    }
+
+    fn archetype_pair(&mut self, source: ArchetypeId, destination: ArchetypeId) -> (&mut Archetype, &mut Archetype) {
+        if source < destination {
+            let (head, tail) = self.archetypes.split_at_mut(destination);
+            (&mut head[source], &mut tail[0])
+        } else {
+            let (head, tail) = self.archetypes.split_at_mut(source);
+            (&mut tail[0], &mut head[destination])
+        }
+    }
+
+    /// Last entity of the archetype was swapped into the row which was just emptied.
+    fn relocate_swapped(&mut self, emptied: EntityLocation) {
+        let archetype = &self.archetypes[emptied.archetype];
+
+        if emptied.row < archetype.len() {
+            let moved_entity_id = archetype.entities()[emptied.row];
+
+            if let Some(moved_location) = self.locations.get_mut(&moved_entity_id) {
+                moved_location.row = emptied.row;
+            }
+        }
+    }
+
+    fn log_removed(&mut self, type_id: TypeId, entity_id: EntityId) {
+        let tick = self.change_tick;
+
+        self.removed
+            .entry(type_id)
+            .or_default()
+            .push((entity_id, tick));
+    }
+
+    pub(in crate::core::ecs) fn increment_change_tick(&mut self) -> u64 {
+        self.change_tick += 1;
+        self.change_tick
+    }
+
+    pub(in crate::core::ecs) fn flush_removed(&mut self) {
+        // removals stay readable until the next flush, so every system gets a chance to see them
+        let flush_tick = self.removed_flush_tick;
+
+        for removed in self.removed.values_mut() {
+            removed.retain(|(_, tick)| *tick >= flush_tick);
+        }
+
+        self.removed.retain(|_, removed| !removed.is_empty());
+        self.removed_flush_tick = self.change_tick;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::ArchetypeStorage;
+    use crate::core::ecs::{
+        test_components::{
+            Frozen,
+            Health,
+            Position,
+            Velocity
+        },
+        Entity,
+        EntityAllocator,
+        EntityError,
+        EntityId
+    };
+
+    fn spawn(storage: &mut ArchetypeStorage, allocator: &mut EntityAllocator, position: f32, velocity: f32) -> EntityId {
+        let mut entity = Entity::new(allocator.allocate());
+        entity.add_component(Position(position));
+        entity.add_component(Velocity(velocity));
+
+        let entity_id = entity.get_id();
+        storage.insert(entity).unwrap();
+        entity_id
+    }
+
+    #[test]
+    fn adding_component_moves_entity_with_its_data() {
+        let mut storage = ArchetypeStorage::new();
+        let mut allocator = EntityAllocator::new();
+        let entity_id = spawn(&mut storage, &mut allocator, 1.0, 2.0);
+        let previous = storage.location(entity_id).unwrap();
+
+        storage.increment_change_tick();
+        storage.add_component(entity_id, Health(10)).unwrap();
+
+        let location = storage.location(entity_id).unwrap();
+        assert_ne!(location.archetype, previous.archetype);
+        assert!(storage.archetype(previous.archetype).unwrap().is_empty());
+        assert_eq!(storage.get::<Position>(entity_id), Some(&Position(1.0)));
+        assert_eq!(storage.get::<Velocity>(entity_id), Some(&Velocity(2.0)));
+        assert_eq!(storage.get::<Health>(entity_id), Some(&Health(10)));
+
+        // moved components keep their ticks, the new one is added now
+        let archetype = storage.archetype(location.archetype).unwrap();
+        assert_eq!(archetype.column_ticks(TypeId::of::<Position>()).unwrap()[location.row].added(), 1);
+        assert_eq!(archetype.column_ticks(TypeId::of::<Health>()).unwrap()[location.row].added(), 2);
+    }
+
+    #[test]
+    fn adding_existing_component_replaces_it_in_place() {
+        let mut storage = ArchetypeStorage::new();
+        let mut allocator = EntityAllocator::new();
+        let entity_id = spawn(&mut storage, &mut allocator, 1.0, 2.0);
+        let previous = storage.location(entity_id).unwrap();
+
+        storage.add_component(entity_id, Position(5.0)).unwrap();
+
+        assert_eq!(storage.location(entity_id), Some(previous));
+        assert_eq!(storage.get::<Position>(entity_id), Some(&Position(5.0)));
+    }
+
+    #[test]
+    fn removing_component_moves_entity_back() {
+        let mut storage = ArchetypeStorage::new();
+        let mut allocator = EntityAllocator::new();
+        let entity_id = spawn(&mut storage, &mut allocator, 1.0, 2.0);
+        let previous = storage.location(entity_id).unwrap();
+
+        storage.add_component(entity_id, Frozen).unwrap();
+        storage.remove_component::<Frozen>(entity_id).unwrap();
+
+        assert_eq!(storage.location(entity_id).map(|location| location.archetype), Some(previous.archetype));
+        assert_eq!(storage.get::<Velocity>(entity_id), Some(&Velocity(2.0)));
+        assert_eq!(storage.removed_components(TypeId::of::<Frozen>()).len(), 1);
+
+        match storage.remove_component::<Frozen>(entity_id) {
+            Err(EntityError::ComponentNotFound { .. }) => (),
+            _ => panic!("Removing a missing component should fail.")
+        }
+    }
+
+    #[test]
+    fn swapped_entity_is_relocated_when_a_row_is_emptied() {
+        let mut storage = ArchetypeStorage::new();
+        let mut allocator = EntityAllocator::new();
+        let first = spawn(&mut storage, &mut allocator, 1.0, 1.0);
+        let second = spawn(&mut storage, &mut allocator, 2.0, 2.0);
+        let third = spawn(&mut storage, &mut allocator, 3.0, 3.0);
+        let fourth = spawn(&mut storage, &mut allocator, 4.0, 4.0);
+
+        // last entity fills the despawned row
+        storage.remove(first).unwrap();
+        assert_eq!(storage.location(fourth).unwrap().row, 0);
+        assert_eq!(storage.get::<Position>(fourth), Some(&Position(4.0)));
+
+        // same when an entity moves away to another archetype
+        storage.add_component(second, Frozen).unwrap();
+        assert_eq!(storage.location(third).unwrap().row, 1);
+        assert_eq!(storage.get::<Position>(third), Some(&Position(3.0)));
+        assert_eq!(storage.get::<Position>(second), Some(&Position(2.0)));
+
+        assert!(!storage.contains(first));
+        assert_eq!(storage.entity_count(), 3);
+        assert_eq!(storage.removed_components(TypeId::of::<Velocity>()), &[(first, 1)]);
+    }
+}
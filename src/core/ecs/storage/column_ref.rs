@@ -0,0 +1,34 @@
+use std::{
+    ops::Deref,
+    ptr::NonNull
+};
+
+use crate::core::ecs::{
+    storage::ComponentColumn,
+    Component
+};
+
+/// Shared view of a column lent by it's archetype, many readers may hold one at the same time.
+pub struct ColumnRef<T: Component + 'static> {
+    column: NonNull<ComponentColumn<T>>
+}
+
+// column is only read through it, and it's archetype refuses to move, change or free a lent column
+unsafe impl<T: Component + Sync + 'static> Send for ColumnRef<T> {}
+
+impl<T: Component + 'static> ColumnRef<T> {
+    pub(super) fn new(column: &ComponentColumn<T>) -> ColumnRef<T> {
+        ColumnRef {
+            column: NonNull::from(column)
+        }
+    }
+}
+
+impl<T: Component + 'static> Deref for ColumnRef<T> {
+    type Target = ComponentColumn<T>;
+
+    fn deref(&self) -> &ComponentColumn<T> {
+        // column is kept boxed and untouched at it's archetype until this is returned
+        unsafe { self.column.as_ref() }
+    }
+}
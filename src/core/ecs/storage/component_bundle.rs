@@ -0,0 +1,332 @@
+use std::{
+    alloc::{
+        self,
+        Layout
+    },
+    any::{
+        self,
+        TypeId
+    },
+    cmp,
+    collections::HashMap,
+    mem,
+    ptr::{
+        self,
+        NonNull
+    }
+};
+
+use crate::core::ecs::{
+    storage::{
+        AnyComponentColumn,
+        ComponentColumn,
+        ComponentTicks
+    },
+    Component
+};
+
+struct BundleEntry {
+    type_id: TypeId,
+    type_name: &'static str,
+    offset: usize,
+    new_column: fn() -> Box<dyn AnyComponentColumn>,
+    push_into: unsafe fn(*mut u8, &mut dyn AnyComponentColumn, ComponentTicks),
+    as_component: unsafe fn(*mut u8) -> *mut dyn Component,
+    drop: unsafe fn(*mut u8)
+}
+
+/// Components of an entity which isn't stored yet, packed together in a single buffer.
+pub struct ComponentBundle {
+    data: NonNull<u8>,
+    layout: Layout,
+    len: usize,
+    entries: Vec<BundleEntry>
+}
+
+impl Default for ComponentBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ComponentBundle {
+    fn drop(&mut self) {
+        for entry in self.entries.drain(..) {
+            // every remaining entry still owns it's component
+            unsafe { (entry.drop)(self.data.as_ptr().add(entry.offset)) };
+        }
+
+        if self.layout.size() > 0 {
+            unsafe { alloc::dealloc(self.data.as_ptr(), self.layout) };
+        }
+    }
+}
+
+impl ComponentBundle {
+    pub fn new() -> ComponentBundle {
+        let layout = Layout::new::<()>();
+
+        ComponentBundle {
+            data: ComponentBundle::dangling(layout.align()),
+            layout,
+            len: 0,
+            entries: Vec::new()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn has_component<T: Component + 'static>(&self) -> bool {
+        self.contains_type(TypeId::of::<T>())
+    }
+
+    pub fn contains_type(&self, type_id: TypeId) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.type_id == type_id)
+    }
+
+    pub fn component_types(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.entries
+            .iter()
+            .map(|entry| entry.type_id)
+    }
+
+    pub fn components(&self) -> impl Iterator<Item = &dyn Component> {
+        let data = self.data.as_ptr();
+
+        self.entries
+            .iter()
+            .map(move |entry| unsafe { &*(entry.as_component)(data.add(entry.offset)) })
+    }
+
+    pub fn components_mut(&mut self) -> impl Iterator<Item = &mut dyn Component> {
+        let data = self.data.as_ptr();
+
+        // each entry points to it's own bytes, so references never overlap
+        self.entries
+            .iter()
+            .map(move |entry| unsafe { &mut *(entry.as_component)(data.add(entry.offset)) })
+    }
+
+    pub fn push<T: Component + 'static>(&mut self, component: T) {
+        if self.has_component::<T>() {
+            panic!("Bundle already has a component '{}'.", any::type_name::<T>());
+        }
+
+        let component_layout = Layout::new::<T>();
+        let offset = (self.len + component_layout.align() - 1) & !(component_layout.align() - 1);
+        self.reserve(offset + component_layout.size(), component_layout.align());
+
+        unsafe { ptr::write(self.data.as_ptr().add(offset) as *mut T, component) };
+
+        self.len = offset + component_layout.size();
+        self.entries.push(BundleEntry {
+            type_id: TypeId::of::<T>(),
+            type_name: any::type_name::<T>(),
+            offset,
+            new_column: new_column::<T>,
+            push_into: push_into::<T>,
+            as_component: as_component::<T>,
+            drop: drop_component::<T>
+        });
+    }
+
+    pub fn remove<T: Component + 'static>(&mut self) -> Option<T> {
+        let index = self.entries
+                        .iter()
+                        .position(|entry| entry.type_id == TypeId::of::<T>())?;
+
+        // bytes stay reserved, they are just not owned by any entry anymore
+        let entry = self.entries.remove(index);
+        Some(unsafe { ptr::read(self.data.as_ptr().add(entry.offset) as *const T) })
+    }
+
+    /// Empty columns for each component, in the same order as they were pushed.
+    pub(super) fn new_columns(&self) -> Vec<Box<dyn AnyComponentColumn>> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.new_column)())
+            .collect()
+    }
+
+    /// Moves each component at the end of it's column, columns must all be there.
+    pub(super) fn move_into(mut self, columns: &mut HashMap<TypeId, Box<dyn AnyComponentColumn>>, ticks: ComponentTicks) {
+        let data = self.data.as_ptr();
+
+        // entries are taken out before moving, so a panic leaks the remaining components instead of dropping moved ones
+        for entry in mem::take(&mut self.entries) {
+            match columns.get_mut(&entry.type_id) {
+                Some(column) => unsafe { (entry.push_into)(data.add(entry.offset), column.as_mut(), ticks) },
+                None => panic!("Column of component '{}' is missing, it may still be borrowed.", entry.type_name)
+            }
+        }
+    }
+
+    fn reserve(&mut self, size: usize, align: usize) {
+        if size <= self.layout.size() && align <= self.layout.align() {
+            return;
+        }
+
+        let align = cmp::max(align, self.layout.align());
+        let size = cmp::max(size, self.layout.size() * 2);
+        let layout = match Layout::from_size_align(size, align) {
+            Ok(layout) => layout,
+            Err(_) => panic!("Can't grow bundle to {} bytes.", size)
+        };
+
+        // zero sized components never need memory
+        let data = if layout.size() == 0 {
+            ComponentBundle::dangling(layout.align())
+        } else {
+            match NonNull::new(unsafe { alloc::alloc(layout) }) {
+                Some(data) => data,
+                None => alloc::handle_alloc_error(layout)
+            }
+        };
+
+        if self.layout.size() > 0 {
+            // offsets stay aligned since the new buffer is at least as aligned as the old one
+            unsafe {
+                ptr::copy_nonoverlapping(self.data.as_ptr(), data.as_ptr(), self.len);
+                alloc::dealloc(self.data.as_ptr(), self.layout);
+            }
+        }
+
+        self.data = data;
+        self.layout = layout;
+    }
+
+    fn dangling(align: usize) -> NonNull<u8> {
+        // an address equal to the alignment is always aligned and never null
+        match NonNull::new(align as *mut u8) {
+            Some(data) => data,
+            None => NonNull::dangling()
+        }
+    }
+}
+
+fn new_column<T: Component + 'static>() -> Box<dyn AnyComponentColumn> {
+    Box::new(ComponentColumn::<T>::new())
+}
+
+unsafe fn push_into<T: Component + 'static>(data: *mut u8, column: &mut dyn AnyComponentColumn, ticks: ComponentTicks) {
+    match column.as_any_mut().downcast_mut::<ComponentColumn<T>>() {
+        Some(typed_column) => typed_column.push_with_ticks(ptr::read(data as *const T), ticks),
+        None => panic!("Can't move component '{}' into a column of another type.", any::type_name::<T>())
+    }
+}
+
+unsafe fn as_component<T: Component + 'static>(data: *mut u8) -> *mut dyn Component {
+    data as *mut T as *mut dyn Component
+}
+
+unsafe fn drop_component<T: Component + 'static>(data: *mut u8) {
+    ptr::drop_in_place(data as *mut T);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::Any,
+        cell::Cell,
+        collections::HashMap,
+        rc::Rc
+    };
+
+    use super::ComponentBundle;
+    use crate::core::ecs::{
+        components::Updatable,
+        storage::{
+            AnyComponentColumn,
+            ComponentColumn,
+            ComponentTicks
+        },
+        test_components::{
+            Frozen,
+            Health,
+            Position
+        },
+        Component
+    };
+
+    struct Dropped(Rc<Cell<u32>>);
+
+    impl Drop for Dropped {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    impl Component for Dropped {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Updatable for Dropped {
+        fn before_update(&mut self) {
+        }
+
+        fn update(&mut self) {
+        }
+
+        fn late_update(&mut self) {
+        }
+    }
+
+    fn column<T: Component + 'static>(columns: &HashMap<std::any::TypeId, Box<dyn AnyComponentColumn>>) -> &ComponentColumn<T> {
+        columns.values()
+               .find_map(|column| column.as_any().downcast_ref::<ComponentColumn<T>>())
+               .unwrap()
+    }
+
+    #[test]
+    fn mixed_components_are_moved_into_their_columns() {
+        let mut bundle = ComponentBundle::new();
+        bundle.push(Frozen);
+        bundle.push(Health(7));
+        bundle.push(Position(1.5));
+
+        let mut columns: HashMap<_, _> = bundle.new_columns()
+                                               .into_iter()
+                                               .map(|column| (column.component_type(), column))
+                                               .collect();
+        bundle.move_into(&mut columns, ComponentTicks::new(3));
+
+        assert_eq!(column::<Health>(&columns).get(0), Some(&Health(7)));
+        assert_eq!(column::<Position>(&columns).get(0), Some(&Position(1.5)));
+        assert_eq!(column::<Frozen>(&columns).len(), 1);
+        assert_eq!(column::<Health>(&columns).ticks()[0], ComponentTicks::new(3));
+    }
+
+    #[test]
+    fn components_are_dropped_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        let mut bundle = ComponentBundle::new();
+        bundle.push(Health(1));
+        bundle.push(Dropped(Rc::clone(&drops)));
+        drop(bundle);
+        assert_eq!(drops.get(), 1);
+
+        let mut bundle = ComponentBundle::new();
+        bundle.push(Dropped(Rc::clone(&drops)));
+        let removed = bundle.remove::<Dropped>();
+        drop(bundle);
+        assert_eq!(drops.get(), 1);
+
+        drop(removed);
+        assert_eq!(drops.get(), 2);
+    }
+}
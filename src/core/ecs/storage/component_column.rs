@@ -0,0 +1,160 @@
+use std::{
+    any::{
+        self,
+        Any,
+        TypeId
+    },
+    iter::Zip,
+    slice::{
+        Iter,
+        IterMut
+    }
+};
+
+use crate::{
+    core::ecs::{
+        storage::{
+            AnyComponentColumn,
+            ComponentTicks
+        },
+        Component
+    }
+};
+
+pub struct ComponentColumn<T: Component + 'static> {
+    components: Vec<T>,
+    ticks: Vec<ComponentTicks>
+}
+
+impl<T: Component + 'static> AnyComponentColumn for ComponentColumn<T> {
+    fn component_type(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn component_type_name(&self) -> &'static str {
+        any::type_name::<T>()
+    }
+
+    fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    fn new_empty(&self) -> Box<dyn AnyComponentColumn> {
+        Box::new(ComponentColumn::<T>::new())
+    }
+
+    fn swap_remove_into(&mut self, row: usize, destination: &mut dyn AnyComponentColumn) {
+        match destination.as_any_mut().downcast_mut::<ComponentColumn<T>>() {
+            Some(destination_column) => {
+                destination_column.components.push(self.components.swap_remove(row));
+                destination_column.ticks.push(self.ticks.swap_remove(row));
+            },
+            None => panic!("Can't move component '{}' into a column of another type.", any::type_name::<T>())
+        }
+    }
+
+    fn swap_remove_drop(&mut self, row: usize) {
+        self.components.swap_remove(row);
+        self.ticks.swap_remove(row);
+    }
+
+    fn get_component(&self, row: usize) -> &dyn Component {
+        &self.components[row]
+    }
+
+    fn get_mut_component(&mut self, row: usize) -> &mut dyn Component {
+        &mut self.components[row]
+    }
+
+    fn ticks(&self) -> &[ComponentTicks] {
+        &self.ticks
+    }
+
+    fn ticks_mut(&mut self) -> &mut [ComponentTicks] {
+        &mut self.ticks
+    }
+
+    fn components<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Component> + 'a> {
+        Box::new(self.components.iter().map(|component| component as &dyn Component))
+    }
+
+    fn components_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut dyn Component> + 'a> {
+        Box::new(self.components.iter_mut().map(|component| component as &mut dyn Component))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<T: Component + 'static> Default for ComponentColumn<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component + 'static> ComponentColumn<T> {
+    pub fn new() -> ComponentColumn<T> {
+        ComponentColumn {
+            components: Vec::new(),
+            ticks: Vec::new()
+        }
+    }
+
+    pub fn with(component: T) -> ComponentColumn<T> {
+        ComponentColumn {
+            components: vec!(component),
+            ticks: vec!(ComponentTicks::default())
+        }
+    }
+
+    pub fn push(&mut self, component: T) {
+        self.components.push(component);
+        self.ticks.push(ComponentTicks::default());
+    }
+
+    pub(super) fn push_with_ticks(&mut self, component: T, ticks: ComponentTicks) {
+        self.components.push(component);
+        self.ticks.push(ticks);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.ticks.pop();
+        self.components.pop()
+    }
+
+    pub fn get(&self, row: usize) -> Option<&T> {
+        self.components.get(row)
+    }
+
+    pub fn get_mut(&mut self, row: usize) -> Option<&mut T> {
+        self.components.get_mut(row)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.components.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.components.iter_mut()
+    }
+
+    pub fn get_mut_with_ticks(&mut self, row: usize) -> Option<(&mut T, &mut ComponentTicks)> {
+        match (self.components.get_mut(row), self.ticks.get_mut(row)) {
+            (Some(component), Some(ticks)) => Some((component, ticks)),
+            _ => None
+        }
+    }
+
+    pub fn iter_mut_with_ticks(&mut self) -> Zip<IterMut<'_, T>, IterMut<'_, ComponentTicks>> {
+        self.components.iter_mut().zip(self.ticks.iter_mut())
+    }
+}
@@ -0,0 +1,34 @@
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct ComponentTicks {
+    added: u64,
+    changed: u64
+}
+
+impl ComponentTicks {
+    pub fn new(tick: u64) -> ComponentTicks {
+        ComponentTicks {
+            added: tick,
+            changed: tick
+        }
+    }
+
+    pub fn added(&self) -> u64 {
+        self.added
+    }
+
+    pub fn changed(&self) -> u64 {
+        self.changed
+    }
+
+    pub fn is_added(&self, last_run: u64) -> bool {
+        self.added > last_run
+    }
+
+    pub fn is_changed(&self, last_run: u64) -> bool {
+        self.changed > last_run
+    }
+
+    pub fn set_changed(&mut self, tick: u64) {
+        self.changed = tick;
+    }
+}
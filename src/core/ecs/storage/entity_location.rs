@@ -0,0 +1,7 @@
+use crate::core::ecs::storage::ArchetypeId;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct EntityLocation {
+    pub archetype: ArchetypeId,
+    pub row: usize
+}
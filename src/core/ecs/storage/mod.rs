@@ -0,0 +1,26 @@
+mod any_component_column;
+pub use any_component_column::AnyComponentColumn;
+
+mod component_column;
+pub use component_column::ComponentColumn;
+
+mod component_bundle;
+pub use component_bundle::ComponentBundle;
+
+mod column_ref;
+pub use column_ref::ColumnRef;
+
+mod component_ticks;
+pub use component_ticks::ComponentTicks;
+
+mod archetype;
+pub use archetype::{
+    Archetype,
+    ArchetypeId
+};
+
+mod archetype_storage;
+pub use archetype_storage::ArchetypeStorage;
+
+mod entity_location;
+pub use entity_location::EntityLocation;
@@ -5,8 +5,11 @@ use std::{
 
 use crate::{
     core::{
-        ecs::containers::{
-            SystemDataContainer
+        ecs::{
+            containers::{
+                SystemDataContainer
+            },
+            SystemContext
         },
         GameState
     }
@@ -16,7 +19,7 @@ pub trait System {
     type DataType: SystemDataContainer;
 
     fn setup(&mut self, game_controller: &mut Ref<GameState>);
-    fn run(&mut self, component_type: &mut Self::DataType, game_controller: &mut Ref<GameState>);
+    fn run(&mut self, component_type: &mut Self::DataType, context: &mut SystemContext);
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
 }
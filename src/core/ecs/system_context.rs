@@ -0,0 +1,30 @@
+use crate::core::{
+    ecs::Commands,
+    GameState
+};
+
+pub struct SystemContext<'a> {
+    game_state: &'a mut GameState,
+    commands: &'a mut Commands
+}
+
+impl<'a> SystemContext<'a> {
+    pub fn new(game_state: &'a mut GameState, commands: &'a mut Commands) -> SystemContext<'a> {
+        SystemContext {
+            game_state,
+            commands
+        }
+    }
+
+    pub fn game_state(&self) -> &GameState {
+        self.game_state
+    }
+
+    pub fn game_state_mut(&mut self) -> &mut GameState {
+        self.game_state
+    }
+
+    pub fn commands(&mut self) -> &mut Commands {
+        self.commands
+    }
+}
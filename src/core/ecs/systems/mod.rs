@@ -3,3 +3,6 @@ pub use rendering_system::RenderingSystem;
 
 mod update_system;
 pub use update_system::UpdateSystem;
+
+mod transform_propagation_system;
+pub use transform_propagation_system::TransformPropagationSystem;
@@ -18,13 +18,11 @@ use crate::{
                 SimpleDataContainer
             },
             System,
+            SystemContext
         },
         GameState
     },
-    graphics::{
-        Drawable,
-        Graphic
-    },
+    graphics::Drawable,
     rendering::Renderer
 };
 
@@ -38,17 +36,14 @@ impl System for RenderingSystem {
     fn setup(&mut self, _game_state: &mut Ref<GameState>) {
     }
 
-    fn run(&mut self, components: &mut Self::DataType, _game_state: &mut Ref<GameState>) {
-        match self.renderer.upgrade() {
-            Some(renderer_strong_ref) => {
-                let mut renderer = <_ as Borrow<RefCell<Renderer>>>::borrow(&renderer_strong_ref)
-                                                                    .borrow_mut();
-                components.components_mut()
-                          .for_each(|component| {
-                              component.draw(&mut renderer);
-                          })
-            },
-            None => ()
+    fn run(&mut self, components: &mut Self::DataType, _context: &mut SystemContext) {
+        if let Some(renderer_strong_ref) = self.renderer.upgrade() {
+            let mut renderer = <_ as Borrow<RefCell<Renderer>>>::borrow(&renderer_strong_ref)
+                                                                .borrow_mut();
+            components.components_mut()
+                      .for_each(|mut component| {
+                          component.draw(&mut renderer);
+                      })
         }
     }
 
@@ -0,0 +1,192 @@
+use std::{
+    any::Any,
+    cell::Ref,
+    collections::HashMap
+};
+
+use crate::core::{
+    ecs::{
+        components::{
+            GlobalTransformComponent,
+            ParentComponent,
+            TransformComponent
+        },
+        containers::{
+            QueryDataContainer,
+            Read,
+            Write
+        },
+        EntityId,
+        System,
+        SystemContext
+    },
+    GameState
+};
+
+type TransformQuery = (
+    Read<TransformComponent>,
+    Option<Write<GlobalTransformComponent>>,
+    Option<Read<ParentComponent>>
+);
+
+pub struct TransformPropagationSystem {
+}
+
+impl System for TransformPropagationSystem {
+    type DataType = QueryDataContainer<TransformQuery>;
+
+    fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+    }
+
+    fn run(&mut self, transforms: &mut Self::DataType, context: &mut SystemContext) {
+        let mut locals = HashMap::with_capacity(transforms.len());
+
+        for (entity_id, transform, _global, parent) in transforms.iter_mut() {
+            let local = TransformComponent::with(transform.position, transform.rotation, transform.scale);
+            locals.insert(entity_id, (local, parent.map(|parent| parent.get())));
+        }
+
+        let mut globals = HashMap::with_capacity(locals.len());
+
+        for (entity_id, _transform, global, _parent) in transforms.iter_mut() {
+            let computed = TransformPropagationSystem::global_transform(entity_id, &locals, &mut globals);
+
+            match global {
+                Some(mut global) => {
+                    // only a real difference should be seen as a change
+                    if *global != computed {
+                        *global = computed;
+                    }
+                },
+                None => {
+                    context.commands().add_component(entity_id, computed);
+                }
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for TransformPropagationSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransformPropagationSystem {
+    pub fn new() -> TransformPropagationSystem {
+        TransformPropagationSystem {
+        }
+    }
+
+    fn global_transform(
+        entity_id: EntityId,
+        locals: &HashMap<EntityId, (TransformComponent, Option<EntityId>)>,
+        globals: &mut HashMap<EntityId, GlobalTransformComponent>
+    ) -> GlobalTransformComponent {
+        if let Some(global) = globals.get(&entity_id) {
+            return global.clone();
+        }
+
+        let global = match locals.get(&entity_id) {
+            Some((local, Some(parent_id))) if locals.contains_key(parent_id) => {
+                TransformPropagationSystem::global_transform(*parent_id, locals, globals).mul_transform(local)
+            },
+            Some((local, _)) => GlobalTransformComponent::from(local),
+            None => GlobalTransformComponent::new()
+        };
+
+        globals.insert(entity_id, global.clone());
+        global
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        f32::consts::FRAC_PI_2,
+        rc::Rc
+    };
+
+    use super::*;
+    use crate::{
+        core::ecs::Realm,
+        math::Vector2
+    };
+
+    fn realm(game_state: &Rc<RefCell<GameState>>) -> Realm {
+        let mut realm = Realm::new();
+        realm.game_state = Rc::downgrade(game_state);
+        realm.register_system("propagate", TransformPropagationSystem::new());
+        realm
+    }
+
+    fn spawn(realm: &mut Realm, x: f32, y: f32, rotation: f32, scale: f32) -> EntityId {
+        realm.create_entity()
+             .with_component(TransformComponent::with(Vector2::with(x, y), rotation, Vector2::with(scale, scale)))
+             .build()
+    }
+
+    fn global_position(realm: &Realm, entity_id: EntityId) -> Vector2<f32> {
+        realm.get_component::<GlobalTransformComponent>(entity_id)
+             .expect("global transform wasn't added")
+             .position()
+    }
+
+    fn assert_near(expected: Vector2<f32>, actual: Vector2<f32>) {
+        assert!((expected.x() - actual.x()).abs() < 1e-4 && (expected.y() - actual.y()).abs() < 1e-4, "expected {:?}, got {:?}", expected, actual);
+    }
+
+    #[test]
+    fn globals_compose_down_the_hierarchy() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm(&game_state);
+
+        let root = spawn(&mut realm, 10.0, 0.0, FRAC_PI_2, 2.0);
+        let parent = spawn(&mut realm, 1.0, 0.0, 0.0, 0.5);
+        let child = spawn(&mut realm, 4.0, 0.0, 0.0, 1.0);
+        realm.set_parent(parent, root).unwrap();
+        realm.set_parent(child, parent).unwrap();
+
+        realm.run_system("propagate");
+
+        // parent sits 2 units above root, child 4 more since scales cancel out
+        assert_near(Vector2::with(10.0, 0.0), global_position(&realm, root));
+        assert_near(Vector2::with(10.0, 2.0), global_position(&realm, parent));
+        assert_near(Vector2::with(10.0, 6.0), global_position(&realm, child));
+
+        let child_global = realm.get_component::<GlobalTransformComponent>(child).unwrap();
+        assert!((child_global.rotation() - FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(Vector2::with(1.0, 1.0), child_global.scale());
+    }
+
+    #[test]
+    fn reparented_entity_follows_its_new_parent() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut realm = realm(&game_state);
+
+        let first = spawn(&mut realm, 5.0, 0.0, 0.0, 1.0);
+        let second = spawn(&mut realm, 0.0, 5.0, 0.0, 1.0);
+        let child = spawn(&mut realm, 1.0, 1.0, 0.0, 1.0);
+        realm.set_parent(child, first).unwrap();
+        realm.run_system("propagate");
+        assert_near(Vector2::with(6.0, 1.0), global_position(&realm, child));
+
+        realm.set_parent(child, second).unwrap();
+        realm.run_system("propagate");
+        assert_near(Vector2::with(1.0, 6.0), global_position(&realm, child));
+        assert_eq!(Some(second), realm.parent(child));
+
+        realm.remove_parent(child).unwrap();
+        realm.run_system("propagate");
+        assert_near(Vector2::with(1.0, 1.0), global_position(&realm, child));
+    }
+}
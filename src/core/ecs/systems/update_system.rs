@@ -1,10 +1,6 @@
 use std:: {
     any::Any,
-    cell::Ref,
-    time::{
-        Duration,
-        Instant
-    }
+    cell::Ref
 };
 
 use crate::{
@@ -13,39 +9,30 @@ use crate::{
             containers::{
                 AnyDataContainer
             },
-            System
+            System,
+            SystemContext
         },
         GameState
     }
 };
 
 pub struct UpdateSystem {
-    timer: Duration,
-    last_update_timer_checkpoint: Option<Instant>,
-    last_update_delta_time: Duration
 }
 
 impl System for UpdateSystem {
     type DataType = AnyDataContainer;
 
     fn setup(&mut self, _game_state: &mut Ref<GameState>) {
-        self.last_update_timer_checkpoint = Some(Instant::now());
     }
 
-    fn run(&mut self, any_components: &mut Self::DataType, _game_state: &mut Ref<GameState>) {
-        self.step_timer();
-        //println!("dt: {:?}, et: {:?}", self.get_update_delta_time(), self.get_timer());
-
+    fn run(&mut self, any_components: &mut Self::DataType, _context: &mut SystemContext) {
         any_components.components_mut()
-                      .flatten()
                       .for_each(|component| component.before_update());
 
         any_components.components_mut()
-                      .flatten()
                       .for_each(|component| component.update());
 
         any_components.components_mut()
-                      .flatten()
                       .for_each(|component| component.late_update());
 
         /*
@@ -65,32 +52,15 @@ impl System for UpdateSystem {
     }
 }
 
+impl Default for UpdateSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl UpdateSystem {
     pub fn new() -> UpdateSystem {
         UpdateSystem {
-            timer: Duration::new(0, 0),
-            last_update_timer_checkpoint: None,
-            last_update_delta_time: Duration::new(0, 0)
         }
     }
-
-    pub fn get_timer(&self) -> &Duration {
-        &self.timer
-    }
-
-    pub fn get_update_delta_time(&self) -> &Duration {
-        &self.last_update_delta_time
-    }
-
-    fn step_timer(&mut self) {
-        let last_update_timer = match self.last_update_timer_checkpoint {
-            Some(t) => t,
-            None => panic!("System was not initialized, can't step timer.")
-        };
-
-        let delta_time = Instant::now().duration_since(last_update_timer);
-        self.timer += delta_time;
-        self.last_update_timer_checkpoint = Some(Instant::now());
-        self.last_update_delta_time = delta_time;
-    }
 }
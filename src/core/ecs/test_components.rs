@@ -0,0 +1,47 @@
+use std::any::Any;
+
+use crate::core::ecs::{
+    components::Updatable,
+    Component
+};
+
+macro_rules! impl_test_component {
+    ($component:ty) => {
+        impl Component for $component {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+        }
+
+        impl Updatable for $component {
+            fn before_update(&mut self) {
+            }
+
+            fn update(&mut self) {
+            }
+
+            fn late_update(&mut self) {
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Position(pub f32);
+impl_test_component!(Position);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Velocity(pub f32);
+impl_test_component!(Velocity);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Health(pub u32);
+impl_test_component!(Health);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Frozen;
+impl_test_component!(Frozen);
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use crate::core::time::Clock;
+
+pub(crate) struct FrameLimiter {
+    next_frame: Option<Duration>
+}
+
+impl FrameLimiter {
+    pub fn new() -> FrameLimiter {
+        FrameLimiter {
+            next_frame: None
+        }
+    }
+
+    /// Blocks until `frame_time` has passed since last frame was due, as measured by `clock`.
+    pub fn wait(&mut self, frame_time: Option<Duration>, clock: &dyn Clock) {
+        let frame_time = match frame_time {
+            Some(frame_time) => frame_time,
+            None => {
+                self.next_frame = None;
+                return;
+            }
+        };
+
+        if let Some(next_frame) = self.next_frame {
+            clock.wait_until(next_frame);
+        }
+
+        // a late frame moves schedule forward instead of rushing next ones
+        let now = clock.now();
+        self.next_frame = Some(match self.next_frame {
+            Some(next_frame) if now < next_frame + frame_time => next_frame + frame_time,
+            _ => now + frame_time
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::time::ManualClock;
+
+    const FRAME_TIME: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn frames_are_spaced_by_frame_time() {
+        let clock = ManualClock::new();
+        let mut limiter = FrameLimiter::new();
+
+        limiter.wait(Some(FRAME_TIME), &clock);
+        assert_eq!(Duration::default(), clock.now());
+
+        clock.advance(Duration::from_millis(4));
+        limiter.wait(Some(FRAME_TIME), &clock);
+        assert_eq!(FRAME_TIME, clock.now());
+
+        limiter.wait(Some(FRAME_TIME), &clock);
+        assert_eq!(FRAME_TIME * 2, clock.now());
+    }
+
+    #[test]
+    fn late_frame_moves_schedule_forward() {
+        let clock = ManualClock::new();
+        let mut limiter = FrameLimiter::new();
+
+        limiter.wait(Some(FRAME_TIME), &clock);
+        clock.advance(Duration::from_millis(35));
+        limiter.wait(Some(FRAME_TIME), &clock);
+        assert_eq!(Duration::from_millis(35), clock.now());
+
+        limiter.wait(Some(FRAME_TIME), &clock);
+        assert_eq!(Duration::from_millis(45), clock.now());
+    }
+}
@@ -6,12 +6,10 @@ use std::{
 
 use crate::{
     core::{
-        ecs::{
-            systems::{
-                RenderingSystem,
-                UpdateSystem
-            },
-            Realm
+        ecs::Realm,
+        states::{
+            State,
+            StateTransition
         },
         GameLoop,
         GameLoopInterface,
@@ -21,14 +19,17 @@ use crate::{
     },
     rendering::Renderer,
     window::{
-        backends::{
-            BackendEventLoop,
-            BackendInterface
-        },
+        backends::BackendEventLoop,
         Window
     }
 };
 
+#[cfg(feature = "no-backend")]
+use std::mem;
+
+#[cfg(feature = "no-backend")]
+use crate::window::backends::headless_backend::EventInjector;
+
 pub struct Game<L: GameLoopInterface = GameLoop> {
     game_state: Rc<RefCell<GameState>>,
     renderer: Option<Renderer>,
@@ -39,10 +40,10 @@ impl Game {
     pub fn new() -> Result<Game<GameLoop>, GameInitError> {
         let window = Window::default();
         let renderer = Renderer::new(Some(&window))
-                                .map_err(|e| GameInitError::RendererCreation(e))?;
+                                .map_err(GameInitError::RendererCreation)?;
 
         Ok(Game { 
-            game_state: Rc::new(RefCell::new(GameState::new())),
+            game_state: Rc::new(RefCell::new(create_game_state())),
             renderer: Some(renderer),
             window
         })
@@ -53,40 +54,29 @@ impl<L: 'static + GameLoopInterface> Game<L> {
     pub fn with_custom_loop<T: 'static + GameLoopInterface>() -> Result<Game<T>, GameInitError> {
         let window = Window::default();
         let renderer = Renderer::new(Some(&window))
-                                .map_err(|e| GameInitError::RendererCreation(e))?;
+                                .map_err(GameInitError::RendererCreation)?;
 
         Ok(Game { 
-            game_state: Rc::new(RefCell::new(GameState::new())),
+            game_state: Rc::new(RefCell::new(create_game_state())),
             renderer: Some(renderer),
             window
         })
     }
 
     pub fn run(&mut self, mut realm: Realm) -> Result<(), GameRuntimeError> {
-        let renderer = Rc::new(RefCell::new(
-            self.renderer
-                .take()
-                .ok_or(GameRuntimeError::RendererNotAvailable)?
-        ));
-
-        realm.game_state = Rc::downgrade(&self.game_state);
+        let renderer = self.take_renderer()?;
 
-        // register default systems
-        realm.register_system("update", UpdateSystem::new());
-        realm.register_system("rendering", RenderingSystem::new(Rc::downgrade(&renderer)));
-
-        realm.setup_systems();
-        <_ as Borrow<RefCell<GameState>>>::borrow(&self.game_state)
-                                          .borrow_mut()
-                                          .start();
+        if let Err(e) = self.prepare(&mut realm, &renderer) {
+            self.reclaim_renderer(renderer);
+            return Err(e);
+        }
 
         // run window event loop
         self.window
             .event_loop()
             .run(L::new(realm, Rc::downgrade(&renderer), Rc::downgrade(&self.game_state)));
-        
-        // dispose managed resources
-        // those which depends on renderer backend
+
+        self.reclaim_renderer(renderer);
 
 
         /*
@@ -111,6 +101,26 @@ impl<L: 'static + GameLoopInterface> Game<L> {
         Ok(())
     }
 
+    /// State entered as soon as game runs, it's pushed over any other already pushed.
+    pub fn push_state<S: 'static + State>(&mut self, state: S) {
+        <_ as Borrow<RefCell<GameState>>>::borrow(&self.game_state)
+                                          .borrow_mut()
+                                          .push_state(state, StateTransition::Immediate);
+    }
+
+    pub fn game_state(&self) -> RefMut<'_, GameState> {
+        <_ as Borrow<RefCell<GameState>>>::borrow(&self.game_state)
+                                          .borrow_mut()
+    }
+
+    pub fn window(&self) -> &Window<L> {
+        &self.window
+    }
+
+    pub fn window_mut(&mut self) -> &mut Window<L> {
+        &mut self.window
+    }
+
     pub fn renderer(&self) -> &Option<Renderer> {
         &self.renderer
     }
@@ -119,6 +129,34 @@ impl<L: 'static + GameLoopInterface> Game<L> {
         &mut self.renderer
     }
 
+    fn take_renderer(&mut self) -> Result<Rc<RefCell<Renderer>>, GameRuntimeError> {
+        self.renderer
+            .take()
+            .map(|renderer| Rc::new(RefCell::new(renderer)))
+            .ok_or(GameRuntimeError::RendererNotAvailable)
+    }
+
+    fn prepare(&mut self, realm: &mut Realm, renderer: &Rc<RefCell<Renderer>>) -> Result<(), GameRuntimeError> {
+        realm.game_state = Rc::downgrade(&self.game_state);
+
+        realm.register_default_systems(Rc::downgrade(renderer));
+        realm.setup_systems()
+             .map_err(GameRuntimeError::InvalidSchedule)?;
+        <_ as Borrow<RefCell<GameState>>>::borrow(&self.game_state)
+                                          .borrow_mut()
+                                          .start();
+
+        Ok(())
+    }
+
+    // some event loops returns, keep renderer around to be inspected or run again
+    fn reclaim_renderer(&mut self, renderer: Rc<RefCell<Renderer>>) {
+        match Rc::try_unwrap(renderer) {
+            Ok(renderer) => self.renderer = Some(renderer.into_inner()),
+            Err(_) => eprintln!("Renderer is still referenced after game loop ended, it can't be reclaimed")
+        }
+    }
+
     /*
     pub fn render(&mut self, renderer: &mut Renderer) -> Result<(), &'static str> {
         /*
@@ -143,3 +181,92 @@ impl<L: 'static + GameLoopInterface> Game<L> {
     }
     */
 }
+
+// only a windowed game reaches real gamepads, headless ones are fed by sources added by hand
+fn create_game_state() -> GameState {
+    #[allow(unused_mut)]
+    let mut game_state = GameState::new();
+
+    #[cfg(not(feature = "no-backend"))]
+    game_state.gamepads_mut().add_platform_sources();
+
+    game_state
+}
+
+#[cfg(feature = "no-backend")]
+impl<L: 'static + GameLoopInterface> Game<L> {
+    /// Synthetic input, every event pushed is delivered as if it came from a window.
+    pub fn injector(&self) -> &EventInjector {
+        self.window
+            .backend()
+            .injector()
+    }
+
+    /// Runs at most `frames` frames, it ends earlier if game is closed.
+    /// Realm is given back afterwards, so it's state can be inspected or run again.
+    pub fn run_frames(&mut self, realm: &mut Realm, frames: u64) -> Result<(), GameRuntimeError> {
+        let renderer = self.take_renderer()?;
+
+        if let Err(e) = self.prepare(realm, &renderer) {
+            self.reclaim_renderer(renderer);
+            return Err(e);
+        }
+
+        let max_frames = self.injector().frame() + frames;
+        self.window
+            .backend_mut()
+            .set_max_frames(Some(max_frames));
+
+        let game_loop = L::new(mem::take(realm), Rc::downgrade(&renderer), Rc::downgrade(&self.game_state));
+        *realm = self.window
+                     .event_loop()
+                     .run_and_return(game_loop)
+                     .into_realm();
+
+        self.window
+            .backend_mut()
+            .set_max_frames(None);
+
+        self.reclaim_renderer(renderer);
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "no-backend"))]
+mod tests {
+    use super::*;
+    use crate::core::{
+        ecs::components::TransformComponent,
+        time::Time
+    };
+
+    #[test]
+    fn run_frames_gives_realm_back() {
+        let mut game = Game::new().unwrap();
+        let mut realm = Realm::new();
+        let entity_id = realm.create_entity()
+                             .with_component(TransformComponent::new())
+                             .build();
+
+        game.run_frames(&mut realm, 5).unwrap();
+
+        assert_eq!(5, realm.resource::<Time>().unwrap().frame_count());
+        assert!(realm.is_alive(entity_id));
+        assert!(game.renderer().is_some());
+
+        game.run_frames(&mut realm, 3).unwrap();
+        assert_eq!(8, realm.resource::<Time>().unwrap().frame_count());
+        assert_eq!(8, game.injector().frame());
+    }
+
+    #[test]
+    fn gpu_frame_ends_once_per_rendered_frame() {
+        let mut game = Game::new().unwrap();
+        let mut realm = Realm::new();
+
+        game.run_frames(&mut realm, 4).unwrap();
+
+        let renderer = game.renderer().as_ref().unwrap();
+        assert_eq!(4, renderer.graphics_device().resources().frame());
+    }
+}
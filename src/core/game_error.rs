@@ -10,6 +10,7 @@ use std::{
 };
 
 use crate::{
+    core::ecs::schedule::ScheduleError,
     rendering::error::{
         RendererInitError
     }
@@ -40,7 +41,8 @@ impl Error for GameInitError {
 
 #[derive(Debug)]
 pub enum GameRuntimeError {
-    RendererNotAvailable
+    RendererNotAvailable,
+    InvalidSchedule(ScheduleError)
 }
 
 impl Display for GameRuntimeError {
@@ -48,6 +50,9 @@ impl Display for GameRuntimeError {
         match self {
             GameRuntimeError::RendererNotAvailable => {
                 write!(fmt, "Renderer isn't available.")
+            },
+            GameRuntimeError::InvalidSchedule(err) => {
+                write!(fmt, "Invalid system schedule: {}", err)
             }
         }
     }
@@ -55,6 +60,9 @@ impl Display for GameRuntimeError {
 
 impl Error for GameRuntimeError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        None
+        match self {
+            GameRuntimeError::RendererNotAvailable => None,
+            GameRuntimeError::InvalidSchedule(err) => Some(err)
+        }
     }
 }
@@ -1,14 +1,22 @@
 use std::{
     borrow::Borrow,
     cell::RefCell,
-    rc::Weak
+    mem,
+    rc::Weak,
+    time::Duration
 };
 
 use crate::{
     core::{
-        ecs::Realm,
+        ecs::{
+            schedule::Stage,
+            Realm
+        },
+        states::StateStack,
+        FrameLimiter,
         GameLoopInterface,
-        GameState
+        GameState,
+        Timestep
     },
     events::{
         Event,
@@ -16,31 +24,65 @@ use crate::{
         EventListener
     },
     input::InputEvent,
-    rendering::{
-        GraphicsDevice,
-        Renderer,
-        ResourceDisposable,
-        panic_if_resource_isnt_disposed
-    },
+    rendering::Renderer,
     window::WindowEvent
 };
 
 pub struct GameLoop {
     realm: Realm,
+    states: StateStack,
     renderer: Weak<RefCell<Renderer>>,
     game_state: Weak<RefCell<GameState>>,
+    last_step: Option<Duration>,
+    accumulator: Duration,
+    frame_limiter: FrameLimiter,
+    replaying: bool,
     finalized: bool
 }
 
 impl EventHandler<InputEvent> for GameLoop {
     fn handle(&mut self, event: &mut Event<InputEvent>) {
+        // input state follows devices, even when event ends up consumed
+        match self.game_state.upgrade() {
+            Some(game_state_strong_ref) => {
+                let mut game_state = <_ as Borrow<RefCell<GameState>>>::borrow(&game_state_strong_ref)
+                                                                       .borrow_mut();
+
+                // devices can't interfere with a replay
+                if game_state.is_replaying() && !self.replaying {
+                    event.consume();
+                    return;
+                }
+
+                if let Some(recorder) = game_state.input_recorder_mut() {
+                    recorder.record(event.kind().clone());
+                }
+
+                game_state.input_mut().handle(event);
+            },
+            None => eprintln!("Can't retrieve game state strong ref (from game loop)")
+        }
+
         self.realm.notify(event);
+
+        // only current state hears events
+        if let Some(state_realm) = self.states.top_realm_mut() {
+            if !event.is_consumed() {
+                state_realm.notify(event);
+            }
+        }
     }
 }
 
 impl EventHandler<WindowEvent> for GameLoop {
     fn handle(&mut self, event: &mut Event<WindowEvent>) {
         self.realm.notify(event);
+
+        if let Some(state_realm) = self.states.top_realm_mut() {
+            if !event.is_consumed() {
+                state_realm.notify(event);
+            }
+        }
     }
 }
 
@@ -48,22 +90,66 @@ impl GameLoopInterface for GameLoop {
     fn new(realm: Realm, renderer: Weak<RefCell<Renderer>>, game_state: Weak<RefCell<GameState>>) -> Self {
         Self {
             realm,
+            states: StateStack::new(renderer.clone(), game_state.clone()),
             renderer,
             game_state,
+            last_step: None,
+            accumulator: Duration::default(),
+            frame_limiter: FrameLimiter::new(),
+            replaying: false,
             finalized: false
         }
     }
 
     fn step(&mut self, redraw_request: &mut bool) {
+        let (updates, delta) = self.begin_frame();
+
+        for _ in 0..updates {
+            let delta = self.begin_update(delta);
+            self.update();
+            self.end_update(delta);
+        }
+
         *redraw_request = true;
-        self.update();
     }
 
     fn update(&mut self) {
+        self.realm.run_stage(Stage::PreUpdate);
+        self.realm.run_stage(Stage::Update);
+        self.realm.run_stage(Stage::PostUpdate);
+        self.states.run_update();
+
+        // every system already saw this frame input edges
+        match self.game_state.upgrade() {
+            Some(game_state_strong_ref) => {
+                let mut game_state = <_ as Borrow<RefCell<GameState>>>::borrow(&game_state_strong_ref)
+                                                                       .borrow_mut();
+
+                let delta = game_state.delta();
+                game_state.input_mut().update(delta);
+            },
+            None => eprintln!("Can't retrieve game state strong ref (from game loop)")
+        }
+
+        self.realm.upkeep();
+
+        if let Some(state_realm) = self.states.top_realm_mut() {
+            state_realm.upkeep();
+        }
     }
 
     fn render(&mut self) {
-        self.realm.run_system("rendering");
+        self.realm.run_stage(Stage::Render);
+        self.states.render();
+
+        match self.renderer.upgrade() {
+            Some(renderer_strong_ref) => {
+                <_ as Borrow<RefCell<Renderer>>>::borrow(&renderer_strong_ref)
+                                                 .borrow_mut()
+                                                 .end_frame();
+            },
+            None => eprintln!("Can't retrieve renderer strong ref (from game loop)")
+        }
 
         /*
         match self.renderer.upgrade() {
@@ -88,19 +174,12 @@ impl GameLoopInterface for GameLoop {
         }
 
         self.finalized = true;
-        let r = self.renderer.upgrade();
-
-        match r {
-            Some(renderer_strong_ref) => {
-                let mut renderer = <_ as Borrow<RefCell<Renderer>>>::borrow(&renderer_strong_ref)
-                                                                    .borrow_mut();
-
-                let graphics_device = renderer.graphics_device();
+        self.states.exit_all();
+    }
 
-                self.realm.dispose(graphics_device);
-            },
-            None => eprintln!("Can't retrieve renderer strong ref (from game loop)")
-        };
+    fn into_realm(mut self) -> Realm {
+        self.finalize();
+        mem::take(&mut self.realm)
     }
 }
 
@@ -111,4 +190,290 @@ impl Drop for GameLoop {
 }
 
 impl GameLoop {
+    // decides how many updates this frame runs, and how long each one lasts
+    fn begin_frame(&mut self) -> (u32, Duration) {
+        let game_state_strong_ref = match self.game_state.upgrade() {
+            Some(game_state_strong_ref) => game_state_strong_ref,
+            None => {
+                eprintln!("Can't retrieve game state strong ref (from game loop)");
+                return (0, Duration::default());
+            }
+        };
+
+        let mut game_state = <_ as Borrow<RefCell<GameState>>>::borrow(&game_state_strong_ref)
+                                                               .borrow_mut();
+
+        let frame_time = game_state.target_fps()
+                                   .map(|fps| Duration::from_secs_f64(1.0 / fps as f64));
+
+        self.frame_limiter.wait(frame_time, game_state.clock());
+
+        let now = game_state.clock().now();
+        let measured_delta = match self.last_step {
+            Some(last_step) => now.saturating_sub(last_step).min(game_state.max_catch_up()),
+            None => Duration::default()
+        };
+
+        self.last_step = Some(now);
+
+        // replay frames already carry their own delta
+        if game_state.is_replaying() {
+            self.accumulator = Duration::default();
+            game_state.set_interpolation_alpha(1.0);
+            return (1, measured_delta);
+        }
+
+        match game_state.timestep() {
+            Timestep::Fixed(step) if step > Duration::default() => {
+                self.accumulator += measured_delta;
+
+                let mut updates = 0;
+                while self.accumulator >= step {
+                    self.accumulator -= step;
+                    updates += 1;
+                }
+
+                game_state.set_interpolation_alpha(self.accumulator.as_secs_f64() / step.as_secs_f64());
+                (updates, step)
+            },
+            Timestep::Locked(step) => {
+                self.accumulator = Duration::default();
+                game_state.set_interpolation_alpha(1.0);
+                (1, step)
+            },
+            _ => {
+                self.accumulator = Duration::default();
+                game_state.set_interpolation_alpha(1.0);
+                (1, measured_delta)
+            }
+        }
+    }
+
+    fn begin_update(&mut self, delta: Duration) -> Duration {
+        let game_state_strong_ref = match self.game_state.upgrade() {
+            Some(game_state_strong_ref) => game_state_strong_ref,
+            None => {
+                eprintln!("Can't retrieve game state strong ref (from game loop)");
+                return delta;
+            }
+        };
+
+        let (delta, replayed_events) = {
+            let mut game_state = <_ as Borrow<RefCell<GameState>>>::borrow(&game_state_strong_ref)
+                                                                   .borrow_mut();
+
+            let (delta, replayed_events) = match game_state.input_replay_mut().and_then(|replay| replay.next_frame()) {
+                Some(frame) => (frame.delta, frame.events.clone()),
+                None => (delta, Vec::new())
+            };
+
+            game_state.set_delta(delta);
+            (delta, replayed_events)
+        };
+
+        // state changes happen before anything else sees this update
+        if let Err(e) = self.states.update(delta) {
+            eprintln!("Can't enter state, invalid system schedule: {}", e);
+        }
+
+        // paused states' time stands still
+        self.realm.advance_time(delta);
+
+        if let Some(state_realm) = self.states.top_realm_mut() {
+            state_realm.advance_time(delta);
+        }
+
+        // replayed events goes through the same path as devices ones
+        self.replaying = true;
+
+        for event in replayed_events {
+            <Self as EventHandler<InputEvent>>::handle(self, &mut Event::new(event));
+        }
+
+        self.replaying = false;
+
+        delta
+    }
+
+    fn end_update(&mut self, delta: Duration) {
+        let game_state_strong_ref = match self.game_state.upgrade() {
+            Some(game_state_strong_ref) => game_state_strong_ref,
+            None => return
+        };
+
+        let mut game_state = <_ as Borrow<RefCell<GameState>>>::borrow(&game_state_strong_ref)
+                                                               .borrow_mut();
+
+        if let Some(recorder) = game_state.input_recorder_mut() {
+            recorder.end_frame(delta);
+        }
+
+        let replay_ended = match game_state.input_replay_mut() {
+            Some(replay) if replay.is_finished() => Some(replay.closes_game_at_end()),
+            _ => None
+        };
+
+        if let Some(close_game) = replay_ended {
+            game_state.stop_replay();
+
+            if close_game {
+                game_state.close_game();
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "no-backend"))]
+mod tests {
+    use std::{
+        any::Any,
+        cell::Ref,
+        rc::Rc
+    };
+
+    use super::*;
+    use crate::{
+        core::{
+            ecs::{
+                components::TransformComponent,
+                containers::{
+                    QueryDataContainer,
+                    Read
+                },
+                System,
+                SystemContext
+            },
+            time::{
+                Clock,
+                ManualClock,
+                Time,
+                Timer,
+                TimerFinished
+            },
+            Game
+        },
+        input::{
+            ButtonState,
+            InputReplay,
+            KeyCode,
+            KeyModifiers,
+            KeyboardEvent,
+            MouseButton,
+            MouseButtonEvent
+        },
+        math::Vector2
+    };
+
+    const FRAMES: u64 = 12;
+
+    // input state as systems see it, one entry per frame
+    struct SnapshotInput {
+        frames: Rc<RefCell<Vec<String>>>
+    }
+
+    impl System for SnapshotInput {
+        type DataType = QueryDataContainer<Read<TransformComponent>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _transforms: &mut Self::DataType, context: &mut SystemContext) {
+            let input = context.game_state().input();
+            let snapshot = format!(
+                "{:?} {:?} {:?} {:?}",
+                input.key(KeyCode::A).map(|key| *key.state()),
+                input.key(KeyCode::Space).map(|key| *key.state()),
+                input.mouse_button(MouseButton::Left).map(|button| *button.state()),
+                input.mouse_position()
+            );
+
+            self.frames.borrow_mut().push(snapshot);
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    fn key(key: KeyCode, state: ButtonState) -> InputEvent {
+        InputEvent::Keyboard(KeyboardEvent::new(0, state, Some(key), KeyModifiers::default()))
+    }
+
+    fn run_snapshotting(game: &mut Game) -> Vec<String> {
+        let frames = Rc::new(RefCell::new(Vec::new()));
+        let mut realm = Realm::new();
+        realm.register_system("snapshot", SnapshotInput { frames: frames.clone() })
+             .in_stage(Stage::Update);
+
+        game.run_frames(&mut realm, FRAMES).unwrap();
+
+        let snapshots = RefCell::borrow(&frames).clone();
+        snapshots
+    }
+
+    #[test]
+    fn replay_reproduces_recorded_input() {
+        let mut game = Game::new().unwrap();
+        let injector = game.injector();
+
+        injector.push_at(1, key(KeyCode::A, ButtonState::Pressed));
+        injector.push_at(3, InputEvent::CursorMoved {
+            position: Vector2::with(10.0, 20.0),
+            modifiers: KeyModifiers::default()
+        });
+        injector.push_at(4, InputEvent::MouseButton(MouseButtonEvent::new(ButtonState::Pressed, MouseButton::Left, KeyModifiers::default())));
+        injector.push_at(5, key(KeyCode::A, ButtonState::Released));
+
+        // tapped within a single frame
+        injector.push_at(7, key(KeyCode::Space, ButtonState::Pressed));
+        injector.push_at(7, key(KeyCode::Space, ButtonState::Released));
+        injector.push_at(9, InputEvent::MouseButton(MouseButtonEvent::new(ButtonState::Released, MouseButton::Left, KeyModifiers::default())));
+
+        game.game_state().start_recording();
+        let recorded = run_snapshotting(&mut game);
+        let recording = game.game_state().stop_recording().unwrap();
+        assert_eq!(FRAMES as usize, recording.len());
+
+        let mut replaying = Game::new().unwrap();
+        replaying.game_state().start_replay(InputReplay::new(recording));
+        let replayed = run_snapshotting(&mut replaying);
+
+        assert_eq!(FRAMES as usize, recorded.len());
+        assert!(recorded.iter().any(|frame| frame.contains("Pressed")));
+        assert_eq!(recorded, replayed);
+    }
+
+    #[test]
+    fn manual_clock_drives_realm_time() {
+        let mut game = Game::new().unwrap();
+        let clock = ManualClock::new();
+        game.game_state().set_clock(clock.clone());
+
+        // waiting for next frame jumps manual clock forward
+        game.game_state().set_target_fps(Some(10));
+
+        let mut realm = Realm::new();
+        let time = realm.resource_mut::<Time>().unwrap();
+        time.set_time_scale(0.5);
+        time.start_timer("tick", Timer::repeating(Duration::from_millis(100)));
+
+        game.run_frames(&mut realm, 5).unwrap();
+
+        // first frame has nothing to measure from
+        let time = realm.resource::<Time>().unwrap();
+        assert_eq!(Duration::from_millis(400), clock.now());
+        assert_eq!(Duration::from_millis(400), time.real_elapsed());
+        assert_eq!(Duration::from_millis(200), time.elapsed());
+
+        // it finished at 100ms and 200ms of scaled time, events only keep the last frames
+        assert_eq!(Duration::default(), time.timer("tick").unwrap().elapsed());
+        let finished: Vec<&TimerFinished> = realm.events::<TimerFinished>().unwrap().iter().collect();
+        assert_eq!(1, finished.len());
+        assert_eq!("tick", finished[0].name);
+    }
 }
+
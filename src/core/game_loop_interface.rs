@@ -23,4 +23,7 @@ pub trait GameLoopInterface : EventHandler<InputEvent> + EventHandler<WindowEven
     fn render(&mut self);
     fn game_state(&self) -> Weak<RefCell<GameState>>;
     fn finalize(&mut self);
+
+    /// Finalizes and gives back the realm it was created with.
+    fn into_realm(self) -> Realm;
 }
@@ -1,24 +1,46 @@
 use std::{
-    cell::RefCell,
-    rc::Weak
+    collections::VecDeque,
+    time::Duration
 };
 
 use crate::{
-    events::Event,
+    core::{
+        states::{
+            State,
+            StateRequest,
+            StateTransition
+        },
+        time::{
+            Clock,
+            SystemClock
+        },
+        Timestep
+    },
     input::{
+        gamepad::GamepadManager,
         Input,
+        InputRecorder,
+        InputRecording,
+        InputReplay
     },
     tools::{
         log::Logger
-    },
-    window::{
-        Window,
-        WindowEvent
     }
 };
 
 pub struct GameState {
     input: Input,
+    gamepads: GamepadManager,
+    input_recorder: Option<InputRecorder>,
+    input_replay: Option<InputReplay>,
+    clock: Box<dyn Clock>,
+    timestep: Timestep,
+    max_catch_up: Duration,
+    target_fps: Option<u32>,
+    delta: Duration,
+    interpolation_alpha: f64,
+    state_requests: VecDeque<StateRequest>,
+    fade_alpha: f64,
     logger: Logger, 
     //window: Weak<RefCell<Window>>,
     is_running: bool
@@ -39,10 +61,27 @@ impl WindowEventListener for GameState {
 }
 */
 
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GameState {
     pub fn new() -> GameState {
         GameState {
             input: Input::new(),
+            gamepads: GamepadManager::new(),
+            input_recorder: None,
+            input_replay: None,
+            clock: Box::new(SystemClock::new()),
+            timestep: Timestep::Variable,
+            max_catch_up: Duration::from_millis(250),
+            target_fps: None,
+            delta: Duration::default(),
+            interpolation_alpha: 1.0,
+            state_requests: VecDeque::new(),
+            fade_alpha: 0.0,
             logger: Logger::new(),
             //window: Some(window),
             is_running: false
@@ -57,6 +96,107 @@ impl GameState {
         &mut self.input
     }
 
+    pub fn gamepads(&self) -> &GamepadManager {
+        &self.gamepads
+    }
+
+    pub fn gamepads_mut(&mut self) -> &mut GamepadManager {
+        &mut self.gamepads
+    }
+
+    pub fn start_recording(&mut self) {
+        self.input_recorder = Some(InputRecorder::new());
+    }
+
+    pub fn stop_recording(&mut self) -> Option<InputRecording> {
+        self.input_recorder
+            .take()
+            .map(InputRecorder::finish)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.input_recorder.is_some()
+    }
+
+    /// Drives input from replay, ignoring devices, until it ends.
+    pub fn start_replay(&mut self, replay: InputReplay) {
+        self.input_replay = Some(replay);
+    }
+
+    pub fn stop_replay(&mut self) -> Option<InputReplay> {
+        self.input_replay.take()
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.input_replay.is_some()
+    }
+
+    /// Clock which frames are measured with.
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    pub fn set_clock<C: 'static + Clock>(&mut self, clock: C) {
+        self.clock = Box::new(clock);
+    }
+
+    pub fn timestep(&self) -> Timestep {
+        self.timestep
+    }
+
+    pub fn set_timestep(&mut self, timestep: Timestep) {
+        self.timestep = timestep;
+    }
+
+    /// Longest time a single frame can advance game, slower frames makes game slow down instead of piling up updates.
+    pub fn max_catch_up(&self) -> Duration {
+        self.max_catch_up
+    }
+
+    pub fn set_max_catch_up(&mut self, max_catch_up: Duration) {
+        self.max_catch_up = max_catch_up;
+    }
+
+    pub fn target_fps(&self) -> Option<u32> {
+        self.target_fps
+    }
+
+    /// Sleeps between frames so they don't happen more often than `target_fps`, `None` runs as fast as possible.
+    pub fn set_target_fps(&mut self, target_fps: Option<u32>) {
+        self.target_fps = target_fps.filter(|fps| *fps > 0);
+    }
+
+    /// Current update duration, unaffected by `Time` scale and pause.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// How far, at [0, 1], rendering is between last fixed update and next one.
+    /// It's always 1 when timestep isn't fixed.
+    pub fn interpolation_alpha(&self) -> f64 {
+        self.interpolation_alpha
+    }
+
+    /// Pauses current state and enters `state`, at next update.
+    pub fn push_state<S: 'static + State>(&mut self, state: S, transition: StateTransition) {
+        self.state_requests.push_back(StateRequest::Push(Box::new(state), transition));
+    }
+
+    /// Exits current state and resumes the one below, at next update.
+    pub fn pop_state(&mut self, transition: StateTransition) {
+        self.state_requests.push_back(StateRequest::Pop(transition));
+    }
+
+    /// Exits current state and enters `state` in it's place, at next update.
+    pub fn switch_state<S: 'static + State>(&mut self, state: S, transition: StateTransition) {
+        self.state_requests.push_back(StateRequest::Switch(Box::new(state), transition));
+    }
+
+    /// How much, at [0, 1], a state fade covers the screen, it's up to rendering to draw it.
+    pub fn fade_alpha(&self) -> f64 {
+        self.fade_alpha
+    }
+
     pub fn logger(&self) -> &Logger {
         &self.logger
     }
@@ -79,10 +219,34 @@ impl GameState {
         self.is_running = false;
     }
 
-    pub(in crate::core) fn start(&mut self) {
+    pub(crate) fn start(&mut self) {
         self.is_running = true;
     }
 
+    pub(crate) fn set_delta(&mut self, delta: Duration) {
+        self.delta = delta;
+    }
+
+    pub(in crate::core) fn take_state_request(&mut self) -> Option<StateRequest> {
+        self.state_requests.pop_front()
+    }
+
+    pub(in crate::core) fn set_fade_alpha(&mut self, fade_alpha: f64) {
+        self.fade_alpha = fade_alpha;
+    }
+
+    pub(crate) fn set_interpolation_alpha(&mut self, interpolation_alpha: f64) {
+        self.interpolation_alpha = interpolation_alpha;
+    }
+
+    pub(crate) fn input_recorder_mut(&mut self) -> Option<&mut InputRecorder> {
+        self.input_recorder.as_mut()
+    }
+
+    pub(crate) fn input_replay_mut(&mut self) -> Option<&mut InputReplay> {
+        self.input_replay.as_mut()
+    }
+
     /*
     pub(in crate::core) fn poll_events(&mut self) {
         match self.window {
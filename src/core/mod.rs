@@ -1,4 +1,6 @@
 pub mod ecs;
+pub mod time;
+pub mod states;
 
 mod game;
 pub use game::Game;
@@ -12,6 +14,12 @@ pub use game_loop_interface::GameLoopInterface;
 mod game_loop;
 pub use game_loop::GameLoop;
 
+mod timestep;
+pub use timestep::Timestep;
+
+mod frame_limiter;
+use frame_limiter::FrameLimiter;
+
 mod game_error;
 pub use game_error::{
     GameInitError,
@@ -0,0 +1,14 @@
+mod state;
+pub use state::State;
+
+mod state_context;
+pub use state_context::StateContext;
+
+mod state_transition;
+pub use state_transition::StateTransition;
+
+mod state_request;
+pub(in crate::core) use state_request::StateRequest;
+
+mod state_stack;
+pub(in crate::core) use state_stack::StateStack;
@@ -0,0 +1,29 @@
+use crate::core::states::StateContext;
+
+/// A game screen, such as a menu or a level, which owns it's own realm while at the state stack.
+pub trait State {
+    /// Pushed to stack, it's realm is empty and systems can be registered here.
+    fn on_enter(&mut self, _context: &mut StateContext) {
+    }
+
+    /// Popped or replaced, it's realm is dropped right after.
+    fn on_exit(&mut self, _context: &mut StateContext) {
+    }
+
+    /// Another state was pushed over it.
+    fn on_pause(&mut self, _context: &mut StateContext) {
+    }
+
+    /// State over it was popped.
+    fn on_resume(&mut self, _context: &mut StateContext) {
+    }
+
+    /// Called every update while at top, before it's realm systems.
+    fn update(&mut self, _context: &mut StateContext) {
+    }
+
+    /// States below an overlay, such as a pause menu, keep being rendered.
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
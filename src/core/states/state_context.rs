@@ -0,0 +1,43 @@
+use std::cell::{
+    Ref,
+    RefCell,
+    RefMut
+};
+
+use crate::core::{
+    ecs::Realm,
+    GameState
+};
+
+pub struct StateContext<'a> {
+    realm: &'a mut Realm,
+    game_state: &'a RefCell<GameState>
+}
+
+impl<'a> StateContext<'a> {
+    pub fn new(realm: &'a mut Realm, game_state: &'a RefCell<GameState>) -> StateContext<'a> {
+        StateContext {
+            realm,
+            game_state
+        }
+    }
+
+    /// State own realm.
+    pub fn realm(&self) -> &Realm {
+        self.realm
+    }
+
+    pub fn realm_mut(&mut self) -> &mut Realm {
+        self.realm
+    }
+
+    /// Game state stays borrowed while it's kept, realm systems can't run meanwhile.
+    pub fn game_state(&self) -> Ref<'_, GameState> {
+        self.game_state.borrow()
+    }
+
+    /// Game state stays borrowed while it's kept, realm systems can't run meanwhile.
+    pub fn game_state_mut(&mut self) -> RefMut<'_, GameState> {
+        self.game_state.borrow_mut()
+    }
+}
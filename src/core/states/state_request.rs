@@ -0,0 +1,20 @@
+use crate::core::states::{
+    State,
+    StateTransition
+};
+
+pub(in crate::core) enum StateRequest {
+    Push(Box<dyn State>, StateTransition),
+    Pop(StateTransition),
+    Switch(Box<dyn State>, StateTransition)
+}
+
+impl StateRequest {
+    pub fn transition(&self) -> StateTransition {
+        match self {
+            StateRequest::Push(_, transition) => *transition,
+            StateRequest::Pop(transition) => *transition,
+            StateRequest::Switch(_, transition) => *transition
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use std::{
+    cell::RefCell,
+    rc::Weak,
+    time::Duration
+};
+
+use crate::{
+    core::{
+        ecs::{
+            schedule::{
+                ScheduleError,
+                Stage
+            },
+            Realm
+        },
+        states::{
+            State,
+            StateContext,
+            StateRequest,
+            StateTransition
+        },
+        GameState
+    },
+    rendering::Renderer
+};
+
+struct StateEntry {
+    state: Box<dyn State>,
+    realm: Realm
+}
+
+struct Fade {
+    // request is applied when fade out ends, then it fades in
+    request: Option<StateRequest>,
+    duration: Duration,
+    elapsed: Duration
+}
+
+pub(in crate::core) struct StateStack {
+    entries: Vec<StateEntry>,
+    fade: Option<Fade>,
+    renderer: Weak<RefCell<Renderer>>,
+    game_state: Weak<RefCell<GameState>>
+}
+
+impl StateStack {
+    pub fn new(renderer: Weak<RefCell<Renderer>>, game_state: Weak<RefCell<GameState>>) -> StateStack {
+        StateStack {
+            entries: Vec::new(),
+            fade: None,
+            renderer,
+            game_state
+        }
+    }
+
+    pub fn top_realm_mut(&mut self) -> Option<&mut Realm> {
+        self.entries.last_mut().map(|entry| &mut entry.realm)
+    }
+
+    /// Advances fade and applies requested changes, requests made while fading wait for it to end.
+    /// A state whose systems can't be scheduled isn't entered, stack stays as it was.
+    pub fn update(&mut self, delta: Duration) -> Result<(), ScheduleError> {
+        if let Some(mut fade) = self.fade.take() {
+            fade.elapsed += delta;
+
+            if fade.elapsed < fade.duration {
+                self.fade = Some(fade);
+            } else if let Some(request) = fade.request.take() {
+                self.fade = Some(Fade {
+                    request: None,
+                    duration: fade.duration,
+                    elapsed: Duration::default()
+                });
+
+                self.apply(request)?;
+            }
+        }
+
+        while self.fade.is_none() {
+            let request = match self.take_request() {
+                Some(request) => request,
+                None => break
+            };
+
+            match request.transition() {
+                StateTransition::Fade(duration) if duration > Duration::default() => {
+                    self.fade = Some(Fade {
+                        request: Some(request),
+                        duration,
+                        elapsed: Duration::default()
+                    });
+                },
+                _ => self.apply(request)?
+            }
+        }
+
+        self.publish_fade_alpha();
+        Ok(())
+    }
+
+    /// Runs top state and every update stage at it's realm.
+    pub fn run_update(&mut self) {
+        let entry = match self.entries.last_mut() {
+            Some(entry) => entry,
+            None => return
+        };
+
+        StateStack::call(&self.game_state, entry, |state, context| state.update(context));
+
+        entry.realm.run_stage(Stage::PreUpdate);
+        entry.realm.run_stage(Stage::Update);
+        entry.realm.run_stage(Stage::PostUpdate);
+    }
+
+    /// Renders from the top most state which isn't an overlay up to the top.
+    pub fn render(&mut self) {
+        let mut first = self.entries.len();
+
+        for (index, entry) in self.entries.iter().enumerate().rev() {
+            first = index;
+
+            if !entry.state.is_overlay() {
+                break;
+            }
+        }
+
+        for entry in self.entries[first..].iter_mut() {
+            entry.realm.run_stage(Stage::Render);
+        }
+    }
+
+    /// Exits every state, from top to bottom.
+    pub fn exit_all(&mut self) {
+        while self.exit_top() {}
+
+        self.fade = None;
+    }
+
+    fn apply(&mut self, request: StateRequest) -> Result<(), ScheduleError> {
+        match request {
+            StateRequest::Push(state, _) => {
+                let entry = self.enter(state)?;
+
+                if let Some(top) = self.entries.last_mut() {
+                    StateStack::call(&self.game_state, top, |state, context| state.on_pause(context));
+                }
+
+                self.entries.push(entry);
+            },
+            StateRequest::Pop(_) => {
+                if !self.exit_top() {
+                    eprintln!("Can't pop state, state stack is empty.");
+                    return Ok(());
+                }
+
+                if let Some(entry) = self.entries.last_mut() {
+                    StateStack::call(&self.game_state, entry, |state, context| state.on_resume(context));
+                }
+            },
+            StateRequest::Switch(state, _) => {
+                let entry = self.enter(state)?;
+
+                self.exit_top();
+                self.entries.push(entry);
+            }
+        }
+
+        Ok(())
+    }
+
+    // entered state is only placed at stack once it's systems are scheduled
+    fn enter(&mut self, state: Box<dyn State>) -> Result<StateEntry, ScheduleError> {
+        let mut realm = Realm::new();
+        realm.game_state = self.game_state.clone();
+        realm.register_default_systems(self.renderer.clone());
+
+        let mut entry = StateEntry {
+            state,
+            realm
+        };
+
+        StateStack::call(&self.game_state, &mut entry, |state, context| state.on_enter(context));
+
+        match entry.realm.setup_systems() {
+            Ok(()) => Ok(entry),
+            Err(e) => {
+                StateStack::call(&self.game_state, &mut entry, |state, context| state.on_exit(context));
+                Err(e)
+            }
+        }
+    }
+
+    fn exit_top(&mut self) -> bool {
+        let mut entry = match self.entries.pop() {
+            Some(entry) => entry,
+            None => return false
+        };
+
+        StateStack::call(&self.game_state, &mut entry, |state, context| state.on_exit(context));
+        true
+    }
+
+    fn take_request(&self) -> Option<StateRequest> {
+        self.game_state
+            .upgrade()
+            .and_then(|game_state| game_state.borrow_mut().take_state_request())
+    }
+
+    fn publish_fade_alpha(&self) {
+        let alpha = match &self.fade {
+            Some(fade) => {
+                let progress = (fade.elapsed.as_secs_f64() / fade.duration.as_secs_f64()).min(1.0);
+
+                match fade.request {
+                    Some(_) => progress,
+                    None => 1.0 - progress
+                }
+            },
+            None => 0.0
+        };
+
+        if let Some(game_state) = self.game_state.upgrade() {
+            game_state.borrow_mut().set_fade_alpha(alpha);
+        }
+    }
+
+    // game state isn't borrowed here, hooks and their realm borrow it only when needed
+    fn call<F: FnOnce(&mut dyn State, &mut StateContext)>(game_state: &Weak<RefCell<GameState>>, entry: &mut StateEntry, hook: F) {
+        match game_state.upgrade() {
+            Some(game_state_strong_ref) => {
+                let mut context = StateContext::new(&mut entry.realm, &game_state_strong_ref);
+                hook(entry.state.as_mut(), &mut context);
+            },
+            None => eprintln!("Can't retrieve game state strong ref (from state stack)")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::Any,
+        cell::Ref,
+        rc::Rc
+    };
+
+    use super::*;
+    use crate::core::{
+        ecs::{
+            components::TransformComponent,
+            containers::{
+                QueryDataContainer,
+                Read
+            },
+            System,
+            SystemContext
+        },
+        states::StateTransition
+    };
+
+    struct CountRuns {
+        runs: usize
+    }
+
+    impl System for CountRuns {
+        type DataType = QueryDataContainer<Read<TransformComponent>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _transforms: &mut Self::DataType, _context: &mut SystemContext) {
+            self.runs += 1;
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    // runs a system of it's own realm and changes game state from it's hook
+    struct Eager;
+
+    impl State for Eager {
+        fn on_enter(&mut self, context: &mut StateContext) {
+            context.realm_mut().register_system("count", CountRuns { runs: 0 });
+            context.realm_mut().run_system("count");
+            context.game_state_mut().set_target_fps(Some(30));
+        }
+    }
+
+    struct Unscheduled {
+        exited: Rc<RefCell<bool>>
+    }
+
+    impl State for Unscheduled {
+        fn on_enter(&mut self, context: &mut StateContext) {
+            context.realm_mut()
+                   .register_system("count", CountRuns { runs: 0 })
+                   .after("missing");
+        }
+
+        fn on_exit(&mut self, _context: &mut StateContext) {
+            *self.exited.borrow_mut() = true;
+        }
+    }
+
+    fn stack(game_state: &Rc<RefCell<GameState>>) -> StateStack {
+        StateStack::new(Weak::new(), Rc::downgrade(game_state))
+    }
+
+    #[test]
+    fn hooks_can_reach_game_state_through_realm() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut states = stack(&game_state);
+
+        game_state.borrow_mut().push_state(Eager, StateTransition::Immediate);
+        states.update(Duration::default()).unwrap();
+
+        let realm = states.top_realm_mut().unwrap();
+        assert_eq!(1, realm.get_system::<CountRuns, _>("count").unwrap().runs);
+        assert_eq!(Some(30), game_state.borrow().target_fps());
+    }
+
+    #[test]
+    fn state_with_invalid_schedule_isnt_entered() {
+        let game_state = Rc::new(RefCell::new(GameState::new()));
+        let mut states = stack(&game_state);
+        let exited = Rc::new(RefCell::new(false));
+
+        game_state.borrow_mut().push_state(Eager, StateTransition::Immediate);
+        states.update(Duration::default()).unwrap();
+
+        game_state.borrow_mut().switch_state(Unscheduled { exited: exited.clone() }, StateTransition::Immediate);
+
+        match states.update(Duration::default()) {
+            Err(ScheduleError::UnknownSystem { dependency, .. }) => assert_eq!("missing", dependency),
+            other => panic!("unexpected update result: {:?}", other)
+        }
+
+        // state below is kept at top
+        assert!(*exited.borrow());
+        assert_eq!(1, states.entries.len());
+        assert!(states.top_realm_mut().unwrap().get_system::<CountRuns, _>("count").is_some());
+    }
+}
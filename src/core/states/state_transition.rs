@@ -0,0 +1,10 @@
+use std::time::Duration;
+
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum StateTransition {
+    #[default]
+    Immediate,
+
+    /// Fades out during duration, changes state and then fades in during the same duration.
+    Fade(Duration)
+}
@@ -0,0 +1,26 @@
+use std::{
+    thread,
+    time::Duration
+};
+
+// sleep is coarse on most platforms, last bit before deadline is spent yielding
+const SPIN_MARGIN: Duration = Duration::from_millis(1);
+
+/// Source of time used to measure frames, it can be swapped to drive time by hand.
+pub trait Clock {
+    /// Monotonic time since clock's origin.
+    fn now(&self) -> Duration;
+
+    /// Blocks until clock reaches `deadline`, a clock driven by hand may just jump there.
+    fn wait_until(&self, deadline: Duration) {
+        let now = self.now();
+
+        if deadline > now + SPIN_MARGIN {
+            thread::sleep(deadline - now - SPIN_MARGIN);
+        }
+
+        while self.now() < deadline {
+            thread::yield_now();
+        }
+    }
+}
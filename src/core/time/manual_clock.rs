@@ -0,0 +1,40 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::Duration
+};
+
+use crate::core::time::Clock;
+
+/// Only moves when told to, clones share the same time so one can be kept while another is given away.
+#[derive(Debug, Default, Clone)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+
+    // nothing else would move it, so waiting jumps straight to deadline
+    fn wait_until(&self, deadline: Duration) {
+        if self.now.get() < deadline {
+            self.now.set(deadline);
+        }
+    }
+}
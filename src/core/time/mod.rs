@@ -0,0 +1,23 @@
+#[allow(clippy::module_inception)]
+mod time;
+pub use time::Time;
+
+mod timer;
+pub use timer::Timer;
+
+mod timer_finished;
+pub use timer_finished::TimerFinished;
+
+mod stopwatch;
+pub use stopwatch::Stopwatch;
+
+// clock related
+
+mod clock;
+pub use clock::Clock;
+
+mod system_clock;
+pub use system_clock::SystemClock;
+
+mod manual_clock;
+pub use manual_clock::ManualClock;
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+#[derive(Debug, Default, Clone)]
+pub struct Stopwatch {
+    elapsed: Duration,
+    paused: bool
+}
+
+impl Stopwatch {
+    pub fn new() -> Stopwatch {
+        Stopwatch::default()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::default();
+    }
+
+    pub fn tick(&mut self, delta: Duration) {
+        if !self.paused {
+            self.elapsed += delta;
+        }
+    }
+}
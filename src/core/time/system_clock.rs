@@ -0,0 +1,30 @@
+use std::time::{
+    Duration,
+    Instant
+};
+
+use crate::core::time::Clock;
+
+pub struct SystemClock {
+    origin: Instant
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock::new()
+    }
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock {
+            origin: Instant::now()
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    collections::BTreeMap,
+    time::Duration
+};
+
+use crate::core::time::{
+    Stopwatch,
+    Timer,
+    TimerFinished
+};
+
+/// Game time, advanced by game loop once per update and available to systems as a resource.
+#[derive(Debug)]
+pub struct Time {
+    real_delta: Duration,
+    real_elapsed: Duration,
+    delta: Duration,
+    elapsed: Duration,
+    time_scale: f64,
+    paused: bool,
+    frame_count: u64,
+    timers: BTreeMap<String, Timer>,
+    stopwatches: BTreeMap<String, Stopwatch>
+}
+
+impl Default for Time {
+    fn default() -> Self {
+        Time::new()
+    }
+}
+
+impl Time {
+    pub fn new() -> Time {
+        Time {
+            real_delta: Duration::default(),
+            real_elapsed: Duration::default(),
+            delta: Duration::default(),
+            elapsed: Duration::default(),
+            time_scale: 1.0,
+            paused: false,
+            frame_count: 0,
+            timers: BTreeMap::new(),
+            stopwatches: BTreeMap::new()
+        }
+    }
+
+    /// Current update duration, scaled and zero while paused.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    /// Current update duration, as it really took.
+    pub fn real_delta(&self) -> Duration {
+        self.real_delta
+    }
+
+    /// Sum of every scaled delta.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn real_elapsed(&self) -> Duration {
+        self.real_elapsed
+    }
+
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Scales every delta from next update on, below 1 is slow motion.
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Updates run so far, fixed timestep can run more than one per rendered frame.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Starts a named timer, replacing any other with the same name.
+    pub fn start_timer<T: Into<String>>(&mut self, name: T, timer: Timer) -> Option<Timer> {
+        self.timers.insert(name.into(), timer)
+    }
+
+    pub fn timer(&self, name: &str) -> Option<&Timer> {
+        self.timers.get(name)
+    }
+
+    pub fn timer_mut(&mut self, name: &str) -> Option<&mut Timer> {
+        self.timers.get_mut(name)
+    }
+
+    pub fn remove_timer(&mut self, name: &str) -> Option<Timer> {
+        self.timers.remove(name)
+    }
+
+    pub fn timers(&self) -> impl Iterator<Item = (&String, &Timer)> {
+        self.timers.iter()
+    }
+
+    /// Starts a named stopwatch, it follows scaled time.
+    pub fn start_stopwatch<T: Into<String>>(&mut self, name: T) -> Option<Stopwatch> {
+        self.stopwatches.insert(name.into(), Stopwatch::new())
+    }
+
+    pub fn stopwatch(&self, name: &str) -> Option<&Stopwatch> {
+        self.stopwatches.get(name)
+    }
+
+    pub fn stopwatch_mut(&mut self, name: &str) -> Option<&mut Stopwatch> {
+        self.stopwatches.get_mut(name)
+    }
+
+    pub fn remove_stopwatch(&mut self, name: &str) -> Option<Stopwatch> {
+        self.stopwatches.remove(name)
+    }
+
+    /// Advances by a real delta, returns every timer which has finished.
+    pub fn advance(&mut self, real_delta: Duration) -> Vec<TimerFinished> {
+        self.frame_count += 1;
+        self.real_delta = real_delta;
+        self.real_elapsed += real_delta;
+
+        self.delta = if self.paused {
+            Duration::default()
+        } else {
+            real_delta.mul_f64(self.time_scale)
+        };
+
+        self.elapsed += self.delta;
+
+        for stopwatch in self.stopwatches.values_mut() {
+            stopwatch.tick(self.delta);
+        }
+
+        let mut finished = Vec::new();
+
+        for (name, timer) in self.timers.iter_mut() {
+            let delta = if timer.is_unscaled() {
+                real_delta
+            } else {
+                self.delta
+            };
+
+            let times = timer.tick(delta);
+
+            if times > 0 {
+                finished.push(TimerFinished {
+                    name: name.clone(),
+                    times
+                });
+            }
+        }
+
+        finished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::time::{
+        Clock,
+        ManualClock
+    };
+
+    const STEP: Duration = Duration::from_millis(100);
+
+    // measures each update with the clock, as game loop does
+    fn step(time: &mut Time, clock: &ManualClock, delta: Duration) -> Vec<TimerFinished> {
+        let last = clock.now();
+        clock.advance(delta);
+        time.advance(clock.now() - last)
+    }
+
+    #[test]
+    fn scale_applies_to_delta_but_not_real_delta() {
+        let clock = ManualClock::new();
+        let mut time = Time::new();
+
+        step(&mut time, &clock, STEP);
+        time.set_time_scale(0.5);
+        step(&mut time, &clock, STEP);
+
+        assert_eq!(Duration::from_millis(50), time.delta());
+        assert_eq!(STEP, time.real_delta());
+        assert_eq!(Duration::from_millis(150), time.elapsed());
+        assert_eq!(Duration::from_millis(200), time.real_elapsed());
+        assert_eq!(2, time.frame_count());
+
+        time.set_time_scale(-1.0);
+        assert_eq!(0.0, time.time_scale());
+    }
+
+    #[test]
+    fn pause_stops_scaled_time_only() {
+        let clock = ManualClock::new();
+        let mut time = Time::new();
+        time.start_timer("scaled", Timer::once(STEP));
+        time.start_timer("unscaled", Timer::once(STEP).unscaled());
+        time.start_stopwatch("watch");
+
+        time.pause();
+        let finished = step(&mut time, &clock, STEP);
+
+        assert_eq!(Duration::default(), time.delta());
+        assert_eq!(Duration::default(), time.elapsed());
+        assert_eq!(Duration::default(), time.stopwatch("watch").unwrap().elapsed());
+        assert_eq!(vec!["unscaled"], finished.iter().map(|event| event.name.as_str()).collect::<Vec<_>>());
+
+        time.resume();
+        let finished = step(&mut time, &clock, STEP);
+
+        assert_eq!(STEP, time.elapsed());
+        assert_eq!(STEP, time.stopwatch("watch").unwrap().elapsed());
+        assert_eq!(vec!["scaled"], finished.iter().map(|event| event.name.as_str()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn named_once_timer_finishes_a_single_time() {
+        let clock = ManualClock::new();
+        let mut time = Time::new();
+        time.start_timer("spawn", Timer::once(Duration::from_millis(250)));
+
+        assert!(step(&mut time, &clock, STEP).is_empty());
+        assert!(step(&mut time, &clock, STEP).is_empty());
+
+        let finished = step(&mut time, &clock, STEP);
+        assert_eq!(1, finished.len());
+        assert_eq!("spawn", finished[0].name);
+        assert_eq!(1, finished[0].times);
+        assert!(time.timer("spawn").unwrap().is_finished());
+
+        assert!(step(&mut time, &clock, STEP).is_empty());
+    }
+
+    #[test]
+    fn named_repeating_timer_follows_scale() {
+        let clock = ManualClock::new();
+        let mut time = Time::new();
+        time.start_timer("tick", Timer::repeating(Duration::from_millis(40)));
+
+        // 100ms hold two whole repetitions, remainder carries over
+        let finished = step(&mut time, &clock, STEP);
+        assert_eq!(2, finished[0].times);
+        assert_eq!(Duration::from_millis(20), time.timer("tick").unwrap().elapsed());
+
+        time.set_time_scale(2.0);
+        let finished = step(&mut time, &clock, STEP);
+        assert_eq!(5, finished[0].times);
+        assert!(!time.timer("tick").unwrap().is_finished());
+
+        time.remove_timer("tick");
+        assert!(step(&mut time, &clock, STEP).is_empty());
+    }
+}
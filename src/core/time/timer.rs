@@ -0,0 +1,189 @@
+use std::{
+    convert::TryFrom,
+    fmt::{
+        self,
+        Debug,
+        Formatter
+    },
+    time::Duration
+};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+pub struct Timer {
+    duration: Duration,
+    elapsed: Duration,
+    repeating: bool,
+    unscaled: bool,
+    paused: bool,
+    finished: bool,
+    callback: Option<Box<dyn FnMut() + Send>>
+}
+
+impl Debug for Timer {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Timer")
+           .field("duration", &self.duration)
+           .field("elapsed", &self.elapsed)
+           .field("repeating", &self.repeating)
+           .field("unscaled", &self.unscaled)
+           .field("paused", &self.paused)
+           .field("finished", &self.finished)
+           .finish()
+    }
+}
+
+impl Timer {
+    /// Finishes once, after `duration`.
+    pub fn once(duration: Duration) -> Timer {
+        Timer {
+            duration,
+            elapsed: Duration::default(),
+            repeating: false,
+            unscaled: false,
+            paused: false,
+            finished: false,
+            callback: None
+        }
+    }
+
+    /// Finishes every `duration`, until it's removed.
+    pub fn repeating(duration: Duration) -> Timer {
+        Timer {
+            repeating: true,
+            ..Timer::once(duration)
+        }
+    }
+
+    /// Runs at real time, ignoring time scale and pause.
+    pub fn unscaled(mut self) -> Timer {
+        self.unscaled = true;
+        self
+    }
+
+    /// Called every time it finishes.
+    pub fn with_callback<F: 'static + FnMut() + Send>(mut self, callback: F) -> Timer {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+
+    /// Progress until next finish, at [0, 1].
+    pub fn fraction(&self) -> f64 {
+        if self.duration == Duration::default() {
+            return 1.0;
+        }
+
+        (self.elapsed.as_secs_f64() / self.duration.as_secs_f64()).min(1.0)
+    }
+
+    pub fn is_repeating(&self) -> bool {
+        self.repeating
+    }
+
+    pub fn is_unscaled(&self) -> bool {
+        self.unscaled
+    }
+
+    /// A repeating timer never stays finished.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::default();
+        self.finished = false;
+    }
+
+    /// Advances timer, returns how many times it has finished.
+    pub fn tick(&mut self, delta: Duration) -> u32 {
+        if self.paused || self.finished {
+            return 0;
+        }
+
+        self.elapsed = self.elapsed.saturating_add(delta);
+
+        if self.elapsed < self.duration {
+            return 0;
+        }
+
+        let times = if !self.repeating {
+            self.elapsed = self.duration;
+            self.finished = true;
+            1
+        } else if self.duration == Duration::default() {
+            // it would finish endlessly
+            self.elapsed = Duration::default();
+            1
+        } else {
+            let elapsed = self.elapsed.as_nanos();
+            let duration = self.duration.as_nanos();
+
+            // remainder is shorter than duration, so it always fits back
+            let remainder = elapsed % duration;
+            self.elapsed = Duration::new((remainder / NANOS_PER_SEC) as u64, (remainder % NANOS_PER_SEC) as u32);
+            u32::try_from(elapsed / duration).unwrap_or(u32::MAX)
+        };
+
+        if let Some(callback) = &mut self.callback {
+            for _ in 0..times {
+                callback();
+            }
+        }
+
+        times
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeating_timer_keeps_remainder() {
+        let mut timer = Timer::repeating(Duration::from_millis(300));
+
+        assert_eq!(3, timer.tick(Duration::from_millis(1000)));
+        assert_eq!(Duration::from_millis(100), timer.elapsed());
+    }
+
+    #[test]
+    fn repeating_timer_saturates_finish_count() {
+        let mut timer = Timer::repeating(Duration::from_nanos(1));
+
+        // more finishes than fit at u32, elapsed still lands at a whole duration
+        assert_eq!(u32::MAX, timer.tick(Duration::from_secs(10)));
+        assert_eq!(Duration::default(), timer.elapsed());
+
+        let mut timer = Timer::repeating(Duration::from_secs(3));
+        timer.tick(Duration::MAX);
+        assert!(timer.elapsed() < timer.duration());
+    }
+}
@@ -0,0 +1,8 @@
+/// Sent when a named timer, registered at `Time`, finishes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TimerFinished {
+    pub name: String,
+
+    /// A repeating timer can finish more than once at a single update.
+    pub times: u32
+}
@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+/// How game time advances between updates.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum Timestep {
+    /// One update per frame lasting measured frame time.
+    #[default]
+    Variable,
+
+    /// Updates of exactly this duration, as many as measured time allows, leftover is kept to next frame.
+    Fixed(Duration),
+
+    /// One update per frame of exactly this duration, no matter how long frame really took.
+    Locked(Duration)
+}
@@ -0,0 +1,160 @@
+use crate::{
+    events::{
+        Event,
+        EventListener
+    }
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct ListenerId(u64);
+
+struct RegisteredListener<E> {
+    id: ListenerId,
+    priority: i32,
+    listener: Box<dyn EventListener<E>>
+}
+
+pub struct EventDispatcher<E> {
+    listeners: Vec<RegisteredListener<E>>,
+    next_id: u64
+}
+
+impl<E> Default for EventDispatcher<E> {
+    fn default() -> Self {
+        EventDispatcher::new()
+    }
+}
+
+impl<E> EventDispatcher<E> {
+    pub fn new() -> EventDispatcher<E> {
+        EventDispatcher {
+            listeners: Vec::new(),
+            next_id: 0
+        }
+    }
+
+    // higher priorities are notified first, equal ones by registration order
+    pub fn register<L: 'static + EventListener<E>>(&mut self, priority: i32, listener: L) -> ListenerId {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+
+        let index = self.listeners
+                        .iter()
+                        .position(|registered| registered.priority < priority)
+                        .unwrap_or(self.listeners.len());
+
+        self.listeners.insert(index, RegisteredListener {
+            id,
+            priority,
+            listener: Box::new(listener)
+        });
+
+        id
+    }
+
+    pub fn unregister(&mut self, id: ListenerId) -> bool {
+        match self.listeners.iter().position(|registered| registered.id == id) {
+            Some(index) => {
+                self.listeners.remove(index);
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn dispatch(&mut self, event: &mut Event<E>) {
+        for registered in self.listeners.iter_mut() {
+            if event.is_consumed() {
+                break;
+            }
+
+            registered.listener.notify(event);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.listeners.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        rc::Rc
+    };
+
+    use super::*;
+
+    struct Recorder {
+        name: &'static str,
+        consumes: bool,
+        log: Rc<RefCell<Vec<&'static str>>>
+    }
+
+    impl EventListener<u32> for Recorder {
+        fn notify(&mut self, event: &mut Event<u32>) {
+            self.log.borrow_mut().push(self.name);
+
+            if self.consumes {
+                event.consume();
+            }
+        }
+    }
+
+    fn recorder(name: &'static str, consumes: bool, log: &Rc<RefCell<Vec<&'static str>>>) -> Recorder {
+        Recorder {
+            name,
+            consumes,
+            log: Rc::clone(log)
+        }
+    }
+
+    #[test]
+    fn higher_priorities_are_notified_first() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.register(0, recorder("low", false, &log));
+        dispatcher.register(10, recorder("high", false, &log));
+        dispatcher.register(0, recorder("low_later", false, &log));
+        dispatcher.register(5, recorder("middle", false, &log));
+
+        dispatcher.dispatch(&mut Event::new(1));
+
+        assert_eq!(vec!["high", "middle", "low", "low_later"], *log.borrow());
+    }
+
+    #[test]
+    fn consumed_event_stops_propagation() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.register(10, recorder("first", false, &log));
+        dispatcher.register(5, recorder("consumer", true, &log));
+        dispatcher.register(0, recorder("last", false, &log));
+
+        let mut event = Event::new(1);
+        dispatcher.dispatch(&mut event);
+
+        assert!(event.is_consumed());
+        assert_eq!(vec!["first", "consumer"], *log.borrow());
+    }
+
+    #[test]
+    fn unregistered_listener_isnt_notified() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = EventDispatcher::new();
+        let consumer = dispatcher.register(10, recorder("consumer", true, &log));
+        dispatcher.register(0, recorder("other", false, &log));
+
+        assert!(dispatcher.unregister(consumer));
+        assert!(!dispatcher.unregister(consumer));
+        dispatcher.dispatch(&mut Event::new(1));
+
+        assert_eq!(1, dispatcher.len());
+        assert_eq!(vec!["other"], *log.borrow());
+    }
+}
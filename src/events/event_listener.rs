@@ -1,3 +1,8 @@
+use std::{
+    cell::RefCell,
+    rc::Rc
+};
+
 use crate::{
     events::Event
 };
@@ -6,3 +11,9 @@ pub trait EventListener<E> {
     fn notify(&mut self, event: &mut Event<E>);
 }
 
+// allows a listener to be registered while it's still reachable from elsewhere
+impl<E, T: EventListener<E>> EventListener<E> for Rc<RefCell<T>> {
+    fn notify(&mut self, event: &mut Event<E>) {
+        self.borrow_mut().notify(event);
+    }
+}
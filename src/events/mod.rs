@@ -7,3 +7,8 @@ pub use event_handler::EventHandler;
 mod event_listener;
 pub use event_listener::EventListener;
 
+mod event_dispatcher;
+pub use event_dispatcher::{
+    EventDispatcher,
+    ListenerId
+};
@@ -1,5 +1,5 @@
 use crate::rendering::Renderer;
 
-pub trait Drawable : Drop {
+pub trait Drawable {
     fn draw(&mut self, renderer: &mut Renderer);
 }
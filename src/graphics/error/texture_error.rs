@@ -6,14 +6,9 @@ use std::{
         self,
         Display,
         Formatter
-    },
-    path::{
-        PathBuf
     }
 };
 
-use image_handler;
-
 use crate::{
     rendering::backend::error::{
         TextureBindingsError
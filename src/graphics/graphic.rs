@@ -1,7 +1,13 @@
 use crate::{
-    graphics::Drawable,
-    rendering::ResourceDisposable
+    graphics::{
+        Drawable,
+        GraphicData
+    }
 };
 
-pub trait Graphic : Drawable + ResourceDisposable {
+pub trait Graphic : Drawable {
+    // how it's described at scene files, when it can be
+    fn graphic_data(&self) -> Option<GraphicData> {
+        None
+    }
 }
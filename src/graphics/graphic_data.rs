@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+use crate::{
+    graphics::{
+        error::TextureError,
+        Graphic,
+        Image,
+        Texture
+    },
+    rendering::GraphicsDevice
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum GraphicData {
+    Image {
+        texture: PathBuf
+    }
+}
+
+impl GraphicData {
+    pub fn load(&self, device: &mut GraphicsDevice) -> Result<Box<dyn Graphic>, TextureError> {
+        match self {
+            GraphicData::Image { texture } => {
+                let texture = Texture::from_file(texture, device)?;
+                Ok(Box::new(Image::new(texture)))
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     graphics::{
         Drawable,
         Graphic,
+        GraphicData,
         Texture
     },
     rendering::{
@@ -9,19 +10,15 @@ use crate::{
             RendererBackend,
             RendererBackendInterface,
         },
-        GraphicsDevice,
         Renderer,
         RenderingRequirements,
-        ResourceDisposable,
-        StandardVertex,
-        panic_if_resource_isnt_disposed
+        StandardVertex
     }
 };
 
 pub struct Image {
     texture: Texture,
-    vertices: [StandardVertex; 6],
-    disposed: bool
+    vertices: [StandardVertex; 6]
 }
 
 impl Drawable for Image {
@@ -31,26 +28,10 @@ impl Drawable for Image {
 }
 
 impl Graphic for Image {
-}
-
-impl ResourceDisposable for Image {
-    fn is_disposed(&self) -> bool {
-        self.disposed
-    }
-
-    fn dispose(&mut self, device: &GraphicsDevice) {
-        if self.disposed {
-            return;
-        }
-
-        self.disposed = true;
-        self.texture.dispose(device)
-    }
-}
-
-impl Drop for Image {
-    fn drop(&mut self) {
-        panic_if_resource_isnt_disposed!(self);
+    fn graphic_data(&self) -> Option<GraphicData> {
+        Some(GraphicData::Image {
+            texture: self.texture.filepath().to_path_buf()
+        })
     }
 }
 
@@ -68,8 +49,11 @@ impl Image {
                 StandardVertex { position: [ -0.5,  0.33 ], uv: [0.0, 1.0] },
                 StandardVertex { position: [  0.5, -0.33 ], uv: [1.0, 0.0] },
                 StandardVertex { position: [ -0.5, -0.33 ], uv: [0.0, 0.0] }
-            ],
-            disposed: false
+            ]
         }
     }
+
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
 }
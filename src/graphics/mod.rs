@@ -6,6 +6,9 @@ pub use drawable::Drawable;
 mod graphic;
 pub use graphic::Graphic;
 
+mod graphic_data;
+pub use graphic_data::GraphicData;
+
 mod image;
 pub use image::Image;
 
@@ -34,7 +34,9 @@ impl Error for ShaderBuilderInitError {
 pub enum ShaderBuildError {
     VertexFileRead(std::io::Error),
     FragmentFileRead(std::io::Error),
+    #[cfg(not(feature = "no-backend"))]
     VertexCompilation(shaderc::Error),
+    #[cfg(not(feature = "no-backend"))]
     FragmentCompilation(shaderc::Error)
 }
 
@@ -47,9 +49,11 @@ impl Display for ShaderBuildError {
             ShaderBuildError::FragmentFileRead(err) => {
                 write!(fmt, "Can't read from fragment filepath. Cause: {}", err)
             },
+            #[cfg(not(feature = "no-backend"))]
             ShaderBuildError::VertexCompilation(err) => {
                 write!(fmt, "Couldn't compile vertex shader. Cause: {}", err)
             },
+            #[cfg(not(feature = "no-backend"))]
             ShaderBuildError::FragmentCompilation(err) => {
                 write!(fmt, "Couldn't compile fragment shader. Cause: {}", err)
             }
@@ -62,9 +66,10 @@ impl Error for ShaderBuildError {
         match self {
             ShaderBuildError::VertexFileRead(err) => Some(err),
             ShaderBuildError::FragmentFileRead(err) => Some(err),
+            #[cfg(not(feature = "no-backend"))]
             ShaderBuildError::VertexCompilation(err) => Some(err),
-            ShaderBuildError::FragmentCompilation(err) => Some(err),
-            _ => None
+            #[cfg(not(feature = "no-backend"))]
+            ShaderBuildError::FragmentCompilation(err) => Some(err)
         }
     }
 }
@@ -1,55 +1,42 @@
+use std::cell::Ref;
+
 use crate::{
     rendering::{
         backend::{
             RendererBackend,
             RendererBackendInterface,
         },
-        GraphicsDevice,
-        ResourceDisposable,
-        panic_if_resource_isnt_disposed
+        resources::ResourceHandle,
+        GraphicsDevice
     }
 };
 
 type ShaderBindings = <RendererBackend as RendererBackendInterface>::ShaderBindings;
 
+#[derive(Clone)]
 pub struct Shader {
-    pub bindings: ShaderBindings,
+    bindings: ResourceHandle<ShaderBindings>,
     vertex_data: Vec<u32>,
-    fragment_data: Vec<u32>,
-    disposed: bool
-}
-
-impl ResourceDisposable for Shader {
-    fn is_disposed(&self) -> bool {
-        self.disposed
-    }
-
-    fn dispose(&mut self, device: &GraphicsDevice) {
-        if self.disposed {
-            return;
-        }
-
-        self.disposed = true;
-        self.bindings.dispose(device);
-    }
-}
-
-impl Drop for Shader {
-    fn drop(&mut self) {
-        panic_if_resource_isnt_disposed!(self);
-    }
+    fragment_data: Vec<u32>
 }
 
 impl Shader {
     pub fn new(vertex_data: Vec<u32>, fragment_data: Vec<u32>, device: &GraphicsDevice) -> Self {
         Self {
-            bindings: ShaderBindings::new(device),
+            bindings: device.resources().register(ShaderBindings::new(device), "shader"),
             vertex_data,
-            fragment_data,
-            disposed: false
+            fragment_data
         }
     }
 
+    pub fn handle(&self) -> &ResourceHandle<ShaderBindings> {
+        &self.bindings
+    }
+
+    pub fn bindings(&self) -> Ref<'_, ShaderBindings> {
+        self.bindings.get()
+    }
+
     pub fn vertex_data(&self) -> &[u32] {
         &self.vertex_data[..]
     }
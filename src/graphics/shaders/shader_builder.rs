@@ -2,6 +2,7 @@ use std::{
     fs
 };
 
+#[cfg(not(feature = "no-backend"))]
 use shaderc;
 
 use crate::{
@@ -10,18 +11,15 @@ use crate::{
         ShaderBuildError,
         ShaderBuilderInitError
     },
-    rendering::{
-        backend::{
-            RendererBackendInterface
-        },
-        GraphicsDevice
-    }
+    rendering::GraphicsDevice
 };
 
 pub struct ShaderBuilder {
+    #[cfg(not(feature = "no-backend"))]
     compiler: shaderc::Compiler
 }
 
+#[cfg(not(feature = "no-backend"))]
 impl ShaderBuilder {
     pub fn new() -> Result<Self, ShaderBuilderInitError> {
         let compiler = match shaderc::Compiler::new() {
@@ -68,3 +66,22 @@ impl ShaderBuilder {
         ))
     }
 }
+
+#[cfg(feature = "no-backend")]
+impl ShaderBuilder {
+    pub fn new() -> Result<Self, ShaderBuilderInitError> {
+        Ok(Self {
+        })
+    }
+
+    /// Files are still read, but there is no backend to compile them to.
+    pub fn shader_from_files(&mut self, vertex_filepath: &str, fragment_filepath: &str, device: &GraphicsDevice) -> Result<Shader, ShaderBuildError> {
+        fs::read_to_string(vertex_filepath)
+                  .map_err(ShaderBuildError::VertexFileRead)?;
+
+        fs::read_to_string(fragment_filepath)
+                  .map_err(ShaderBuildError::FragmentFileRead)?;
+
+        Ok(Shader::new(Vec::new(), Vec::new(), device))
+    }
+}
@@ -1,9 +1,12 @@
 use std::{
-    path::Path
-};
-
-use core::mem::{
-    ManuallyDrop
+    cell::{
+        Ref,
+        RefMut
+    },
+    path::{
+        Path,
+        PathBuf
+    }
 };
 
 use crate::{
@@ -13,15 +16,9 @@ use crate::{
         }
     },
     rendering::{
-        backend::{
-            error::{
-                TextureBindingsError
-            },
-            TextureBindings
-        },
-        GraphicsDevice,
-        ResourceDisposable,
-        panic_if_resource_isnt_disposed
+        backend::TextureBindings,
+        resources::ResourceHandle,
+        GraphicsDevice
     }
 };
 
@@ -29,50 +26,50 @@ use crate::{
     math::Size
 };
 
+/// Cloning shares the same gpu texture, it's released when the last clone drops.
+#[derive(Clone)]
 pub struct Texture {
-    pub bindings: TextureBindings,
+    bindings: ResourceHandle<TextureBindings>,
     uid: u64,
-    disposed: bool
+    filepath: PathBuf
 }
 
-impl ResourceDisposable for Texture {
-    fn is_disposed(&self) -> bool {
-        self.disposed
-    }
+impl Texture {
+    pub fn from_file<P: AsRef<Path>>(filepath: P, device: &mut GraphicsDevice) -> Result<Self, TextureError> {
+        let bindings = TextureBindings::with(filepath.as_ref(), device)
+                                       .map_err(TextureError::Loading)?;
 
-    fn dispose(&mut self, device: &GraphicsDevice) {
-        if self.disposed {
-            return;
-        }
+        let bindings = device.resources()
+                             .register(bindings, filepath.as_ref().display().to_string());
 
-        self.disposed = true;
-        self.bindings.dispose(device);
+        Ok(Self {
+            uid: device.next_texture_uid(),
+            filepath: filepath.as_ref().to_path_buf(),
+            bindings
+        })
     }
-}
 
-impl Drop for Texture {
-    fn drop(&mut self) {
-        panic_if_resource_isnt_disposed!(self);
+    pub fn handle(&self) -> &ResourceHandle<TextureBindings> {
+        &self.bindings
     }
-}
 
-impl Texture {
-    pub fn from_file<P: AsRef<Path>>(filepath: P, device: &mut GraphicsDevice) -> Result<Self, TextureError> {
-        let bindings = TextureBindings::with(filepath, device)
-                                       .map_err(|e| TextureError::Loading(e))?;
+    pub fn bindings(&self) -> Ref<'_, TextureBindings> {
+        self.bindings.get()
+    }
 
-        Ok(Self {
-            uid: device.next_texture_uid(),
-            bindings,
-            disposed: false
-        })
+    pub fn bindings_mut(&self) -> RefMut<'_, TextureBindings> {
+        self.bindings.get_mut()
     }
 
     pub fn uid(&self) -> u64 {
         self.uid
     }
 
-    pub fn size(&self) -> &Size<u32> {
-        self.bindings.size()
+    pub fn filepath(&self) -> &Path {
+        &self.filepath
+    }
+
+    pub fn size(&self) -> Size<u32> {
+        *self.bindings().size()
     }
 }
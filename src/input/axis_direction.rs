@@ -0,0 +1,20 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize)]
+pub enum AxisDirection {
+    Positive,
+    Negative
+}
+
+impl AxisDirection {
+    /// Part of `value` which points to this direction, always positive.
+    pub fn extract(&self, value: f32) -> f32 {
+        match self {
+            AxisDirection::Positive => value.max(0.0),
+            AxisDirection::Negative => (-value).max(0.0)
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+use crate::input::InputBinding;
+
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AxisMapping {
+    pub positive: Vec<InputBinding>,
+    pub negative: Vec<InputBinding>,
+    pub dead_zone: f32
+}
+
+impl AxisMapping {
+    pub fn new() -> AxisMapping {
+        AxisMapping::default()
+    }
+
+    /// Digital axis, such as a pair of keys.
+    pub fn with(negative: InputBinding, positive: InputBinding) -> AxisMapping {
+        AxisMapping {
+            positive: vec![positive],
+            negative: vec![negative],
+            dead_zone: 0.0
+        }
+    }
+
+    pub fn with_positive(mut self, binding: InputBinding) -> AxisMapping {
+        self.positive.push(binding);
+        self
+    }
+
+    pub fn with_negative(mut self, binding: InputBinding) -> AxisMapping {
+        self.negative.push(binding);
+        self
+    }
+
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> AxisMapping {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    // remaps value outside dead zone back to [0, 1], so it doesn't jump when leaving it
+    pub(super) fn apply_dead_zone(&self, value: f32) -> f32 {
+        let dead_zone = self.dead_zone.clamp(0.0, 1.0);
+        let magnitude = value.abs();
+
+        if magnitude <= dead_zone || dead_zone >= 1.0 {
+            return 0.0;
+        }
+
+        value.signum() * ((magnitude - dead_zone) / (1.0 - dead_zone)).min(1.0)
+    }
+}
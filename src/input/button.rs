@@ -3,23 +3,25 @@ use crate::{
 };
 
 pub struct Button {
-    state: ButtonState
+    state: ButtonState,
+    release_pending: bool
 }
 
 impl Button {
     pub fn press(&mut self) {
         match &self.state {
             ButtonState::Released | ButtonState::Up => self.state = ButtonState::Pressed,
-            ButtonState::Pressed => self.state = ButtonState::Down,
-            ButtonState::Down => (),
+            ButtonState::Pressed => self.release_pending = false,
+            ButtonState::Down => ()
         }
     }
 
     pub fn release(&mut self) {
         match &self.state {
-            ButtonState::Up => (),
-            ButtonState::Pressed | ButtonState::Down => self.state = ButtonState::Released,
-            ButtonState::Released => self.state = ButtonState::Up,
+            // pressed and released at the same frame, still report the press
+            ButtonState::Pressed => self.release_pending = true,
+            ButtonState::Down => self.state = ButtonState::Released,
+            ButtonState::Released | ButtonState::Up => ()
         }
     }
 
@@ -28,36 +30,46 @@ impl Button {
     }
 
     pub fn is_pressed(&self) -> bool {
-        match &self.state {
-            ButtonState::Pressed => true,
-            _ => false
-        }
+        matches!(self.state, ButtonState::Pressed)
     }
 
     pub fn is_released(&self) -> bool {
-        match &self.state {
-            ButtonState::Released => true,
-            _ => false
-        }
+        matches!(self.state, ButtonState::Released)
     }
 
     pub fn is_down(&self) -> bool {
-        match &self.state {
-            ButtonState::Down => true,
-            _ => false
-        }
+        matches!(self.state, ButtonState::Down)
+    }
+
+    /// Either just pressed or still down.
+    pub fn is_held(&self) -> bool {
+        matches!(self.state, ButtonState::Pressed | ButtonState::Down)
     }
 
     pub fn is_up(&self) -> bool {
-        match &self.state {
-            ButtonState::Up => true,
-            _ => false
-        }
+        matches!(self.state, ButtonState::Up)
     }
 
-    pub(super) fn new() -> Self {
+    pub(in crate::input) fn is_release_pending(&self) -> bool {
+        self.release_pending
+    }
+
+    pub(in crate::input) fn new() -> Self {
         Self {
-            state: ButtonState::Up
+            state: ButtonState::Up,
+            release_pending: false
+        }
+    }
+
+    pub(in crate::input) fn update(&mut self) {
+        match &self.state {
+            ButtonState::Pressed if self.release_pending => {
+                self.state = ButtonState::Released;
+                self.release_pending = false;
+            },
+            ButtonState::Pressed => self.state = ButtonState::Down,
+            ButtonState::Released => self.state = ButtonState::Up,
+            ButtonState::Down | ButtonState::Up => ()
         }
     }
 }
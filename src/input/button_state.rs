@@ -1,5 +1,9 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize)]
 pub enum ButtonState {
     Pressed,
     Released,
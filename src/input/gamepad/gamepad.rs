@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::input::{
+    gamepad::{
+        GamepadAxis,
+        GamepadButton
+    },
+    Button
+};
+
+pub struct Gamepad {
+    name: String,
+    buttons: HashMap<GamepadButton, Button>,
+    axes: HashMap<GamepadAxis, f32>
+}
+
+impl Gamepad {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn button(&self, button: GamepadButton) -> &Button {
+        match self.buttons.get(&button) {
+            Some(b) => b,
+            None => panic!("Gamepad button {:?} isn't tracked.", button)
+        }
+    }
+
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes.get(&axis).copied().unwrap_or(0.0)
+    }
+
+    pub(in crate::input) fn new(name: String) -> Gamepad {
+        Gamepad {
+            name,
+            buttons: GamepadButton::ALL.iter().map(|button| (*button, Button::new())).collect(),
+            axes: HashMap::new()
+        }
+    }
+
+    pub(in crate::input) fn button_mut(&mut self, button: GamepadButton) -> &mut Button {
+        self.buttons
+            .entry(button)
+            .or_insert_with(Button::new)
+    }
+
+    pub(in crate::input) fn set_axis(&mut self, axis: GamepadAxis, value: f32) {
+        self.axes.insert(axis, value);
+    }
+
+    pub(in crate::input) fn update(&mut self) {
+        for button in self.buttons.values_mut() {
+            button.update();
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger
+}
+
+impl GamepadAxis {
+    /// Triggers rests at zero and goes up to 1, sticks goes from -1 to 1.
+    pub fn is_trigger(&self) -> bool {
+        matches!(self, GamepadAxis::LeftTrigger | GamepadAxis::RightTrigger)
+    }
+}
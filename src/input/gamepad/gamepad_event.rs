@@ -0,0 +1,54 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+use crate::input::{
+    gamepad::{
+        GamepadAxis,
+        GamepadButton,
+        GamepadId
+    },
+    ButtonState
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GamepadEvent {
+    Connected {
+        id: GamepadId,
+        name: String
+    },
+    Disconnected(GamepadId),
+    Button {
+        id: GamepadId,
+        button: GamepadButton,
+        state: ButtonState
+    },
+    Axis {
+        id: GamepadId,
+        axis: GamepadAxis,
+        value: f32
+    }
+}
+
+impl GamepadEvent {
+    pub fn id(&self) -> GamepadId {
+        match self {
+            GamepadEvent::Connected { id, .. } => *id,
+            GamepadEvent::Disconnected(id) => *id,
+            GamepadEvent::Button { id, .. } => *id,
+            GamepadEvent::Axis { id, .. } => *id
+        }
+    }
+
+    pub(super) fn with_id(mut self, new_id: GamepadId) -> GamepadEvent {
+        match &mut self {
+            GamepadEvent::Connected { id, .. } => *id = new_id,
+            GamepadEvent::Disconnected(id) => *id = new_id,
+            GamepadEvent::Button { id, .. } => *id = new_id,
+            GamepadEvent::Axis { id, .. } => *id = new_id
+        }
+
+        self
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter
+};
+
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, Serialize, Deserialize)]
+pub struct GamepadId(pub u32);
+
+impl Display for GamepadId {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "gamepad #{}", self.0)
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    collections::HashMap,
+    io::{
+        self,
+        ErrorKind
+    }
+};
+
+use crate::input::gamepad::{
+    GamepadEvent,
+    GamepadId,
+    GamepadSource,
+    RumbleEffect
+};
+
+/// Gathers every gamepad source, giving their gamepads unique ids.
+pub struct GamepadManager {
+    sources: Vec<Box<dyn GamepadSource>>,
+    ids: HashMap<(usize, GamepadId), GamepadId>,
+    next_id: u32
+}
+
+impl Default for GamepadManager {
+    fn default() -> Self {
+        GamepadManager::new()
+    }
+}
+
+impl GamepadManager {
+    pub fn new() -> GamepadManager {
+        GamepadManager {
+            sources: Vec::new(),
+            ids: HashMap::new(),
+            next_id: 0
+        }
+    }
+
+    /// Sources to reach devices at current platform.
+    pub fn with_platform_sources() -> GamepadManager {
+        let mut manager = GamepadManager::new();
+        manager.add_platform_sources();
+        manager
+    }
+
+    pub fn add_platform_sources(&mut self) {
+        #[cfg(target_os = "linux")]
+        self.add_source(crate::input::gamepad::JoydevSource::default());
+    }
+
+    pub fn add_source<S: 'static + GamepadSource>(&mut self, source: S) {
+        self.sources.push(Box::new(source));
+    }
+
+    pub fn clear_sources(&mut self) {
+        self.sources.clear();
+        self.ids.clear();
+    }
+
+    pub fn poll(&mut self) -> Vec<GamepadEvent> {
+        let mut events = Vec::new();
+        let mut source_events = Vec::new();
+
+        for (source_index, source) in self.sources.iter_mut().enumerate() {
+            source.poll(&mut source_events);
+
+            for event in source_events.drain(..) {
+                let key = (source_index, event.id());
+                let next_id = &mut self.next_id;
+
+                let id = *self.ids.entry(key).or_insert_with(|| {
+                    let id = GamepadId(*next_id);
+                    *next_id += 1;
+                    id
+                });
+
+                if let GamepadEvent::Disconnected(_) = event {
+                    self.ids.remove(&key);
+                }
+
+                events.push(event.with_id(id));
+            }
+        }
+
+        events
+    }
+
+    /// Fails with `ErrorKind::Unsupported` when gamepad source can't rumble.
+    pub fn rumble(&mut self, id: GamepadId, effect: RumbleEffect) -> io::Result<()> {
+        let (source_index, source_id) = self.source_of(id)?;
+
+        match self.sources[source_index].rumble() {
+            Some(rumble) => rumble.rumble(source_id, effect),
+            None => Err(io::Error::new(ErrorKind::Unsupported, "gamepad can't rumble"))
+        }
+    }
+
+    pub fn stop_rumble(&mut self, id: GamepadId) -> io::Result<()> {
+        let (source_index, source_id) = self.source_of(id)?;
+
+        match self.sources[source_index].rumble() {
+            Some(rumble) => rumble.stop_rumble(source_id),
+            None => Err(io::Error::new(ErrorKind::Unsupported, "gamepad can't rumble"))
+        }
+    }
+
+    fn source_of(&self, id: GamepadId) -> io::Result<(usize, GamepadId)> {
+        self.ids
+            .iter()
+            .find(|(_, global_id)| **global_id == id)
+            .map(|(key, _)| *key)
+            .ok_or_else(|| io::Error::new(ErrorKind::NotFound, format!("{} isn't connected", id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        time::Duration
+    };
+
+    use super::*;
+    use crate::{
+        events::Event,
+        input::{
+            gamepad::{
+                GamepadAxis,
+                GamepadButton
+            },
+            ButtonState,
+            Input,
+            InputEvent
+        }
+    };
+
+    #[derive(Clone, Default)]
+    struct FakeSource {
+        events: Rc<RefCell<Vec<GamepadEvent>>>
+    }
+
+    impl FakeSource {
+        fn push(&self, event: GamepadEvent) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+
+    impl GamepadSource for FakeSource {
+        fn poll(&mut self, events: &mut Vec<GamepadEvent>) {
+            events.append(&mut self.events.borrow_mut());
+        }
+    }
+
+    fn connected(id: u32) -> GamepadEvent {
+        GamepadEvent::Connected {
+            id: GamepadId(id),
+            name: format!("fake {}", id)
+        }
+    }
+
+    // polls manager and feeds input, as a frame does
+    fn frame(manager: &mut GamepadManager, input: &mut Input) -> Vec<GamepadEvent> {
+        input.update(Duration::default());
+
+        let events = manager.poll();
+
+        for event in &events {
+            input.handle(&mut Event::new(InputEvent::Gamepad(event.clone())));
+        }
+
+        events
+    }
+
+    #[test]
+    fn new_manager_has_no_sources() {
+        let mut manager = GamepadManager::new();
+        assert!(manager.poll().is_empty());
+    }
+
+    #[test]
+    fn connect_gives_unique_ids_across_sources() {
+        let mut manager = GamepadManager::new();
+        let mut input = Input::new();
+        let first = FakeSource::default();
+        let second = FakeSource::default();
+        manager.add_source(first.clone());
+        manager.add_source(second.clone());
+
+        first.push(connected(0));
+        second.push(connected(0));
+        let events = frame(&mut manager, &mut input);
+
+        assert_eq!(vec![GamepadId(0), GamepadId(1)], events.iter().map(GamepadEvent::id).collect::<Vec<_>>());
+        assert_eq!("fake 0", input.gamepad(GamepadId(1)).unwrap().name());
+        assert_eq!(2, input.gamepads().count());
+    }
+
+    #[test]
+    fn disconnect_removes_gamepad_and_reconnect_gets_a_new_id() {
+        let mut manager = GamepadManager::new();
+        let mut input = Input::new();
+        let source = FakeSource::default();
+        manager.add_source(source.clone());
+
+        source.push(connected(3));
+        frame(&mut manager, &mut input);
+        assert!(input.gamepad(GamepadId(0)).is_some());
+
+        source.push(GamepadEvent::Disconnected(GamepadId(3)));
+        let events = frame(&mut manager, &mut input);
+        assert_eq!(GamepadId(0), events[0].id());
+        assert!(input.gamepad(GamepadId(0)).is_none());
+        assert!(manager.rumble(GamepadId(0), RumbleEffect::new(1.0, 1.0, Duration::from_millis(100))).is_err());
+
+        source.push(connected(3));
+        let events = frame(&mut manager, &mut input);
+        assert_eq!(GamepadId(1), events[0].id());
+    }
+
+    #[test]
+    fn button_goes_through_every_state() {
+        let mut manager = GamepadManager::new();
+        let mut input = Input::new();
+        let source = FakeSource::default();
+        manager.add_source(source.clone());
+
+        source.push(connected(0));
+        source.push(GamepadEvent::Button { id: GamepadId(0), button: GamepadButton::South, state: ButtonState::Pressed });
+        frame(&mut manager, &mut input);
+
+        let button = |input: &Input| *input.gamepad(GamepadId(0)).unwrap().button(GamepadButton::South).state();
+        assert_eq!(ButtonState::Pressed, button(&input));
+
+        frame(&mut manager, &mut input);
+        assert_eq!(ButtonState::Down, button(&input));
+
+        source.push(GamepadEvent::Button { id: GamepadId(0), button: GamepadButton::South, state: ButtonState::Released });
+        frame(&mut manager, &mut input);
+        assert_eq!(ButtonState::Released, button(&input));
+
+        frame(&mut manager, &mut input);
+        assert_eq!(ButtonState::Up, button(&input));
+    }
+
+    #[test]
+    fn axis_keeps_last_value_until_changed() {
+        let mut manager = GamepadManager::new();
+        let mut input = Input::new();
+        let source = FakeSource::default();
+        manager.add_source(source.clone());
+
+        source.push(connected(0));
+        source.push(GamepadEvent::Axis { id: GamepadId(0), axis: GamepadAxis::LeftStickX, value: -0.75 });
+        frame(&mut manager, &mut input);
+
+        let gamepad = input.gamepad(GamepadId(0)).unwrap();
+        assert_eq!(-0.75, gamepad.axis(GamepadAxis::LeftStickX));
+        assert_eq!(0.0, gamepad.axis(GamepadAxis::RightTrigger));
+
+        frame(&mut manager, &mut input);
+        assert_eq!(-0.75, input.gamepad(GamepadId(0)).unwrap().axis(GamepadAxis::LeftStickX));
+
+        source.push(GamepadEvent::Axis { id: GamepadId(0), axis: GamepadAxis::LeftStickX, value: 0.0 });
+        frame(&mut manager, &mut input);
+        assert_eq!(0.0, input.gamepad(GamepadId(0)).unwrap().axis(GamepadAxis::LeftStickX));
+    }
+}
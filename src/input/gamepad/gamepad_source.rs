@@ -0,0 +1,13 @@
+use crate::input::gamepad::{
+    GamepadEvent,
+    Rumble
+};
+
+/// Provides gamepad events, ids only have to be unique inside the same source.
+pub trait GamepadSource {
+    fn poll(&mut self, events: &mut Vec<GamepadEvent>);
+
+    fn rumble(&mut self) -> Option<&mut dyn Rumble> {
+        None
+    }
+}
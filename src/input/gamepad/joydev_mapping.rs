@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::input::gamepad::{
+    GamepadAxis,
+    GamepadButton
+};
+
+/// Relates joydev button and axis numbers to standard ones.
+#[derive(Debug, Clone)]
+pub struct JoydevMapping {
+    pub buttons: HashMap<u8, GamepadButton>,
+    pub axes: HashMap<u8, GamepadAxis>,
+
+    /// Hat axes (horizontal, vertical) reported as dpad buttons.
+    pub dpad_axes: Option<(u8, u8)>
+}
+
+impl Default for JoydevMapping {
+    // xpad layout, used by most xinput compatible controllers
+    fn default() -> Self {
+        let buttons = [
+            (0, GamepadButton::South),
+            (1, GamepadButton::East),
+            (2, GamepadButton::West),
+            (3, GamepadButton::North),
+            (4, GamepadButton::LeftBumper),
+            (5, GamepadButton::RightBumper),
+            (6, GamepadButton::Select),
+            (7, GamepadButton::Start),
+            (8, GamepadButton::Mode),
+            (9, GamepadButton::LeftStick),
+            (10, GamepadButton::RightStick)
+        ];
+
+        let axes = [
+            (0, GamepadAxis::LeftStickX),
+            (1, GamepadAxis::LeftStickY),
+            (2, GamepadAxis::LeftTrigger),
+            (3, GamepadAxis::RightStickX),
+            (4, GamepadAxis::RightStickY),
+            (5, GamepadAxis::RightTrigger)
+        ];
+
+        JoydevMapping {
+            buttons: buttons.iter().copied().collect(),
+            axes: axes.iter().copied().collect(),
+            dpad_axes: Some((6, 7))
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::HashMap,
+    fs::{
+        self,
+        File
+    },
+    io::{
+        ErrorKind,
+        Read
+    },
+    path::{
+        Path,
+        PathBuf
+    },
+    sync::mpsc::{
+        self,
+        Receiver,
+        Sender
+    },
+    thread,
+    time::{
+        Duration,
+        Instant
+    }
+};
+
+use crate::input::{
+    gamepad::{
+        GamepadButton,
+        GamepadEvent,
+        GamepadId,
+        GamepadSource,
+        JoydevMapping
+    },
+    ButtonState
+};
+
+const JS_EVENT_BUTTON: u8 = 0x01;
+const JS_EVENT_AXIS: u8 = 0x02;
+const JS_EVENT_INIT: u8 = 0x80;
+const JS_EVENT_SIZE: usize = 8;
+
+enum JoydevMessage {
+    Event {
+        value: i16,
+        kind: u8,
+        number: u8
+    },
+
+    // device stopped answering, as when it's unplugged
+    Removed
+}
+
+struct JoydevDevice {
+    id: GamepadId,
+    hat: (i8, i8)
+}
+
+/// Reads every joydev device (js*) inside a directory, `/dev/input` by default.
+pub struct JoydevSource {
+    directory: PathBuf,
+    mapping: JoydevMapping,
+    devices: HashMap<PathBuf, JoydevDevice>,
+    sender: Sender<(PathBuf, JoydevMessage)>,
+    receiver: Receiver<(PathBuf, JoydevMessage)>,
+    next_id: u32,
+    scan_interval: Duration,
+    last_scan: Option<Instant>
+}
+
+impl Default for JoydevSource {
+    fn default() -> Self {
+        JoydevSource::new("/dev/input")
+    }
+}
+
+impl GamepadSource for JoydevSource {
+    fn poll(&mut self, events: &mut Vec<GamepadEvent>) {
+        let should_scan = match self.last_scan {
+            Some(last_scan) => last_scan.elapsed() >= self.scan_interval,
+            None => true
+        };
+
+        if should_scan {
+            self.scan(events);
+            self.last_scan = Some(Instant::now());
+        }
+
+        while let Ok((path, message)) = self.receiver.try_recv() {
+            match message {
+                JoydevMessage::Event { value, kind, number } => self.translate(&path, value, kind, number, events),
+                JoydevMessage::Removed => {
+                    if let Some(device) = self.devices.remove(&path) {
+                        events.push(GamepadEvent::Disconnected(device.id));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl JoydevSource {
+    pub fn new<P: Into<PathBuf>>(directory: P) -> JoydevSource {
+        let (sender, receiver) = mpsc::channel();
+
+        JoydevSource {
+            directory: directory.into(),
+            mapping: JoydevMapping::default(),
+            devices: HashMap::new(),
+            sender,
+            receiver,
+            next_id: 0,
+            scan_interval: Duration::from_secs(1),
+            last_scan: None
+        }
+    }
+
+    pub fn with_mapping(mut self, mapping: JoydevMapping) -> JoydevSource {
+        self.mapping = mapping;
+        self
+    }
+
+    /// How often directory is looked up for new devices.
+    pub fn with_scan_interval(mut self, scan_interval: Duration) -> JoydevSource {
+        self.scan_interval = scan_interval;
+        self
+    }
+
+    pub fn mapping(&self) -> &JoydevMapping {
+        &self.mapping
+    }
+
+    fn scan(&mut self, events: &mut Vec<GamepadEvent>) {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(_) => return
+        };
+
+        let mut paths: Vec<PathBuf> = entries.filter_map(|entry| entry.ok())
+                                             .map(|entry| entry.path())
+                                             .filter(|path| JoydevSource::is_joydev(path) && !self.devices.contains_key(path))
+                                             .collect();
+
+        paths.sort();
+
+        for path in paths {
+            // lack of permission is common, such devices are just ignored
+            let file = match File::open(&path) {
+                Ok(file) => file,
+                Err(_) => continue
+            };
+
+            let id = GamepadId(self.next_id);
+            self.next_id += 1;
+
+            self.devices.insert(path.clone(), JoydevDevice { id, hat: (0, 0) });
+            events.push(GamepadEvent::Connected { id, name: JoydevSource::device_name(&path) });
+
+            let sender = self.sender.clone();
+            thread::spawn(move || JoydevSource::read_device(path, file, sender));
+        }
+    }
+
+    // blocking reads are kept away from game thread
+    fn read_device(path: PathBuf, mut file: File, sender: Sender<(PathBuf, JoydevMessage)>) {
+        let mut buffer = [0u8; JS_EVENT_SIZE];
+
+        loop {
+            match file.read_exact(&mut buffer) {
+                Ok(()) => {
+                    let message = JoydevMessage::Event {
+                        value: i16::from_ne_bytes([buffer[4], buffer[5]]),
+                        kind: buffer[6],
+                        number: buffer[7]
+                    };
+
+                    if sender.send((path.clone(), message)).is_err() {
+                        return;
+                    }
+                },
+
+                // regular files ends, devices don't
+                Err(e) if e.kind() == ErrorKind::UnexpectedEof => return,
+                Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                Err(_) => {
+                    let _ = sender.send((path, JoydevMessage::Removed));
+                    return;
+                }
+            }
+        }
+    }
+
+    fn translate(&mut self, path: &Path, value: i16, kind: u8, number: u8, events: &mut Vec<GamepadEvent>) {
+        let device = match self.devices.get_mut(path) {
+            Some(device) => device,
+            None => return
+        };
+
+        let id = device.id;
+
+        match kind & !JS_EVENT_INIT {
+            JS_EVENT_BUTTON => {
+                if let Some(button) = self.mapping.buttons.get(&number) {
+                    events.push(GamepadEvent::Button {
+                        id,
+                        button: *button,
+                        state: if value != 0 { ButtonState::Pressed } else { ButtonState::Released }
+                    });
+                }
+            },
+            JS_EVENT_AXIS => {
+                match self.mapping.dpad_axes {
+                    Some((horizontal, _)) if horizontal == number => {
+                        let previous = device.hat.0;
+                        device.hat.0 = value.signum() as i8;
+                        JoydevSource::translate_hat(id, previous, device.hat.0, GamepadButton::DPadLeft, GamepadButton::DPadRight, events);
+                        return;
+                    },
+                    Some((_, vertical)) if vertical == number => {
+                        let previous = device.hat.1;
+                        device.hat.1 = value.signum() as i8;
+                        JoydevSource::translate_hat(id, previous, device.hat.1, GamepadButton::DPadUp, GamepadButton::DPadDown, events);
+                        return;
+                    },
+                    _ => ()
+                }
+
+                if let Some(axis) = self.mapping.axes.get(&number) {
+                    let mut normalized = (value as f32 / i16::MAX as f32).clamp(-1.0, 1.0);
+
+                    if axis.is_trigger() {
+                        normalized = (normalized + 1.0) / 2.0;
+                    }
+
+                    events.push(GamepadEvent::Axis {
+                        id,
+                        axis: *axis,
+                        value: normalized
+                    });
+                }
+            },
+            _ => ()
+        }
+    }
+
+    fn translate_hat(id: GamepadId, previous: i8, current: i8, negative: GamepadButton, positive: GamepadButton, events: &mut Vec<GamepadEvent>) {
+        if previous == current {
+            return;
+        }
+
+        let button_of = |direction: i8| if direction < 0 { negative } else { positive };
+
+        if previous != 0 {
+            events.push(GamepadEvent::Button { id, button: button_of(previous), state: ButtonState::Released });
+        }
+
+        if current != 0 {
+            events.push(GamepadEvent::Button { id, button: button_of(current), state: ButtonState::Pressed });
+        }
+    }
+
+    fn is_joydev(path: &Path) -> bool {
+        match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name.starts_with("js"),
+            None => false
+        }
+    }
+
+    fn device_name(path: &Path) -> String {
+        let file_name = path.file_name()
+                            .and_then(|name| name.to_str())
+                            .unwrap_or("js");
+
+        // sysfs has a friendlier name, when available
+        fs::read_to_string(Path::new("/sys/class/input").join(file_name).join("device/name"))
+           .map(|name| name.trim().to_owned())
+           .unwrap_or_else(|_| file_name.to_owned())
+    }
+}
@@ -0,0 +1,35 @@
+#[allow(clippy::module_inception)]
+mod gamepad;
+pub use gamepad::Gamepad;
+
+mod gamepad_id;
+pub use gamepad_id::GamepadId;
+
+mod gamepad_button;
+pub use gamepad_button::GamepadButton;
+
+mod gamepad_axis;
+pub use gamepad_axis::GamepadAxis;
+
+mod gamepad_event;
+pub use gamepad_event::GamepadEvent;
+
+mod gamepad_manager;
+pub use gamepad_manager::GamepadManager;
+
+mod gamepad_source;
+pub use gamepad_source::GamepadSource;
+
+mod rumble;
+pub use rumble::{
+    Rumble,
+    RumbleEffect
+};
+
+// linux joydev related
+
+mod joydev_mapping;
+pub use joydev_mapping::JoydevMapping;
+
+mod joydev_source;
+pub use joydev_source::JoydevSource;
@@ -0,0 +1,33 @@
+use std::{
+    io,
+    time::Duration
+};
+
+use crate::input::gamepad::GamepadId;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RumbleEffect {
+    /// Low frequency motor magnitude, at [0, 1].
+    pub strong: f32,
+
+    /// High frequency motor magnitude, at [0, 1].
+    pub weak: f32,
+
+    pub duration: Duration
+}
+
+impl RumbleEffect {
+    pub fn new(strong: f32, weak: f32, duration: Duration) -> RumbleEffect {
+        RumbleEffect {
+            strong,
+            weak,
+            duration
+        }
+    }
+}
+
+/// Force feedback, only provided by sources whose devices supports it.
+pub trait Rumble {
+    fn rumble(&mut self, id: GamepadId, effect: RumbleEffect) -> io::Result<()>;
+    fn stop_rumble(&mut self, id: GamepadId) -> io::Result<()>;
+}
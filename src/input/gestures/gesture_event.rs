@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use crate::{
+    input::gestures::SwipeDirection,
+    math::Vector2
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GestureEvent {
+    Tap {
+        position: Vector2<f64>
+    },
+    DoubleTap {
+        position: Vector2<f64>
+    },
+    LongPress {
+        position: Vector2<f64>
+    },
+    Swipe {
+        start: Vector2<f64>,
+        end: Vector2<f64>,
+        direction: SwipeDirection,
+        duration: Duration
+    },
+
+    /// Two fingers distance changed, `scale` is relative to previous pinch event.
+    Pinch {
+        center: Vector2<f64>,
+        scale: f64
+    },
+
+    /// Two fingers center moved by `delta`.
+    Pan {
+        center: Vector2<f64>,
+        delta: Vector2<f64>
+    }
+}
@@ -0,0 +1,395 @@
+use std::{
+    collections::BTreeMap,
+    time::Duration
+};
+
+use crate::{
+    input::{
+        gestures::{
+            GestureEvent,
+            GestureSettings,
+            SwipeDirection
+        },
+        TouchEvent,
+        TouchPhase
+    },
+    math::Vector2
+};
+
+struct TrackedTouch {
+    start_position: Vector2<f64>,
+    start_time: Duration,
+    position: Vector2<f64>,
+    left_tap_area: bool,
+    long_pressed: bool
+}
+
+struct TwoFingers {
+    center: Vector2<f64>,
+    distance: f64
+}
+
+/// Recognizes gestures from touch events, time is provided by caller so it can be driven by tests.
+pub struct GestureRecognizer {
+    settings: GestureSettings,
+    touches: BTreeMap<u64, TrackedTouch>,
+
+    // most fingers used since first one touched, a multi-touch can't end as a tap or swipe
+    max_touches: usize,
+
+    two_fingers: Option<TwoFingers>,
+    last_tap: Option<(Duration, Vector2<f64>)>
+}
+
+impl Default for GestureRecognizer {
+    fn default() -> Self {
+        GestureRecognizer::new(GestureSettings::default())
+    }
+}
+
+impl GestureRecognizer {
+    pub fn new(settings: GestureSettings) -> GestureRecognizer {
+        GestureRecognizer {
+            settings,
+            touches: BTreeMap::new(),
+            max_touches: 0,
+            two_fingers: None,
+            last_tap: None
+        }
+    }
+
+    pub fn settings(&self) -> &GestureSettings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut GestureSettings {
+        &mut self.settings
+    }
+
+    pub fn active_touches(&self) -> usize {
+        self.touches.len()
+    }
+
+    pub fn handle(&mut self, event: &TouchEvent, time: Duration, gestures: &mut Vec<GestureEvent>) {
+        match event.phase() {
+            TouchPhase::Started => {
+                self.touches.insert(event.id(), TrackedTouch {
+                    start_position: event.position(),
+                    start_time: time,
+                    position: event.position(),
+                    left_tap_area: false,
+                    long_pressed: false
+                });
+
+                self.max_touches = self.max_touches.max(self.touches.len());
+                self.two_fingers = self.two_fingers();
+            },
+            TouchPhase::Moved => {
+                let tap_distance = self.settings.tap_distance;
+
+                match self.touches.get_mut(&event.id()) {
+                    Some(touch) => {
+                        touch.position = event.position();
+
+                        if distance(touch.start_position, touch.position) > tap_distance {
+                            touch.left_tap_area = true;
+                        }
+                    },
+                    None => return
+                }
+
+                self.recognize_two_fingers(gestures);
+            },
+            TouchPhase::Ended => {
+                if let Some(touch) = self.touches.remove(&event.id()) {
+                    if self.max_touches == 1 && !touch.long_pressed {
+                        self.recognize_release(&touch, event.position(), time, gestures);
+                    }
+                }
+
+                self.end_touch();
+            },
+            TouchPhase::Cancelled => {
+                self.touches.remove(&event.id());
+                self.end_touch();
+            }
+        }
+    }
+
+    /// Checks gestures which only depends on time passing, as long press.
+    pub fn update(&mut self, time: Duration, gestures: &mut Vec<GestureEvent>) {
+        if self.max_touches != 1 {
+            return;
+        }
+
+        let long_press_duration = self.settings.long_press_duration;
+
+        for touch in self.touches.values_mut() {
+            if !touch.long_pressed
+              && !touch.left_tap_area
+              && time.checked_sub(touch.start_time).is_some_and(|elapsed| elapsed >= long_press_duration) {
+                touch.long_pressed = true;
+                gestures.push(GestureEvent::LongPress { position: touch.position });
+            }
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.touches.clear();
+        self.max_touches = 0;
+        self.two_fingers = None;
+        self.last_tap = None;
+    }
+
+    fn recognize_release(&mut self, touch: &TrackedTouch, position: Vector2<f64>, time: Duration, gestures: &mut Vec<GestureEvent>) {
+        let duration = time.checked_sub(touch.start_time).unwrap_or_default();
+        let displacement = position - touch.start_position;
+
+        if !touch.left_tap_area && distance(touch.start_position, position) <= self.settings.tap_distance {
+            if duration > self.settings.tap_duration {
+                return;
+            }
+
+            let is_double_tap = match self.last_tap {
+                Some((last_time, last_position)) => {
+                    time.checked_sub(last_time).is_some_and(|interval| interval <= self.settings.double_tap_interval)
+                      && distance(last_position, position) <= self.settings.tap_distance
+                },
+                None => false
+            };
+
+            if is_double_tap {
+                self.last_tap = None;
+                gestures.push(GestureEvent::DoubleTap { position });
+            } else {
+                self.last_tap = Some((time, position));
+                gestures.push(GestureEvent::Tap { position });
+            }
+        } else if distance(touch.start_position, position) >= self.settings.swipe_distance
+          && duration <= self.settings.swipe_duration {
+            gestures.push(GestureEvent::Swipe {
+                start: touch.start_position,
+                end: position,
+                direction: SwipeDirection::of(displacement),
+                duration
+            });
+        }
+    }
+
+    fn recognize_two_fingers(&mut self, gestures: &mut Vec<GestureEvent>) {
+        let (previous, current) = match (self.two_fingers.take(), self.two_fingers()) {
+            (Some(previous), Some(current)) => (previous, current),
+            (_, current) => {
+                self.two_fingers = current;
+                return;
+            }
+        };
+
+        let delta = current.center - previous.center;
+
+        if delta != Vector2::default() {
+            gestures.push(GestureEvent::Pan {
+                center: current.center,
+                delta
+            });
+        }
+
+        if previous.distance > 0.0 && (current.distance - previous.distance).abs() > f64::EPSILON {
+            gestures.push(GestureEvent::Pinch {
+                center: current.center,
+                scale: current.distance / previous.distance
+            });
+        }
+
+        self.two_fingers = Some(current);
+    }
+
+    fn two_fingers(&self) -> Option<TwoFingers> {
+        if self.touches.len() != 2 {
+            return None;
+        }
+
+        let mut positions = self.touches.values().map(|touch| touch.position);
+        let a = positions.next()?;
+        let b = positions.next()?;
+
+        Some(TwoFingers {
+            center: Vector2::with((a.x() + b.x()) / 2.0, (a.y() + b.y()) / 2.0),
+            distance: distance(a, b)
+        })
+    }
+
+    fn end_touch(&mut self) {
+        self.two_fingers = self.two_fingers();
+
+        if self.touches.is_empty() {
+            self.max_touches = 0;
+        }
+    }
+}
+
+fn distance(a: Vector2<f64>, b: Vector2<f64>) -> f64 {
+    let d = b - a;
+    (d.x() * d.x() + d.y() * d.y()).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // drives the recognizer with a manual clock, collecting every recognized gesture
+    struct Screen {
+        recognizer: GestureRecognizer,
+        time: Duration,
+        gestures: Vec<GestureEvent>
+    }
+
+    impl Screen {
+        fn new() -> Screen {
+            Screen {
+                recognizer: GestureRecognizer::default(),
+                time: Duration::default(),
+                gestures: Vec::new()
+            }
+        }
+
+        fn wait(&mut self, millis: u64) {
+            self.time += Duration::from_millis(millis);
+            self.recognizer.update(self.time, &mut self.gestures);
+        }
+
+        fn touch(&mut self, phase: TouchPhase, id: u64, x: f64, y: f64) {
+            self.recognizer.handle(&TouchEvent::new(phase, Vector2::with(x, y), id), self.time, &mut self.gestures);
+        }
+
+        fn tap(&mut self, x: f64, y: f64) {
+            self.touch(TouchPhase::Started, 0, x, y);
+            self.wait(50);
+            self.touch(TouchPhase::Ended, 0, x, y);
+        }
+
+        fn take(&mut self) -> Vec<GestureEvent> {
+            std::mem::take(&mut self.gestures)
+        }
+    }
+
+    #[test]
+    fn short_touch_is_a_tap() {
+        let mut screen = Screen::new();
+
+        screen.tap(10.0, 20.0);
+        assert_eq!(vec![GestureEvent::Tap { position: Vector2::with(10.0, 20.0) }], screen.take());
+
+        // held for too long, it isn't a tap anymore, nor a long press since it moved
+        screen.wait(1000);
+        screen.touch(TouchPhase::Started, 0, 10.0, 20.0);
+        screen.touch(TouchPhase::Moved, 0, 30.0, 20.0);
+        screen.touch(TouchPhase::Moved, 0, 12.0, 20.0);
+        screen.wait(400);
+        screen.touch(TouchPhase::Ended, 0, 12.0, 20.0);
+        assert!(screen.take().is_empty());
+    }
+
+    #[test]
+    fn close_taps_are_a_double_tap() {
+        let mut screen = Screen::new();
+
+        screen.tap(10.0, 10.0);
+        screen.wait(100);
+        screen.tap(14.0, 10.0);
+        screen.wait(100);
+        screen.tap(14.0, 10.0);
+
+        // a third tap starts over instead of being another double tap
+        assert_eq!(vec![
+            GestureEvent::Tap { position: Vector2::with(10.0, 10.0) },
+            GestureEvent::DoubleTap { position: Vector2::with(14.0, 10.0) },
+            GestureEvent::Tap { position: Vector2::with(14.0, 10.0) }
+        ], screen.take());
+
+        screen.wait(400);
+        screen.tap(14.0, 10.0);
+        assert_eq!(vec![GestureEvent::Tap { position: Vector2::with(14.0, 10.0) }], screen.take());
+    }
+
+    #[test]
+    fn held_touch_is_a_long_press_once() {
+        let mut screen = Screen::new();
+
+        screen.touch(TouchPhase::Started, 0, 5.0, 5.0);
+        screen.wait(499);
+        assert!(screen.take().is_empty());
+
+        screen.wait(1);
+        screen.wait(500);
+        assert_eq!(vec![GestureEvent::LongPress { position: Vector2::with(5.0, 5.0) }], screen.take());
+
+        // released after a long press, it doesn't also tap
+        screen.touch(TouchPhase::Ended, 0, 5.0, 5.0);
+        assert!(screen.take().is_empty());
+    }
+
+    #[test]
+    fn fast_long_move_is_a_swipe() {
+        let mut screen = Screen::new();
+
+        screen.touch(TouchPhase::Started, 0, 100.0, 100.0);
+        screen.wait(100);
+        screen.touch(TouchPhase::Moved, 0, 100.0, 60.0);
+        screen.wait(100);
+        screen.touch(TouchPhase::Ended, 0, 105.0, 20.0);
+
+        assert_eq!(vec![GestureEvent::Swipe {
+            start: Vector2::with(100.0, 100.0),
+            end: Vector2::with(105.0, 20.0),
+            direction: SwipeDirection::Up,
+            duration: Duration::from_millis(200)
+        }], screen.take());
+
+        // too slow to be a swipe
+        screen.touch(TouchPhase::Started, 0, 0.0, 0.0);
+        screen.touch(TouchPhase::Moved, 0, 80.0, 0.0);
+        screen.wait(600);
+        screen.touch(TouchPhase::Ended, 0, 80.0, 0.0);
+        assert!(screen.take().is_empty());
+    }
+
+    #[test]
+    fn two_fingers_moving_together_pan() {
+        let mut screen = Screen::new();
+
+        screen.touch(TouchPhase::Started, 0, 0.0, 0.0);
+        screen.touch(TouchPhase::Started, 1, 20.0, 0.0);
+        screen.touch(TouchPhase::Moved, 0, 0.0, 10.0);
+        screen.touch(TouchPhase::Moved, 1, 20.0, 10.0);
+
+        // one finger moving at a time also pinches a bit, only the center matters here
+        assert_eq!(vec![
+            GestureEvent::Pan { center: Vector2::with(10.0, 5.0), delta: Vector2::with(0.0, 5.0) },
+            GestureEvent::Pan { center: Vector2::with(10.0, 10.0), delta: Vector2::with(0.0, 5.0) }
+        ], screen.take().into_iter().filter(|gesture| matches!(gesture, GestureEvent::Pan { .. })).collect::<Vec<_>>());
+
+        // a multi-touch never ends as a tap
+        screen.touch(TouchPhase::Ended, 0, 0.0, 10.0);
+        screen.touch(TouchPhase::Ended, 1, 20.0, 10.0);
+        assert!(screen.take().is_empty());
+    }
+
+    #[test]
+    fn two_fingers_spreading_pinch() {
+        let mut screen = Screen::new();
+
+        screen.touch(TouchPhase::Started, 0, 40.0, 50.0);
+        screen.touch(TouchPhase::Started, 1, 60.0, 50.0);
+        screen.touch(TouchPhase::Moved, 0, 30.0, 50.0);
+        screen.touch(TouchPhase::Moved, 1, 70.0, 50.0);
+
+        // each finger moves the center, while scale is relative to previous pinch
+        assert_eq!(vec![
+            GestureEvent::Pan { center: Vector2::with(45.0, 50.0), delta: Vector2::with(-5.0, 0.0) },
+            GestureEvent::Pinch { center: Vector2::with(45.0, 50.0), scale: 1.5 },
+            GestureEvent::Pan { center: Vector2::with(50.0, 50.0), delta: Vector2::with(5.0, 0.0) },
+            GestureEvent::Pinch { center: Vector2::with(50.0, 50.0), scale: 40.0 / 30.0 }
+        ], screen.take());
+    }
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GestureSettings {
+    /// Longest touch which still counts as a tap.
+    pub tap_duration: Duration,
+
+    /// How far, in logical pixels, a finger may move and still tap or long press.
+    pub tap_distance: f64,
+
+    /// Longest time between taps of a double tap.
+    pub double_tap_interval: Duration,
+
+    pub long_press_duration: Duration,
+
+    /// Shortest distance, in logical pixels, to be recognized as a swipe.
+    pub swipe_distance: f64,
+
+    /// Longest touch which still counts as a swipe.
+    pub swipe_duration: Duration
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        GestureSettings {
+            tap_duration: Duration::from_millis(300),
+            tap_distance: 10.0,
+            double_tap_interval: Duration::from_millis(300),
+            long_press_duration: Duration::from_millis(500),
+            swipe_distance: 50.0,
+            swipe_duration: Duration::from_millis(500)
+        }
+    }
+}
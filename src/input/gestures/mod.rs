@@ -0,0 +1,11 @@
+mod gesture_recognizer;
+pub use gesture_recognizer::GestureRecognizer;
+
+mod gesture_event;
+pub use gesture_event::GestureEvent;
+
+mod gesture_settings;
+pub use gesture_settings::GestureSettings;
+
+mod swipe_direction;
+pub use swipe_direction::SwipeDirection;
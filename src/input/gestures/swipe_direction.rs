@@ -0,0 +1,22 @@
+use crate::math::Vector2;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum SwipeDirection {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+impl SwipeDirection {
+    /// Dominant direction of a screen space displacement, where y grows downwards.
+    pub fn of(displacement: Vector2<f64>) -> SwipeDirection {
+        if displacement.x().abs() >= displacement.y().abs() {
+            if displacement.x() < 0.0 { SwipeDirection::Left } else { SwipeDirection::Right }
+        } else if displacement.y() < 0.0 {
+            SwipeDirection::Up
+        } else {
+            SwipeDirection::Down
+        }
+    }
+}
@@ -1,17 +1,47 @@
-use std::collections::HashMap;
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        HashSet
+    },
+    time::Duration
+};
 
 use crate::{
     events::Event,
     input::{
+        gamepad::{
+            Gamepad,
+            GamepadAxis,
+            GamepadEvent,
+            GamepadId
+        },
+        gestures::{
+            GestureEvent,
+            GestureRecognizer
+        },
+        text::TextInput,
+        AxisDirection,
+        AxisMapping,
         Button,
         ButtonState,
+        InputBinding,
         InputEvent,
+        InputMap,
         KeyCode,
-        MouseButton
+        KeyModifiers,
+        MouseButton,
+        MouseScrollDelta,
+        Touch,
+        TouchPhase,
+        WheelDirection
     },
     math::Vector2
 };
 
+// analog values must go beyond it to count as a held button
+const AXIS_PRESS_THRESHOLD: f32 = 0.5;
+
 pub struct Input {
     // keyboard
     // TODO  maybe it should be using scancode instead keycode
@@ -20,7 +50,31 @@ pub struct Input {
     // mouse
     mouse_position: Vector2<f64>,
     mouse_buttons: HashMap<MouseButton, Button>,
-    mouse_extra_buttons: HashMap<u8, Button>
+    mouse_extra_buttons: HashMap<u8, Button>,
+    mouse_wheel_lines: Vector2<f32>,
+    mouse_wheel_pixels: Vector2<f64>,
+
+    // devices axes
+    axes: HashMap<u32, f64>,
+
+    // gamepads
+    gamepads: BTreeMap<GamepadId, Gamepad>,
+
+    // touch
+    touches: BTreeMap<u64, Touch>,
+    gesture_recognizer: GestureRecognizer,
+    gestures: Vec<GestureEvent>,
+
+    // text
+    text: String,
+    text_input: TextInput,
+
+    // mapping
+    map: InputMap,
+    last_binding: Option<InputBinding>,
+
+    // time since game start, as seen by input
+    time: Duration
 }
 
 macro_rules! update_state {
@@ -31,93 +85,319 @@ macro_rules! update_state {
             $button.release();
         }
 
-        let state = $button.state();
-        if *$button_current_state != *state {
-            *$button_current_state = *state;
+        // a release at the same frame of it's press keeps arriving as released
+        if !$button.is_release_pending() {
+            let state = $button.state();
+            if *$button_current_state != *state {
+                *$button_current_state = *state;
+            }
         }
     }
 }
 
-/*
-impl InputEventsIndirectHandler<Box<&mut dyn InputEventListener>> for Input {
-    fn handle(&mut self, listener: &mut Box<&mut dyn InputEventListener>, events: &mut Vec<Event<InputEvent>>) {
-        for event in events.iter_mut() {
-            listener.handle(event);
+impl Input {
+    pub fn key(&self, key: KeyCode) -> Option<&Button> {
+        self.keys.get(&key)
+    }
+
+    pub fn key_mut(&mut self, key: KeyCode) -> Option<&mut Button> {
+        self.keys.get_mut(&key)
+    }
+
+    pub fn mouse_button(&self, button: MouseButton) -> Option<&Button> {
+        match button {
+            MouseButton::Other(id) => self.mouse_extra_buttons.get(&id),
+            _ => self.mouse_buttons.get(&button)
         }
     }
 
-    fn handle_multiple(&mut self, mut listeners: Vec<Box<&mut dyn InputEventListener>>, events: &mut Vec<Event<InputEvent>>) {
-        for event in events.iter_mut() {
-            match &mut event.kind_mut() {
-                InputEvent::Keyboard(ref mut e) => {
-                    let key_state = &mut e.state;
-
-                    match &e.key {
-                        Some(keycode) => {
-                            match self.keys.get_mut(&keycode) {
-                                Some(ref mut key) => {
-                                    update_state!(key, key_state);
-                                },
-                                None => {
-                                    println!("Keyboard's key {:?} not registered.", &keycode);
-                                }
-                            };
-                        },
-                        None => ()
-                    };
-                },
-                InputEvent::MouseButton(ref mut e) => {
-                    let button_state = &mut e.state;
-
-                    if let MouseButton::Other(id) = e.button {
-                        match self.mouse_extra_buttons.get_mut(&id) {
-                            Some(ref mut button) => {
-                                update_state!(button, button_state);
-                            },
-                            None => {
-                                let mut button = Button::new();
-                                update_state!(button, button_state);
-                                self.mouse_extra_buttons.insert(id, button);
-                            }
-                        }
-                    } else {
-                        match self.mouse_buttons.get_mut(&e.button) {
-                            Some(ref mut button) => { 
-                                update_state!(button, button_state);
-                            },
-                            None => {
-                                println!("Mouse button {:?} not registered.", &e.button);
-                            }
-                        }
-                    }
-                },
-                InputEvent::CursorEntered => {
-                    // TODO  enable mouse interaction
-                },
-                InputEvent::CursorLeft => {
-                    // TODO  disable mouse interaction
-                },
-                InputEvent::CursorMoved { position, modifiers } => {
-                    self.mouse_position = *position;
-                },
-                _ => (),
-            };
+    pub fn mouse_position(&self) -> Vector2<f64> {
+        self.mouse_position
+    }
 
-            for listener in listeners.iter_mut() {
-                listener.handle(event);
-            }
+    /// Wheel scroll, in lines, received at current frame.
+    pub fn mouse_wheel_lines(&self) -> Vector2<f32> {
+        self.mouse_wheel_lines
+    }
+
+    /// Wheel scroll, in pixels, received at current frame.
+    pub fn mouse_wheel_pixels(&self) -> Vector2<f64> {
+        self.mouse_wheel_pixels
+    }
+
+    /// Characters typed at current frame, control characters excluded.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn text_input(&self) -> &TextInput {
+        &self.text_input
+    }
+
+    pub fn text_input_mut(&mut self) -> &mut TextInput {
+        &mut self.text_input
+    }
+
+    /// Last value reported by a device axis, it's expected to be normalized at [-1, 1].
+    pub fn raw_axis(&self, axis: u32) -> f64 {
+        self.axes.get(&axis).copied().unwrap_or(0.0)
+    }
+
+    pub fn gamepad(&self, id: GamepadId) -> Option<&Gamepad> {
+        self.gamepads.get(&id)
+    }
+
+    /// Connected gamepads, ordered by id.
+    pub fn gamepads(&self) -> impl Iterator<Item = (GamepadId, &Gamepad)> {
+        self.gamepads.iter().map(|(id, gamepad)| (*id, gamepad))
+    }
+
+    pub fn touch(&self, id: u64) -> Option<&Touch> {
+        self.touches.get(&id)
+    }
+
+    /// Active touches, and the ones which ended at current frame.
+    pub fn touches(&self) -> impl Iterator<Item = &Touch> {
+        self.touches.values()
+    }
+
+    /// Gestures recognized at current frame.
+    pub fn gestures(&self) -> &[GestureEvent] {
+        &self.gestures
+    }
+
+    pub fn gesture_recognizer(&self) -> &GestureRecognizer {
+        &self.gesture_recognizer
+    }
+
+    pub fn gesture_recognizer_mut(&mut self) -> &mut GestureRecognizer {
+        &mut self.gesture_recognizer
+    }
+
+    pub fn modifiers(&self) -> KeyModifiers {
+        let is_held = |keys: &[KeyCode]| {
+            keys.iter().any(|key| self.keys.get(key).is_some_and(Button::is_held))
+        };
+
+        KeyModifiers {
+            ctrl: is_held(&[KeyCode::LeftControl, KeyCode::RightControl]),
+            shift: is_held(&[KeyCode::LeftShift, KeyCode::RightShift]),
+            alt: is_held(&[KeyCode::LeftAlt, KeyCode::RightAlt]),
+            command: is_held(&[KeyCode::LeftCommand, KeyCode::RightCommand])
         }
     }
-}
-*/
 
-impl Input {
-    pub fn key(&self, key: KeyCode) -> Option<&Button> {
-        self.keys.get(&key)
+    /// Binding which started being held at current frame, useful to let players rebind controls.
+    pub fn last_binding(&self) -> Option<&InputBinding> {
+        self.last_binding.as_ref()
     }
 
-    pub fn key_mut(&mut self, key: KeyCode) -> Option<&mut Button> {
-        self.keys.get_mut(&key)
+    pub fn input_map(&self) -> &InputMap {
+        &self.map
+    }
+
+    pub fn input_map_mut(&mut self) -> &mut InputMap {
+        &mut self.map
+    }
+
+    pub fn set_input_map(&mut self, map: InputMap) {
+        self.map = map;
+    }
+
+    pub fn action_state(&self, name: &str) -> ButtonState {
+        let is_held = self.is_action_held(name);
+
+        match (is_held, self.map.was_held(name)) {
+            (true, false) => ButtonState::Pressed,
+            (true, true) => ButtonState::Down,
+            (false, true) => ButtonState::Released,
+            (false, false) => ButtonState::Up
+        }
+    }
+
+    pub fn is_action_pressed(&self, name: &str) -> bool {
+        self.action_state(name) == ButtonState::Pressed
+    }
+
+    pub fn is_action_released(&self, name: &str) -> bool {
+        self.action_state(name) == ButtonState::Released
+    }
+
+    pub fn is_action_down(&self, name: &str) -> bool {
+        self.action_state(name) == ButtonState::Down
+    }
+
+    pub fn is_action_up(&self, name: &str) -> bool {
+        self.action_state(name) == ButtonState::Up
+    }
+
+    /// Either just pressed or still down.
+    pub fn is_action_held(&self, name: &str) -> bool {
+        self.map
+            .action_bindings(name)
+            .iter()
+            .any(|binding| self.is_binding_held(binding))
+    }
+
+    /// Mapped axis value at [-1, 1], or zero when it isn't mapped.
+    pub fn axis(&self, name: &str) -> f32 {
+        let mapping = match self.map.axis_mapping(name) {
+            Some(mapping) => mapping,
+            None => return 0.0
+        };
+
+        let positive: f32 = mapping.positive
+                                   .iter()
+                                   .map(|binding| self.binding_value(binding, mapping))
+                                   .sum();
+
+        let negative: f32 = mapping.negative
+                                   .iter()
+                                   .map(|binding| self.binding_value(binding, mapping))
+                                   .sum();
+
+        (positive - negative).clamp(-1.0, 1.0)
+    }
+
+    // advances every button state, must be called once at frame end
+    pub(crate) fn update(&mut self, delta: Duration) {
+        self.time += delta;
+        let held_actions: HashSet<String> = self.map
+                                                .actions()
+                                                .filter(|(_, bindings)| bindings.iter().any(|binding| self.is_binding_held(binding)))
+                                                .map(|(name, _)| name.clone())
+                                                .collect();
+
+        self.map.set_held_actions(held_actions);
+        self.last_binding = None;
+
+        for button in self.keys.values_mut() {
+            button.update();
+        }
+
+        for button in self.mouse_buttons.values_mut() {
+            button.update();
+        }
+
+        for button in self.mouse_extra_buttons.values_mut() {
+            button.update();
+        }
+
+        for gamepad in self.gamepads.values_mut() {
+            gamepad.update();
+        }
+
+        self.touches.retain(|_, touch| touch.is_active());
+        self.gestures.clear();
+        self.gesture_recognizer.update(self.time, &mut self.gestures);
+
+        self.mouse_wheel_lines = Vector2::default();
+        self.mouse_wheel_pixels = Vector2::default();
+        self.text.clear();
+        self.text_input.update();
+    }
+
+    // event state is rewritten to match it's button, so a repeated press arrives as Down
+    pub(crate) fn handle(&mut self, event: &mut Event<InputEvent>) {
+        match event.kind_mut() {
+            InputEvent::Keyboard(ref mut e) => {
+                let modifiers = self.modifiers();
+                let key_state = &mut e.state;
+
+                if let Some(keycode) = &e.key {
+                    // repeated presses still arrive as pressed here
+                    if let ButtonState::Pressed = key_state {
+                        self.text_input.handle_key(*keycode, modifiers);
+                    }
+
+                    if let Some(key) = self.keys.get_mut(keycode) {
+                        update_state!(key, key_state);
+
+                        if let ButtonState::Pressed = key_state {
+                            self.last_binding = Some(InputBinding::chord(*keycode, modifiers.without_key(*keycode)));
+                        }
+                    }
+                }
+            },
+            InputEvent::MouseButton(ref mut e) => {
+                let button_state = &mut e.state;
+
+                if let MouseButton::Other(id) = e.button {
+                    let button = self.mouse_extra_buttons
+                                     .entry(id)
+                                     .or_insert_with(Button::new);
+
+                    update_state!(button, button_state);
+                } else if let Some(button) = self.mouse_buttons.get_mut(&e.button) {
+                    update_state!(button, button_state);
+                }
+
+                if let ButtonState::Pressed = button_state {
+                    self.last_binding = Some(InputBinding::MouseButton(e.button));
+                }
+            },
+            InputEvent::CursorMoved { position, .. } => {
+                self.mouse_position = *position;
+            },
+            InputEvent::MouseWheel { delta, .. } => {
+                let (horizontal, vertical) = match delta {
+                    MouseScrollDelta::Line { horizontal, vertical } => {
+                        self.mouse_wheel_lines += Vector2::with(*horizontal, *vertical);
+                        (*horizontal as f64, *vertical as f64)
+                    },
+                    MouseScrollDelta::Pixel(value) => {
+                        self.mouse_wheel_pixels += *value;
+                        (value.x(), value.y())
+                    }
+                };
+
+                let direction = if vertical.abs() >= horizontal.abs() {
+                    if vertical > 0.0 { WheelDirection::Up } else { WheelDirection::Down }
+                } else if horizontal > 0.0 {
+                    WheelDirection::Right
+                } else {
+                    WheelDirection::Left
+                };
+
+                if horizontal != 0.0 || vertical != 0.0 {
+                    self.last_binding = Some(InputBinding::MouseWheel(direction));
+                }
+            },
+            InputEvent::AxisMotion { axis, value } => {
+                let previous = self.axes.insert(*axis, *value).unwrap_or(0.0);
+
+                for direction in &[AxisDirection::Positive, AxisDirection::Negative] {
+                    if direction.extract(previous as f32) <= AXIS_PRESS_THRESHOLD
+                      && direction.extract(*value as f32) > AXIS_PRESS_THRESHOLD {
+                        self.last_binding = Some(InputBinding::Axis {
+                            axis: *axis,
+                            direction: *direction
+                        });
+                    }
+                }
+            },
+            InputEvent::Touch(e) => {
+                match e.phase() {
+                    TouchPhase::Started => {
+                        self.touches.insert(e.id(), Touch::new(e.id(), e.position(), self.time));
+                    },
+                    phase => {
+                        if let Some(touch) = self.touches.get_mut(&e.id()) {
+                            touch.set(phase, e.position());
+                        }
+                    }
+                }
+
+                self.gesture_recognizer.handle(e, self.time, &mut self.gestures);
+            },
+            InputEvent::Gamepad(e) => self.handle_gamepad(e),
+            InputEvent::ReceivedChar(c) if !c.is_control() => {
+                self.text.push(*c);
+                self.text_input.handle_char(*c);
+            },
+            InputEvent::Ime(e) => self.text_input.handle_ime(e),
+            _ => ()
+        }
     }
 
     pub(crate) fn new() -> Input {
@@ -295,7 +575,276 @@ impl Input {
             keys,
             mouse_position: Vector2::default(),
             mouse_buttons,
-            mouse_extra_buttons: HashMap::new()
+            mouse_extra_buttons: HashMap::new(),
+            mouse_wheel_lines: Vector2::default(),
+            mouse_wheel_pixels: Vector2::default(),
+            axes: HashMap::new(),
+            gamepads: BTreeMap::new(),
+            touches: BTreeMap::new(),
+            gesture_recognizer: GestureRecognizer::default(),
+            gestures: Vec::new(),
+            text: String::new(),
+            text_input: TextInput::new(),
+            map: InputMap::new(),
+            last_binding: None,
+            time: Duration::default()
+        }
+    }
+
+    fn is_binding_held(&self, binding: &InputBinding) -> bool {
+        match binding {
+            // unbound modifiers are ignored, so Space still jumps while Shift is held to sprint
+            InputBinding::Key { key, modifiers } => {
+                let held_modifiers = self.modifiers().without_key(*key);
+
+                self.keys.get(key).is_some_and(Button::is_held)
+                  && held_modifiers.contains(*modifiers)
+                  && !self.is_chord_shadowed(*key, *modifiers, held_modifiers)
+            },
+            InputBinding::MouseButton(button) => {
+                self.mouse_button(*button).is_some_and(Button::is_held)
+            },
+            InputBinding::MouseWheel(direction) => self.wheel_value(*direction) > 0.0,
+            InputBinding::Axis { axis, direction } => {
+                direction.extract(self.raw_axis(*axis) as f32) > AXIS_PRESS_THRESHOLD
+            },
+            InputBinding::GamepadButton(button) => {
+                self.gamepads
+                    .values()
+                    .any(|gamepad| gamepad.button(*button).is_held())
+            },
+            InputBinding::GamepadAxis { axis, direction } => {
+                direction.extract(self.gamepad_axis(*axis)) > AXIS_PRESS_THRESHOLD
+            }
+        }
+    }
+
+    // a held chord of the same key with more modifiers wins, so S doesn't fire while Ctrl+S is held
+    fn is_chord_shadowed(&self, key: KeyCode, modifiers: KeyModifiers, held_modifiers: KeyModifiers) -> bool {
+        self.map
+            .bindings()
+            .any(|binding| match binding {
+                InputBinding::Key { key: other_key, modifiers: other_modifiers } => {
+                    *other_key == key
+                      && *other_modifiers != modifiers
+                      && other_modifiers.contains(modifiers)
+                      && held_modifiers.contains(*other_modifiers)
+                },
+                _ => false
+            })
+    }
+
+    fn binding_value(&self, binding: &InputBinding, mapping: &AxisMapping) -> f32 {
+        match binding {
+            InputBinding::MouseWheel(direction) => self.wheel_value(*direction),
+            InputBinding::Axis { axis, direction } => {
+                direction.extract(mapping.apply_dead_zone(self.raw_axis(*axis) as f32))
+            },
+            InputBinding::GamepadAxis { axis, direction } => {
+                direction.extract(mapping.apply_dead_zone(self.gamepad_axis(*axis)))
+            },
+            _ => {
+                if self.is_binding_held(binding) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    // the most pushed among every gamepad
+    fn gamepad_axis(&self, axis: GamepadAxis) -> f32 {
+        self.gamepads
+            .values()
+            .map(|gamepad| gamepad.axis(axis))
+            .fold(0.0, |value, other| if other.abs() > value.abs() { other } else { value })
+    }
+
+    fn handle_gamepad(&mut self, event: &mut GamepadEvent) {
+        match event {
+            GamepadEvent::Connected { id, name } => {
+                self.gamepads.insert(*id, Gamepad::new(name.clone()));
+            },
+            GamepadEvent::Disconnected(id) => {
+                self.gamepads.remove(id);
+            },
+            GamepadEvent::Button { id, button, state } => {
+                let gamepad = self.gamepads
+                                  .entry(*id)
+                                  .or_insert_with(|| Gamepad::new(id.to_string()));
+
+                let button_state = state;
+                let gamepad_button = gamepad.button_mut(*button);
+                update_state!(gamepad_button, button_state);
+
+                if let ButtonState::Pressed = button_state {
+                    self.last_binding = Some(InputBinding::GamepadButton(*button));
+                }
+            },
+            GamepadEvent::Axis { id, axis, value } => {
+                let gamepad = self.gamepads
+                                  .entry(*id)
+                                  .or_insert_with(|| Gamepad::new(id.to_string()));
+
+                let previous = gamepad.axis(*axis);
+                gamepad.set_axis(*axis, *value);
+
+                for direction in &[AxisDirection::Positive, AxisDirection::Negative] {
+                    if direction.extract(previous) <= AXIS_PRESS_THRESHOLD
+                      && direction.extract(*value) > AXIS_PRESS_THRESHOLD {
+                        self.last_binding = Some(InputBinding::GamepadAxis {
+                            axis: *axis,
+                            direction: *direction
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn wheel_value(&self, direction: WheelDirection) -> f32 {
+        // pixel deltas only matters when device doesn't report lines
+        let (horizontal, vertical) = if self.mouse_wheel_lines != Vector2::default() {
+            (self.mouse_wheel_lines.x(), self.mouse_wheel_lines.y())
+        } else {
+            (self.mouse_wheel_pixels.x() as f32, self.mouse_wheel_pixels.y() as f32)
+        };
+
+        match direction {
+            WheelDirection::Up => AxisDirection::Positive.extract(vertical),
+            WheelDirection::Down => AxisDirection::Negative.extract(vertical),
+            WheelDirection::Right => AxisDirection::Positive.extract(horizontal),
+            WheelDirection::Left => AxisDirection::Negative.extract(horizontal)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{
+        gamepad::GamepadButton,
+        KeyboardEvent
+    };
+
+    fn key_event(key: KeyCode, state: ButtonState) -> Event<InputEvent> {
+        Event::new(InputEvent::Keyboard(KeyboardEvent::new(0, state, Some(key), KeyModifiers::default())))
+    }
+
+    fn gamepad_button_event(button: GamepadButton, state: ButtonState) -> Event<InputEvent> {
+        Event::new(InputEvent::Gamepad(GamepadEvent::Button {
+            id: GamepadId(0),
+            button,
+            state
+        }))
+    }
+
+    fn event_state(event: &Event<InputEvent>) -> ButtonState {
+        match event.kind() {
+            InputEvent::Keyboard(e) => e.state,
+            InputEvent::Gamepad(GamepadEvent::Button { state, .. }) => *state,
+            _ => panic!("event hasn't a button state")
+        }
+    }
+
+    #[test]
+    fn key_tap_at_same_frame_arrives_as_press_then_release() {
+        let mut input = Input::new();
+
+        let mut press = key_event(KeyCode::A, ButtonState::Pressed);
+        input.handle(&mut press);
+        let mut release = key_event(KeyCode::A, ButtonState::Released);
+        input.handle(&mut release);
+
+        assert_eq!(ButtonState::Pressed, event_state(&press));
+        assert_eq!(ButtonState::Released, event_state(&release));
+        assert!(input.key(KeyCode::A).unwrap().is_pressed());
+
+        input.update(Duration::default());
+        assert!(input.key(KeyCode::A).unwrap().is_released());
+
+        input.update(Duration::default());
+        assert!(input.key(KeyCode::A).unwrap().is_up());
+    }
+
+    #[test]
+    fn gamepad_button_tap_at_same_frame_arrives_as_press_then_release() {
+        let mut input = Input::new();
+
+        let mut press = gamepad_button_event(GamepadButton::South, ButtonState::Pressed);
+        input.handle(&mut press);
+        let mut release = gamepad_button_event(GamepadButton::South, ButtonState::Released);
+        input.handle(&mut release);
+
+        assert_eq!(ButtonState::Pressed, event_state(&press));
+        assert_eq!(ButtonState::Released, event_state(&release));
+
+        let button = input.gamepad(GamepadId(0)).unwrap().button(GamepadButton::South);
+        assert!(button.is_pressed());
+    }
+
+    #[test]
+    fn repeated_key_press_arrives_as_down() {
+        let mut input = Input::new();
+
+        input.handle(&mut key_event(KeyCode::A, ButtonState::Pressed));
+        input.update(Duration::default());
+
+        let mut repeat = key_event(KeyCode::A, ButtonState::Pressed);
+        input.handle(&mut repeat);
+        assert_eq!(ButtonState::Down, event_state(&repeat));
+    }
+
+    #[test]
+    fn plain_key_binding_ignores_unbound_modifiers() {
+        let mut input = Input::new();
+
+        input.input_map_mut().bind_action("sprint", InputBinding::key(KeyCode::LeftShift));
+        input.input_map_mut().bind_action("jump", InputBinding::key(KeyCode::Space));
+        input.input_map_mut().bind_axis("move_x", AxisMapping::with(InputBinding::key(KeyCode::A), InputBinding::key(KeyCode::D)));
+
+        input.handle(&mut key_event(KeyCode::LeftShift, ButtonState::Pressed));
+        input.handle(&mut key_event(KeyCode::Space, ButtonState::Pressed));
+        input.handle(&mut key_event(KeyCode::D, ButtonState::Pressed));
+
+        assert!(input.is_action_held("sprint"));
+        assert!(input.is_action_held("jump"));
+        assert_eq!(1.0, input.axis("move_x"));
+    }
+
+    #[test]
+    fn chord_takes_over_plain_binding_of_its_key() {
+        let mut input = Input::new();
+        let ctrl = KeyModifiers {
+            ctrl: true,
+            ..KeyModifiers::default()
+        };
+
+        input.input_map_mut().bind_action("move_down", InputBinding::key(KeyCode::S));
+        input.input_map_mut().bind_action("save", InputBinding::chord(KeyCode::S, ctrl));
+
+        input.handle(&mut key_event(KeyCode::LeftControl, ButtonState::Pressed));
+        input.handle(&mut key_event(KeyCode::LeftShift, ButtonState::Pressed));
+        input.handle(&mut key_event(KeyCode::S, ButtonState::Pressed));
+        assert!(input.is_action_pressed("save"));
+        assert!(!input.is_action_held("move_down"));
+
+        input.update(Duration::default());
+        input.handle(&mut key_event(KeyCode::LeftControl, ButtonState::Released));
+        assert!(input.is_action_held("move_down"));
+        assert!(input.is_action_released("save"));
+    }
+
+    #[test]
+    fn modifier_key_binding_is_held_alone() {
+        let mut input = Input::new();
+
+        input.input_map_mut().bind_action("run", InputBinding::key(KeyCode::LeftShift));
+        input.handle(&mut key_event(KeyCode::LeftShift, ButtonState::Pressed));
+
+        assert!(input.is_action_held("run"));
+        assert_eq!(Some(&InputBinding::key(KeyCode::LeftShift)), input.last_binding());
+    }
+}
+
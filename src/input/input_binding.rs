@@ -0,0 +1,55 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+use crate::input::{
+    gamepad::{
+        GamepadAxis,
+        GamepadButton
+    },
+    AxisDirection,
+    KeyCode,
+    KeyModifiers,
+    MouseButton,
+    WheelDirection
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key {
+        key: KeyCode,
+        #[serde(default)]
+        modifiers: KeyModifiers
+    },
+    MouseButton(MouseButton),
+    MouseWheel(WheelDirection),
+    Axis {
+        axis: u32,
+        direction: AxisDirection
+    },
+
+    // any connected gamepad
+    GamepadButton(GamepadButton),
+    GamepadAxis {
+        axis: GamepadAxis,
+        direction: AxisDirection
+    }
+}
+
+impl InputBinding {
+    pub fn key(key: KeyCode) -> InputBinding {
+        InputBinding::Key {
+            key,
+            modifiers: KeyModifiers::default()
+        }
+    }
+
+    /// Key which only counts while exactly `modifiers` are held too.
+    pub fn chord(key: KeyCode, modifiers: KeyModifiers) -> InputBinding {
+        InputBinding::Key {
+            key,
+            modifiers
+        }
+    }
+}
@@ -1,5 +1,12 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
 use crate::{
     input::{
+        gamepad::GamepadEvent,
+        text::ImeEvent,
         KeyboardEvent,
         KeyModifiers,
         MouseButtonEvent,
@@ -10,9 +17,11 @@ use crate::{
     math::Vector2,
 };
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum InputEvent {
     Keyboard(KeyboardEvent),
     ReceivedChar(char),
+    Ime(ImeEvent),
     MouseButton(MouseButtonEvent),
     MouseWheel {
         delta: MouseScrollDelta,
@@ -33,5 +42,6 @@ pub enum InputEvent {
     AxisMotion {
         axis: u32,
         value: f64
-    }
+    },
+    Gamepad(GamepadEvent)
 }
@@ -0,0 +1,46 @@
+use std::{
+    fs,
+    path::Path
+};
+
+use ron::ser::PrettyConfig;
+use serde::{
+    de::DeserializeOwned,
+    Serialize
+};
+
+use crate::{
+    core::ecs::serialization::SceneFormat,
+    input::InputFileError
+};
+
+pub(super) fn parse<T: DeserializeOwned>(source: &str, format: SceneFormat) -> Result<T, InputFileError> {
+    match format {
+        SceneFormat::Ron => ron::de::from_str(source).map_err(InputFileError::Ron),
+        SceneFormat::Json => serde_json::from_str(source).map_err(InputFileError::Json)
+    }
+}
+
+pub(super) fn write<T: Serialize>(value: &T, format: SceneFormat) -> Result<String, InputFileError> {
+    match format {
+        SceneFormat::Ron => ron::ser::to_string_pretty(value, PrettyConfig::default()).map_err(InputFileError::Ron),
+        SceneFormat::Json => serde_json::to_string_pretty(value).map_err(InputFileError::Json)
+    }
+}
+
+pub(super) fn read_file<T: DeserializeOwned>(filepath: &Path) -> Result<T, InputFileError> {
+    let format = format_of(filepath)?;
+    let source = fs::read_to_string(filepath).map_err(InputFileError::Io)?;
+
+    parse(&source, format)
+}
+
+pub(super) fn write_file<T: Serialize>(filepath: &Path, value: &T) -> Result<(), InputFileError> {
+    let text = write(value, format_of(filepath)?)?;
+
+    fs::write(filepath, text).map_err(InputFileError::Io)
+}
+
+fn format_of(filepath: &Path) -> Result<SceneFormat, InputFileError> {
+    SceneFormat::from_path(filepath).ok_or_else(|| InputFileError::UnknownFormat(filepath.to_path_buf()))
+}
@@ -0,0 +1,50 @@
+use std::{
+    error::{
+        Error
+    },
+    fmt::{
+        self,
+        Display,
+        Formatter
+    },
+    io,
+    path::PathBuf
+};
+
+#[derive(Debug)]
+pub enum InputFileError {
+    Io(io::Error),
+    Ron(ron::Error),
+    Json(serde_json::Error),
+    UnknownFormat(PathBuf)
+}
+
+impl Display for InputFileError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputFileError::Io(err) => {
+                write!(fmt, "Failed to access input file: {}", err)
+            },
+            InputFileError::Ron(err) => {
+                write!(fmt, "Invalid RON input file: {}", err)
+            },
+            InputFileError::Json(err) => {
+                write!(fmt, "Invalid JSON input file: {}", err)
+            },
+            InputFileError::UnknownFormat(path) => {
+                write!(fmt, "Can't infer input file format from file '{}'.", path.display())
+            }
+        }
+    }
+}
+
+impl Error for InputFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputFileError::Io(err) => Some(err),
+            InputFileError::Ron(err) => Some(err),
+            InputFileError::Json(err) => Some(err),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashSet
+    },
+    path::Path
+};
+
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+use crate::{
+    core::ecs::serialization::SceneFormat,
+    input::{
+        input_file,
+        AxisMapping,
+        InputBinding,
+        InputFileError
+    }
+};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputMap {
+    actions: BTreeMap<String, Vec<InputBinding>>,
+    axes: BTreeMap<String, AxisMapping>,
+
+    // actions held at previous frame, to detect their edges
+    #[serde(skip)]
+    held_actions: HashSet<String>
+}
+
+impl InputMap {
+    pub fn new() -> InputMap {
+        InputMap::default()
+    }
+
+    pub fn from_text(source: &str, format: SceneFormat) -> Result<InputMap, InputFileError> {
+        input_file::parse(source, format)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(filepath: P) -> Result<InputMap, InputFileError> {
+        input_file::read_file(filepath.as_ref())
+    }
+
+    pub fn to_text(&self, format: SceneFormat) -> Result<String, InputFileError> {
+        input_file::write(self, format)
+    }
+
+    pub fn save_file<P: AsRef<Path>>(&self, filepath: P) -> Result<(), InputFileError> {
+        input_file::write_file(filepath.as_ref(), self)
+    }
+
+    // actions
+
+    pub fn bind_action<T: Into<String>>(&mut self, name: T, binding: InputBinding) {
+        let bindings = self.actions
+                           .entry(name.into())
+                           .or_default();
+
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    pub fn unbind_action(&mut self, name: &str, binding: &InputBinding) -> bool {
+        match self.actions.get_mut(name) {
+            Some(bindings) => {
+                let len = bindings.len();
+                bindings.retain(|b| b != binding);
+                bindings.len() != len
+            },
+            None => false
+        }
+    }
+
+    /// Replaces `previous` binding by `binding`, keeping it's position.
+    pub fn rebind_action(&mut self, name: &str, previous: &InputBinding, binding: InputBinding) -> bool {
+        let bindings = match self.actions.get_mut(name) {
+            Some(bindings) => bindings,
+            None => return false
+        };
+
+        if bindings.contains(&binding) {
+            bindings.retain(|b| b != previous);
+            return true;
+        }
+
+        match bindings.iter_mut().find(|b| *b == previous) {
+            Some(b) => {
+                *b = binding;
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn set_action_bindings<T: Into<String>>(&mut self, name: T, bindings: Vec<InputBinding>) {
+        self.actions.insert(name.into(), bindings);
+    }
+
+    pub fn action_bindings(&self, name: &str) -> &[InputBinding] {
+        match self.actions.get(name) {
+            Some(bindings) => bindings,
+            None => &[]
+        }
+    }
+
+    pub fn remove_action(&mut self, name: &str) -> Option<Vec<InputBinding>> {
+        self.held_actions.remove(name);
+        self.actions.remove(name)
+    }
+
+    pub fn actions(&self) -> impl Iterator<Item = (&String, &Vec<InputBinding>)> {
+        self.actions.iter()
+    }
+
+    // axes
+
+    pub fn bind_axis<T: Into<String>>(&mut self, name: T, mapping: AxisMapping) {
+        self.axes.insert(name.into(), mapping);
+    }
+
+    pub fn axis_mapping(&self, name: &str) -> Option<&AxisMapping> {
+        self.axes.get(name)
+    }
+
+    pub fn axis_mapping_mut(&mut self, name: &str) -> Option<&mut AxisMapping> {
+        self.axes.get_mut(name)
+    }
+
+    pub fn remove_axis(&mut self, name: &str) -> Option<AxisMapping> {
+        self.axes.remove(name)
+    }
+
+    pub fn axes(&self) -> impl Iterator<Item = (&String, &AxisMapping)> {
+        self.axes.iter()
+    }
+
+    // every binding, of both actions and axes
+    pub(super) fn bindings(&self) -> impl Iterator<Item = &InputBinding> {
+        let axes = self.axes
+                       .values()
+                       .flat_map(|mapping| mapping.positive.iter().chain(mapping.negative.iter()));
+
+        self.actions
+            .values()
+            .flatten()
+            .chain(axes)
+    }
+
+    pub(super) fn was_held(&self, name: &str) -> bool {
+        self.held_actions.contains(name)
+    }
+
+    pub(super) fn set_held_actions(&mut self, held_actions: HashSet<String>) {
+        self.held_actions = held_actions;
+    }
+}
+
+#[cfg(all(test, feature = "no-backend"))]
+mod tests {
+    use std::{
+        any::Any,
+        cell::{
+            Ref,
+            RefCell
+        },
+        rc::Rc
+    };
+
+    use super::*;
+    use crate::{
+        core::{
+            ecs::{
+                components::TransformComponent,
+                containers::{
+                    QueryDataContainer,
+                    Read
+                },
+                schedule::Stage,
+                Realm,
+                System,
+                SystemContext
+            },
+            Game,
+            GameState
+        },
+        input::{
+            gamepad::{
+                GamepadAxis,
+                GamepadButton,
+                GamepadEvent,
+                GamepadId
+            },
+            AxisDirection,
+            ButtonState,
+            InputEvent,
+            KeyCode,
+            KeyModifiers,
+            KeyboardEvent
+        }
+    };
+
+    // action state, axis value and last binding as systems see them
+    type MapFrame = (ButtonState, f32, Option<InputBinding>);
+
+    // one entry per frame
+    struct SnapshotMap {
+        frames: Rc<RefCell<Vec<MapFrame>>>
+    }
+
+    impl System for SnapshotMap {
+        type DataType = QueryDataContainer<Read<TransformComponent>>;
+
+        fn setup(&mut self, _game_state: &mut Ref<GameState>) {
+        }
+
+        fn run(&mut self, _transforms: &mut Self::DataType, context: &mut SystemContext) {
+            let input = context.game_state().input();
+            self.frames.borrow_mut().push((input.action_state("jump"), input.axis("horizontal"), input.last_binding().cloned()));
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    fn key(key: KeyCode, state: ButtonState) -> InputEvent {
+        InputEvent::Keyboard(KeyboardEvent::new(0, state, Some(key), KeyModifiers::default()))
+    }
+
+    fn stick(value: f32) -> InputEvent {
+        InputEvent::Gamepad(GamepadEvent::Axis {
+            id: GamepadId(0),
+            axis: GamepadAxis::LeftStickX,
+            value
+        })
+    }
+
+    fn game_with_map() -> Game {
+        let game = Game::new().unwrap();
+        let mut map = InputMap::new();
+
+        map.bind_action("jump", InputBinding::key(KeyCode::Space));
+        map.bind_action("jump", InputBinding::GamepadButton(GamepadButton::South));
+        map.bind_axis("horizontal", AxisMapping::with(InputBinding::key(KeyCode::A), InputBinding::key(KeyCode::D))
+                                                .with_positive(InputBinding::GamepadAxis {
+                                                    axis: GamepadAxis::LeftStickX,
+                                                    direction: AxisDirection::Positive
+                                                })
+                                                .with_negative(InputBinding::GamepadAxis {
+                                                    axis: GamepadAxis::LeftStickX,
+                                                    direction: AxisDirection::Negative
+                                                })
+                                                .with_dead_zone(0.5));
+
+        game.game_state().input_mut().set_input_map(map);
+        game
+    }
+
+    fn run_snapshotting(game: &mut Game, frames: u64) -> Vec<MapFrame> {
+        let snapshots = Rc::new(RefCell::new(Vec::new()));
+        let mut realm = Realm::new();
+        realm.register_system("snapshot", SnapshotMap { frames: snapshots.clone() })
+             .in_stage(Stage::Update);
+
+        game.run_frames(&mut realm, frames).unwrap();
+
+        let taken = snapshots.borrow().clone();
+        taken
+    }
+
+    #[test]
+    fn action_follows_any_of_its_bindings() {
+        let mut game = game_with_map();
+        let injector = game.injector();
+
+        injector.push_at(1, key(KeyCode::Space, ButtonState::Pressed));
+        injector.push_at(3, InputEvent::Gamepad(GamepadEvent::Button {
+            id: GamepadId(0),
+            button: GamepadButton::South,
+            state: ButtonState::Pressed
+        }));
+        injector.push_at(4, key(KeyCode::Space, ButtonState::Released));
+        injector.push_at(5, InputEvent::Gamepad(GamepadEvent::Button {
+            id: GamepadId(0),
+            button: GamepadButton::South,
+            state: ButtonState::Released
+        }));
+
+        let states: Vec<ButtonState> = run_snapshotting(&mut game, 7).into_iter()
+                                                                     .map(|(state, _, _)| state)
+                                                                     .collect();
+
+        // releasing one binding keeps action down while another is still held
+        assert_eq!(vec![
+            ButtonState::Up,
+            ButtonState::Pressed,
+            ButtonState::Down,
+            ButtonState::Down,
+            ButtonState::Down,
+            ButtonState::Released,
+            ButtonState::Up
+        ], states);
+    }
+
+    #[test]
+    fn axis_sums_keys_and_gamepad_outside_dead_zone() {
+        let mut game = game_with_map();
+        let injector = game.injector();
+
+        injector.push_at(1, key(KeyCode::D, ButtonState::Pressed));
+        injector.push_at(2, key(KeyCode::A, ButtonState::Pressed));
+        injector.push_at(3, key(KeyCode::D, ButtonState::Released));
+        injector.push_at(4, key(KeyCode::A, ButtonState::Released));
+        injector.push_at(4, stick(0.25));
+        injector.push_at(5, stick(0.75));
+        injector.push_at(6, stick(-1.0));
+
+        let values: Vec<f32> = run_snapshotting(&mut game, 7).into_iter()
+                                                             .map(|(_, value, _)| value)
+                                                             .collect();
+
+        assert_eq!(vec![0.0, 1.0, 0.0, -1.0, 0.0, 0.5, -1.0], values);
+    }
+
+    #[test]
+    fn rebound_action_follows_new_binding_only() {
+        let mut game = game_with_map();
+
+        // player presses the key they want for jumping
+        game.injector().push_at(0, key(KeyCode::J, ButtonState::Pressed));
+        game.injector().push_at(1, key(KeyCode::J, ButtonState::Released));
+
+        let captured = run_snapshotting(&mut game, 2).remove(0).2.unwrap();
+        assert_eq!(InputBinding::key(KeyCode::J), captured);
+
+        let rebound = game.game_state()
+                          .input_mut()
+                          .input_map_mut()
+                          .rebind_action("jump", &InputBinding::key(KeyCode::Space), captured);
+        assert!(rebound);
+
+        let frame = game.injector().frame();
+        game.injector().push_at(frame + 1, key(KeyCode::Space, ButtonState::Pressed));
+        game.injector().push_at(frame + 2, key(KeyCode::J, ButtonState::Pressed));
+
+        let states: Vec<ButtonState> = run_snapshotting(&mut game, 4).into_iter()
+                                                                     .map(|(state, _, _)| state)
+                                                                     .collect();
+
+        assert_eq!(vec![ButtonState::Up, ButtonState::Up, ButtonState::Pressed, ButtonState::Down], states);
+        assert_eq!(&[InputBinding::key(KeyCode::J), InputBinding::GamepadButton(GamepadButton::South)][..],
+                   game.game_state().input().input_map().action_bindings("jump"));
+    }
+}
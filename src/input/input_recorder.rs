@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::input::{
+    InputEvent,
+    InputRecording,
+    RecordedFrame
+};
+
+pub struct InputRecorder {
+    recording: InputRecording,
+    pending_events: Vec<InputEvent>
+}
+
+impl Default for InputRecorder {
+    fn default() -> Self {
+        InputRecorder::new()
+    }
+}
+
+impl InputRecorder {
+    pub fn new() -> InputRecorder {
+        InputRecorder {
+            recording: InputRecording::new(),
+            pending_events: Vec::new()
+        }
+    }
+
+    pub fn record(&mut self, event: InputEvent) {
+        self.pending_events.push(event);
+    }
+
+    /// Closes current frame, every event recorded until now belongs to it.
+    pub fn end_frame(&mut self, delta: Duration) {
+        self.recording.frames.push(RecordedFrame {
+            delta,
+            events: self.pending_events.drain(..).collect()
+        });
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.recording.len()
+    }
+
+    pub fn finish(mut self) -> InputRecording {
+        // events which arrived after last frame would be lost otherwise
+        if !self.pending_events.is_empty() {
+            self.end_frame(Duration::default());
+        }
+
+        self.recording
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    path::Path,
+    time::Duration
+};
+
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+use crate::{
+    core::ecs::serialization::SceneFormat,
+    input::{
+        input_file,
+        InputEvent,
+        InputFileError
+    }
+};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RecordedFrame {
+    pub delta: Duration,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<InputEvent>
+}
+
+/// Input events received at each frame, along with it's duration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InputRecording {
+    pub frames: Vec<RecordedFrame>
+}
+
+impl InputRecording {
+    pub fn new() -> InputRecording {
+        InputRecording::default()
+    }
+
+    pub fn from_text(source: &str, format: SceneFormat) -> Result<InputRecording, InputFileError> {
+        input_file::parse(source, format)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(filepath: P) -> Result<InputRecording, InputFileError> {
+        input_file::read_file(filepath.as_ref())
+    }
+
+    pub fn to_text(&self, format: SceneFormat) -> Result<String, InputFileError> {
+        input_file::write(self, format)
+    }
+
+    pub fn save_file<P: AsRef<Path>>(&self, filepath: P) -> Result<(), InputFileError> {
+        input_file::write_file(filepath.as_ref(), self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.frames
+            .iter()
+            .map(|frame| frame.delta)
+            .sum()
+    }
+}
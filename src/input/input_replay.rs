@@ -0,0 +1,49 @@
+use crate::input::{
+    InputRecording,
+    RecordedFrame
+};
+
+pub struct InputReplay {
+    recording: InputRecording,
+    next_frame: usize,
+    close_game_at_end: bool
+}
+
+impl InputReplay {
+    pub fn new(recording: InputRecording) -> InputReplay {
+        InputReplay {
+            recording,
+            next_frame: 0,
+            close_game_at_end: false
+        }
+    }
+
+    /// Closes game after it's last frame, as needed to run replays unattended.
+    pub fn closing_game_at_end(mut self) -> InputReplay {
+        self.close_game_at_end = true;
+        self
+    }
+
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+
+    /// Frames already played.
+    pub fn frame(&self) -> usize {
+        self.next_frame
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_frame >= self.recording.len()
+    }
+
+    pub fn closes_game_at_end(&self) -> bool {
+        self.close_game_at_end
+    }
+
+    pub fn next_frame(&mut self) -> Option<&RecordedFrame> {
+        let frame = self.recording.frames.get(self.next_frame)?;
+        self.next_frame += 1;
+        Some(frame)
+    }
+}
@@ -1,8 +1,39 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
 
-#[derive(Debug)]
+use crate::input::KeyCode;
+
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct KeyModifiers {
     pub ctrl: bool,
     pub shift: bool,
     pub alt: bool,
     pub command: bool
 }
+
+impl KeyModifiers {
+    /// Same modifiers, except the one `key` is itself, a modifier key never modifies it's own binding.
+    pub fn without_key(&self, key: KeyCode) -> KeyModifiers {
+        let mut modifiers = *self;
+
+        match key {
+            KeyCode::LeftControl | KeyCode::RightControl => modifiers.ctrl = false,
+            KeyCode::LeftShift | KeyCode::RightShift => modifiers.shift = false,
+            KeyCode::LeftAlt | KeyCode::RightAlt => modifiers.alt = false,
+            KeyCode::LeftCommand | KeyCode::RightCommand => modifiers.command = false,
+            _ => ()
+        }
+
+        modifiers
+    }
+
+    /// Whether every modifier of `other` is also set here.
+    pub fn contains(&self, other: KeyModifiers) -> bool {
+        (self.ctrl || !other.ctrl)
+          && (self.shift || !other.shift)
+          && (self.alt || !other.alt)
+          && (self.command || !other.command)
+    }
+}
@@ -1,3 +1,8 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
 use super::{
     ButtonState,
     KeyCode,
@@ -6,6 +11,7 @@ use super::{
 
 type ScanCode = u32;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyboardEvent {
     pub scan_code: ScanCode,
     pub state: ButtonState,
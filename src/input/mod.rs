@@ -1,6 +1,11 @@
+#[allow(clippy::module_inception)]
 mod input;
 pub use input::Input;
 
+pub mod gamepad;
+pub mod gestures;
+pub mod text;
+
 mod mouse_button;
 pub use mouse_button::MouseButton;
 
@@ -31,5 +36,44 @@ pub use keyboard_event::KeyboardEvent;
 mod touch_event;
 pub use touch_event::TouchEvent;
 
+mod touch;
+pub use touch::Touch;
+
 mod touch_phase;
 pub use touch_phase::TouchPhase;
+
+mod input_file_error;
+pub use input_file_error::InputFileError;
+
+mod input_file;
+
+// mapping related
+
+mod input_map;
+pub use input_map::InputMap;
+
+mod input_binding;
+pub use input_binding::InputBinding;
+
+mod axis_mapping;
+pub use axis_mapping::AxisMapping;
+
+mod axis_direction;
+pub use axis_direction::AxisDirection;
+
+mod wheel_direction;
+pub use wheel_direction::WheelDirection;
+
+// recording related
+
+mod input_recording;
+pub use input_recording::{
+    InputRecording,
+    RecordedFrame
+};
+
+mod input_recorder;
+pub use input_recorder::InputRecorder;
+
+mod input_replay;
+pub use input_replay::InputReplay;
@@ -1,9 +1,15 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
 use super::{
     ButtonState,
     MouseButton,
     KeyModifiers
 };
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MouseButtonEvent {
     pub state: ButtonState,
     pub button: MouseButton,
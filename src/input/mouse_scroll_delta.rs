@@ -1,8 +1,13 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
 use crate::{
     math::Vector2
 };
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub enum MouseScrollDelta {
     Line {
         horizontal: f32,
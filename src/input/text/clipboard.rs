@@ -0,0 +1,5 @@
+/// Clipboard used by text editing, so platform clipboards can be plugged in.
+pub trait Clipboard {
+    fn text(&mut self) -> Option<String>;
+    fn set_text(&mut self, text: &str);
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CursorMotion {
+    Left,
+    Right,
+    WordLeft,
+    WordRight,
+    Home,
+    End
+}
@@ -0,0 +1,16 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum ImeEvent {
+    /// Composition changed, `cursor` is a byte range into `text`.
+    Preedit {
+        text: String,
+        cursor: Option<(usize, usize)>
+    },
+
+    /// Composition finished and `0` should be inserted.
+    Commit(String)
+}
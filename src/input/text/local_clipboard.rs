@@ -0,0 +1,23 @@
+use crate::input::text::Clipboard;
+
+/// Keeps copied text in memory, it's only shared inside the game.
+#[derive(Debug, Default, Clone)]
+pub struct LocalClipboard {
+    text: Option<String>
+}
+
+impl LocalClipboard {
+    pub fn new() -> LocalClipboard {
+        LocalClipboard::default()
+    }
+}
+
+impl Clipboard for LocalClipboard {
+    fn text(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.text = Some(text.to_owned());
+    }
+}
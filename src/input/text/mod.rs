@@ -0,0 +1,25 @@
+mod text_input;
+pub use text_input::TextInput;
+
+mod text_field_id;
+pub use text_field_id::TextFieldId;
+
+mod text_buffer;
+pub use text_buffer::TextBuffer;
+
+mod text_edit;
+pub use text_edit::TextEdit;
+
+mod cursor_motion;
+pub use cursor_motion::CursorMotion;
+
+mod ime_event;
+pub use ime_event::ImeEvent;
+
+// clipboard related
+
+mod clipboard;
+pub use clipboard::Clipboard;
+
+mod local_clipboard;
+pub use local_clipboard::LocalClipboard;
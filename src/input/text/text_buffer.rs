@@ -0,0 +1,488 @@
+use std::ops::Range;
+
+use crate::input::text::{
+    Clipboard,
+    CursorMotion,
+    TextEdit
+};
+
+/// UTF-8 text with a cursor and an optional selection, positions are byte offsets at char boundaries.
+#[derive(Debug, Default, Clone)]
+pub struct TextBuffer {
+    text: String,
+    cursor: usize,
+
+    // selection goes from anchor to cursor
+    anchor: Option<usize>,
+
+    max_chars: Option<usize>,
+    preedit: String,
+    preedit_cursor: Option<(usize, usize)>
+}
+
+impl TextBuffer {
+    pub fn new() -> TextBuffer {
+        TextBuffer::default()
+    }
+
+    pub fn with_text<T: Into<String>>(text: T) -> TextBuffer {
+        let mut buffer = TextBuffer::new();
+        buffer.set_text(text);
+        buffer
+    }
+
+    pub fn with_max_chars(mut self, max_chars: usize) -> TextBuffer {
+        self.max_chars = Some(max_chars);
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces all text and moves cursor to it's end.
+    pub fn set_text<T: Into<String>>(&mut self, text: T) {
+        self.text = text.into();
+
+        if let Some(max_chars) = self.max_chars {
+            if let Some((index, _)) = self.text.char_indices().nth(max_chars) {
+                self.text.truncate(index);
+            }
+        }
+
+        self.cursor = self.text.len();
+        self.anchor = None;
+    }
+
+    pub fn clear(&mut self) {
+        self.set_text(String::new());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn max_chars(&self) -> Option<usize> {
+        self.max_chars
+    }
+
+    pub fn set_max_chars(&mut self, max_chars: Option<usize>) {
+        self.max_chars = max_chars;
+        let text = std::mem::take(&mut self.text);
+        self.set_text(text);
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves cursor to `position`, or the closest char boundary before it, dropping selection.
+    pub fn set_cursor(&mut self, position: usize) {
+        self.cursor = self.floor_boundary(position);
+        self.anchor = None;
+    }
+
+    pub fn selection(&self) -> Option<Range<usize>> {
+        match self.anchor {
+            Some(anchor) if anchor < self.cursor => Some(anchor..self.cursor),
+            Some(anchor) if anchor > self.cursor => Some(self.cursor..anchor),
+            _ => None
+        }
+    }
+
+    pub fn selected_text(&self) -> &str {
+        match self.selection() {
+            Some(range) => &self.text[range],
+            None => ""
+        }
+    }
+
+    pub fn select(&mut self, range: Range<usize>) {
+        self.anchor = Some(self.floor_boundary(range.start));
+        self.cursor = self.floor_boundary(range.end);
+    }
+
+    pub fn select_all(&mut self) {
+        self.select(0..self.text.len());
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Text being composed by an IME, it should be displayed at cursor but isn't part of text yet.
+    pub fn preedit(&self) -> &str {
+        &self.preedit
+    }
+
+    /// Byte range, into preedit text, which IME wants highlighted.
+    pub fn preedit_cursor(&self) -> Option<(usize, usize)> {
+        self.preedit_cursor
+    }
+
+    /// Inserts at cursor replacing selection, control characters are dropped and max chars is respected.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let removed = self.delete_selection();
+        let available = match self.max_chars {
+            Some(max_chars) => max_chars.saturating_sub(self.char_count()),
+            None => usize::MAX
+        };
+
+        let text: String = text.chars()
+                               .filter(|c| !c.is_control())
+                               .take(available)
+                               .collect();
+
+        self.text.insert_str(self.cursor, &text);
+        self.cursor += text.len();
+
+        removed || !text.is_empty()
+    }
+
+    pub fn backspace(&mut self, word: bool) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let start = if word {
+            self.word_start(self.cursor)
+        } else {
+            self.previous_boundary(self.cursor)
+        };
+
+        self.remove(start..self.cursor)
+    }
+
+    pub fn delete(&mut self, word: bool) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let end = if word {
+            self.word_end(self.cursor)
+        } else {
+            self.next_boundary(self.cursor)
+        };
+
+        self.remove(self.cursor..end)
+    }
+
+    /// Moves cursor, extending selection from where it was when `select` is set.
+    pub fn move_cursor(&mut self, motion: CursorMotion, select: bool) {
+        let selection = self.selection();
+
+        let position = match (motion, &selection) {
+            // collapsing a selection stops at it's side
+            (CursorMotion::Left, Some(range)) if !select => range.start,
+            (CursorMotion::Right, Some(range)) if !select => range.end,
+            (CursorMotion::Left, _) => self.previous_boundary(self.cursor),
+            (CursorMotion::Right, _) => self.next_boundary(self.cursor),
+            (CursorMotion::WordLeft, _) => self.word_start(self.cursor),
+            (CursorMotion::WordRight, _) => self.word_end(self.cursor),
+            (CursorMotion::Home, _) => 0,
+            (CursorMotion::End, _) => self.text.len()
+        };
+
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+
+        self.cursor = position;
+    }
+
+    /// Applies an edit, returns if text has changed.
+    pub fn apply(&mut self, edit: &TextEdit, clipboard: &mut dyn Clipboard) -> bool {
+        match edit {
+            TextEdit::Insert(text) => {
+                self.clear_preedit();
+                self.insert(text)
+            },
+            TextEdit::Backspace { word } => self.backspace(*word),
+            TextEdit::Delete { word } => self.delete(*word),
+            TextEdit::Move { motion, select } => {
+                self.move_cursor(*motion, *select);
+                false
+            },
+            TextEdit::SelectAll => {
+                self.select_all();
+                false
+            },
+            TextEdit::Copy => {
+                if self.selection().is_some() {
+                    clipboard.set_text(self.selected_text());
+                }
+
+                false
+            },
+            TextEdit::Cut => {
+                if self.selection().is_some() {
+                    clipboard.set_text(self.selected_text());
+                }
+
+                self.delete_selection()
+            },
+            TextEdit::Paste => {
+                match clipboard.text() {
+                    Some(text) => self.insert(&text),
+                    None => false
+                }
+            },
+            TextEdit::Preedit { text, cursor } => {
+                self.preedit = text.clone();
+                self.preedit_cursor = *cursor;
+                false
+            }
+        }
+    }
+
+    fn clear_preedit(&mut self) {
+        self.preedit.clear();
+        self.preedit_cursor = None;
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some(range) => self.remove(range),
+            None => {
+                self.anchor = None;
+                false
+            }
+        }
+    }
+
+    fn remove(&mut self, range: Range<usize>) -> bool {
+        self.anchor = None;
+
+        if range.is_empty() {
+            return false;
+        }
+
+        self.cursor = range.start;
+        self.text.replace_range(range, "");
+        true
+    }
+
+    fn floor_boundary(&self, position: usize) -> usize {
+        let mut position = position.min(self.text.len());
+
+        while !self.text.is_char_boundary(position) {
+            position -= 1;
+        }
+
+        position
+    }
+
+    fn previous_boundary(&self, position: usize) -> usize {
+        self.text[..position]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    fn next_boundary(&self, position: usize) -> usize {
+        self.text[position..]
+            .chars()
+            .next()
+            .map_or(position, |c| position + c.len_utf8())
+    }
+
+    // skips separators and then the word before position
+    fn word_start(&self, position: usize) -> usize {
+        let mut chars = self.text[..position].char_indices().rev().peekable();
+        let mut start = position;
+
+        while let Some((index, _)) = chars.next_if(|(_, c)| !is_word_char(*c)) {
+            start = index;
+        }
+
+        while let Some((index, _)) = chars.next_if(|(_, c)| is_word_char(*c)) {
+            start = index;
+        }
+
+        start
+    }
+
+    // skips separators and then the word after position
+    fn word_end(&self, position: usize) -> usize {
+        let mut chars = self.text[position..].char_indices().peekable();
+        let mut end = position;
+
+        while let Some((index, c)) = chars.next_if(|(_, c)| !is_word_char(*c)) {
+            end = position + index + c.len_utf8();
+        }
+
+        while let Some((index, c)) = chars.next_if(|(_, c)| is_word_char(*c)) {
+            end = position + index + c.len_utf8();
+        }
+
+        end
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::text::LocalClipboard;
+
+    fn motion(buffer: &mut TextBuffer, motion: CursorMotion) -> usize {
+        buffer.move_cursor(motion, false);
+        buffer.cursor()
+    }
+
+    #[test]
+    fn backspace_and_delete_remove_whole_multibyte_chars() {
+        // one, two, three and four bytes long
+        let mut buffer = TextBuffer::with_text("aé日😀b");
+        assert_eq!(11, buffer.cursor());
+
+        assert!(buffer.backspace(false));
+        assert!(buffer.backspace(false));
+        assert_eq!("aé日", buffer.text());
+        assert_eq!(6, buffer.cursor());
+
+        // a position inside a char falls back to it's start
+        buffer.set_cursor(2);
+        assert_eq!(1, buffer.cursor());
+        assert!(buffer.delete(false));
+        assert_eq!("a日", buffer.text());
+
+        assert_eq!(4, motion(&mut buffer, CursorMotion::End));
+        assert!(!buffer.delete(false));
+        assert_eq!(1, motion(&mut buffer, CursorMotion::Left));
+        assert!(buffer.backspace(false));
+        assert!(!buffer.backspace(false));
+        assert_eq!("日", buffer.text());
+    }
+
+    #[test]
+    fn word_motions_skip_separators_then_a_word() {
+        let mut buffer = TextBuffer::with_text("hello, big_world  42");
+
+        assert_eq!(18, motion(&mut buffer, CursorMotion::WordLeft));
+        assert_eq!(7, motion(&mut buffer, CursorMotion::WordLeft));
+        assert_eq!(0, motion(&mut buffer, CursorMotion::WordLeft));
+        assert_eq!(0, motion(&mut buffer, CursorMotion::WordLeft));
+        assert_eq!(5, motion(&mut buffer, CursorMotion::WordRight));
+        assert_eq!(16, motion(&mut buffer, CursorMotion::WordRight));
+
+        assert!(buffer.delete(true));
+        assert_eq!("hello, big_world", buffer.text());
+        assert!(buffer.backspace(true));
+        assert_eq!("hello, ", buffer.text());
+
+        buffer.move_cursor(CursorMotion::WordLeft, true);
+        assert_eq!("hello, ", buffer.selected_text());
+    }
+
+    #[test]
+    fn insert_replaces_selection() {
+        let mut buffer = TextBuffer::with_text("hello world");
+
+        buffer.select(6..11);
+        assert!(buffer.insert("there"));
+        assert_eq!("hello there", buffer.text());
+        assert_eq!(None, buffer.selection());
+
+        // selecting backwards from cursor, then typing over it
+        buffer.move_cursor(CursorMotion::WordLeft, true);
+        buffer.move_cursor(CursorMotion::Left, true);
+        assert_eq!(Some(5..11), buffer.selection());
+        assert!(buffer.insert("!"));
+        assert_eq!("hello!", buffer.text());
+
+        // collapsing a selection stops at it's start without moving further
+        buffer.select_all();
+        assert_eq!(0, motion(&mut buffer, CursorMotion::Left));
+        assert_eq!("hello!", buffer.text());
+    }
+
+    #[test]
+    fn cut_copy_and_paste_go_through_clipboard() {
+        let mut clipboard = LocalClipboard::new();
+        let mut buffer = TextBuffer::with_text("copy me");
+
+        // nothing selected, nothing copied
+        assert!(!buffer.apply(&TextEdit::Copy, &mut clipboard));
+        assert!(!buffer.apply(&TextEdit::Paste, &mut clipboard));
+
+        buffer.select_all();
+        assert!(!buffer.apply(&TextEdit::Copy, &mut clipboard));
+        assert_eq!(Some("copy me".to_owned()), clipboard.text());
+        assert_eq!("copy me", buffer.text());
+
+        buffer.select(0..4);
+        assert!(buffer.apply(&TextEdit::Cut, &mut clipboard));
+        assert_eq!(" me", buffer.text());
+        assert_eq!(Some("copy".to_owned()), clipboard.text());
+
+        buffer.move_cursor(CursorMotion::End, false);
+        assert!(buffer.apply(&TextEdit::Paste, &mut clipboard));
+        assert_eq!(" mecopy", buffer.text());
+
+        buffer.select_all();
+        assert!(buffer.apply(&TextEdit::Paste, &mut clipboard));
+        assert_eq!("copy", buffer.text());
+    }
+
+    #[test]
+    fn max_chars_truncates_by_chars() {
+        let mut buffer = TextBuffer::new().with_max_chars(5);
+
+        assert!(buffer.insert("héllo wörld"));
+        assert_eq!("héllo", buffer.text());
+        assert!(!buffer.insert("x"));
+
+        // a selection frees room for what replaces it
+        buffer.select(0..3);
+        assert!(buffer.insert("xyz"));
+        assert_eq!("xyllo", buffer.text());
+
+        buffer.set_text("日本語テキスト");
+        assert_eq!("日本語テキ", buffer.text());
+
+        buffer.set_max_chars(Some(2));
+        assert_eq!("日本", buffer.text());
+        assert_eq!(6, buffer.cursor());
+    }
+
+    #[test]
+    fn preedit_is_kept_apart_until_committed() {
+        let mut clipboard = LocalClipboard::new();
+        let mut buffer = TextBuffer::with_text("a");
+
+        let preedit = TextEdit::Preedit {
+            text: "にほ".to_owned(),
+            cursor: Some((0, 6))
+        };
+        assert!(!buffer.apply(&preedit, &mut clipboard));
+        assert_eq!("にほ", buffer.preedit());
+        assert_eq!(Some((0, 6)), buffer.preedit_cursor());
+        assert_eq!("a", buffer.text());
+
+        // committed text replaces composition
+        assert!(buffer.apply(&TextEdit::Insert("日本".to_owned()), &mut clipboard));
+        assert_eq!("a日本", buffer.text());
+        assert_eq!("", buffer.preedit());
+        assert_eq!(None, buffer.preedit_cursor());
+
+        // cancelled composition leaves text untouched
+        buffer.apply(&preedit, &mut clipboard);
+        let cancel = TextEdit::Preedit {
+            text: String::new(),
+            cursor: None
+        };
+        assert!(!buffer.apply(&cancel, &mut clipboard));
+        assert_eq!("", buffer.preedit());
+        assert_eq!("a日本", buffer.text());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::input::text::CursorMotion;
+
+/// Editing command produced by input while a text field is focused.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TextEdit {
+    Insert(String),
+    Backspace {
+        word: bool
+    },
+    Delete {
+        word: bool
+    },
+    Move {
+        motion: CursorMotion,
+        select: bool
+    },
+    SelectAll,
+    Copy,
+    Cut,
+    Paste,
+
+    /// Text being composed by an IME, empty when composition ends.
+    Preedit {
+        text: String,
+        cursor: Option<(usize, usize)>
+    }
+}
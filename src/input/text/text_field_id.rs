@@ -0,0 +1,15 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter
+};
+
+/// Identifies a widget which can hold text input focus, it's value is up to the game.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub struct TextFieldId(pub u64);
+
+impl Display for TextFieldId {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "text field #{}", self.0)
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    input::{
+        text::{
+            Clipboard,
+            CursorMotion,
+            ImeEvent,
+            LocalClipboard,
+            TextBuffer,
+            TextEdit,
+            TextFieldId
+        },
+        KeyCode,
+        KeyModifiers
+    },
+    math::Vector2
+};
+
+/// Text input state, edits are only produced while a text field holds focus.
+pub struct TextInput {
+    focus: Option<TextFieldId>,
+    edits: Vec<TextEdit>,
+    composing: bool,
+    ime_position: Option<Vector2<f64>>,
+    clipboard: Box<dyn Clipboard>
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        TextInput::new()
+    }
+}
+
+impl TextInput {
+    pub fn new() -> TextInput {
+        TextInput {
+            focus: None,
+            edits: Vec::new(),
+            composing: false,
+            ime_position: None,
+            clipboard: Box::new(LocalClipboard::new())
+        }
+    }
+
+    /// Gives focus to `id`, taking it from any other text field.
+    pub fn focus(&mut self, id: TextFieldId) {
+        if self.focus != Some(id) {
+            self.focus = Some(id);
+            self.edits.clear();
+            self.composing = false;
+        }
+    }
+
+    /// Drops focus only when it's held by `id`, so a stale widget can't steal it from a newer one.
+    pub fn unfocus(&mut self, id: TextFieldId) -> bool {
+        if self.focus == Some(id) {
+            self.clear_focus();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn clear_focus(&mut self) {
+        self.focus = None;
+        self.edits.clear();
+        self.composing = false;
+        self.ime_position = None;
+    }
+
+    pub fn focused(&self) -> Option<TextFieldId> {
+        self.focus
+    }
+
+    pub fn is_focused(&self, id: TextFieldId) -> bool {
+        self.focus == Some(id)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.focus.is_some()
+    }
+
+    /// Edits received at current frame.
+    pub fn edits(&self) -> &[TextEdit] {
+        &self.edits
+    }
+
+    /// Applies current frame edits to `buffer`, returns if it's text has changed.
+    pub fn apply(&mut self, buffer: &mut TextBuffer) -> bool {
+        let mut changed = false;
+
+        for edit in &self.edits {
+            changed |= buffer.apply(edit, self.clipboard.as_mut());
+        }
+
+        changed
+    }
+
+    /// Where IME candidate window should be placed, in window logical coordinates.
+    pub fn ime_position(&self) -> Option<Vector2<f64>> {
+        self.ime_position
+    }
+
+    pub fn set_ime_position(&mut self, position: Vector2<f64>) {
+        self.ime_position = Some(position);
+    }
+
+    pub fn clipboard(&self) -> &dyn Clipboard {
+        self.clipboard.as_ref()
+    }
+
+    pub fn clipboard_mut(&mut self) -> &mut dyn Clipboard {
+        self.clipboard.as_mut()
+    }
+
+    pub fn set_clipboard<C: 'static + Clipboard>(&mut self, clipboard: C) {
+        self.clipboard = Box::new(clipboard);
+    }
+
+    pub(in crate::input) fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        // IME owns keys while composing
+        if !self.is_enabled() || self.composing {
+            return;
+        }
+
+        let word = modifiers.ctrl || modifiers.alt;
+        let shortcut = modifiers.ctrl || modifiers.command;
+        let select = modifiers.shift;
+
+        let edit = match key {
+            KeyCode::Backspace => TextEdit::Backspace { word },
+            KeyCode::Delete => TextEdit::Delete { word },
+            KeyCode::Left if modifiers.command => TextEdit::Move { motion: CursorMotion::Home, select },
+            KeyCode::Right if modifiers.command => TextEdit::Move { motion: CursorMotion::End, select },
+            KeyCode::Left if word => TextEdit::Move { motion: CursorMotion::WordLeft, select },
+            KeyCode::Right if word => TextEdit::Move { motion: CursorMotion::WordRight, select },
+            KeyCode::Left => TextEdit::Move { motion: CursorMotion::Left, select },
+            KeyCode::Right => TextEdit::Move { motion: CursorMotion::Right, select },
+            KeyCode::Home => TextEdit::Move { motion: CursorMotion::Home, select },
+            KeyCode::End => TextEdit::Move { motion: CursorMotion::End, select },
+            KeyCode::A if shortcut => TextEdit::SelectAll,
+            KeyCode::C if shortcut => TextEdit::Copy,
+            KeyCode::X if shortcut => TextEdit::Cut,
+            KeyCode::V if shortcut => TextEdit::Paste,
+            KeyCode::Copy => TextEdit::Copy,
+            KeyCode::Cut => TextEdit::Cut,
+            KeyCode::Paste => TextEdit::Paste,
+            _ => return
+        };
+
+        self.edits.push(edit);
+    }
+
+    pub(in crate::input) fn handle_char(&mut self, c: char) {
+        if !self.is_enabled() || self.composing || c.is_control() {
+            return;
+        }
+
+        match self.edits.last_mut() {
+            Some(TextEdit::Insert(text)) => text.push(c),
+            _ => self.edits.push(TextEdit::Insert(c.to_string()))
+        }
+    }
+
+    pub(in crate::input) fn handle_ime(&mut self, event: &ImeEvent) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        match event {
+            ImeEvent::Preedit { text, cursor } => {
+                self.composing = !text.is_empty();
+                self.edits.push(TextEdit::Preedit {
+                    text: text.clone(),
+                    cursor: *cursor
+                });
+            },
+            ImeEvent::Commit(text) => {
+                self.composing = false;
+                self.edits.push(TextEdit::Insert(text.clone()));
+            }
+        }
+    }
+
+    pub(in crate::input) fn update(&mut self) {
+        self.edits.clear();
+    }
+}
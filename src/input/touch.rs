@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use crate::{
+    input::TouchPhase,
+    math::Vector2
+};
+
+/// Touch tracked by `Input`, it's kept for one frame after it ended.
+#[derive(Debug, Clone)]
+pub struct Touch {
+    id: u64,
+    phase: TouchPhase,
+    position: Vector2<f64>,
+    start_position: Vector2<f64>,
+    start_time: Duration
+}
+
+impl Touch {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn phase(&self) -> TouchPhase {
+        self.phase
+    }
+
+    pub fn position(&self) -> Vector2<f64> {
+        self.position
+    }
+
+    pub fn start_position(&self) -> Vector2<f64> {
+        self.start_position
+    }
+
+    /// Time, since game start, when finger touched.
+    pub fn start_time(&self) -> Duration {
+        self.start_time
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self.phase, TouchPhase::Started | TouchPhase::Moved)
+    }
+
+    pub(super) fn new(id: u64, position: Vector2<f64>, start_time: Duration) -> Touch {
+        Touch {
+            id,
+            phase: TouchPhase::Started,
+            position,
+            start_position: position,
+            start_time
+        }
+    }
+
+    pub(super) fn set(&mut self, phase: TouchPhase, position: Vector2<f64>) {
+        self.phase = phase;
+        self.position = position;
+    }
+}
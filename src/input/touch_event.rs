@@ -1,8 +1,14 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
 use crate::{
     input::TouchPhase,
     math::Vector2,
 };
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TouchEvent {
     phase: TouchPhase,
     position: Vector2<f64>,
@@ -17,4 +23,16 @@ impl TouchEvent {
             id
         }
     }
+
+    pub fn phase(&self) -> TouchPhase {
+        self.phase
+    }
+
+    pub fn position(&self) -> Vector2<f64> {
+        self.position
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
 }
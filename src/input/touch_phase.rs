@@ -1,5 +1,9 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
 
-#[derive(Debug, PartialEq, Copy, Clone, Hash)]
+#[derive(Debug, PartialEq, Copy, Clone, Hash, Serialize, Deserialize)]
 pub enum TouchPhase {
     Started,
     Moved,
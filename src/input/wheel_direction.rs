@@ -0,0 +1,12 @@
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize)]
+pub enum WheelDirection {
+    Up,
+    Down,
+    Left,
+    Right
+}
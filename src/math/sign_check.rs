@@ -1,4 +1,5 @@
 
+#[allow(clippy::wrong_self_convention)]
 pub trait SignCheck {
     fn is_positive(self) -> bool;
     fn is_negative(self) -> bool;
@@ -143,15 +143,9 @@ impl<T> Size<T> where
     }
 
     pub fn try_with<U: TryInto<T, Error = E>, E>(width: U, height: U) -> Result<Self, E> {
-        let w_component = match width.try_into() {
-            Ok(value) => value,
-            Err(e) => return Err(e)
-        };
-
-        let h_component = match height.try_into() {
-            Ok(value) => value,
-            Err(e) => return Err(e)
-        };
+        let w_component = width.try_into()?;
+
+        let h_component = height.try_into()?;
 
         Ok(Size::with(w_component, h_component))
     }
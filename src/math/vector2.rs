@@ -13,7 +13,12 @@ use std::{
     }
 };
 
-#[derive(Default, Debug, PartialEq, Copy, Clone)]
+use serde::{
+    Deserialize,
+    Serialize
+};
+
+#[derive(Default, Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub struct Vector2<T> where 
   T: Copy + PartialEq
 {
@@ -147,15 +152,9 @@ impl<T> Vector2<T> where
     }
 
     pub fn try_with<U: TryInto<T, Error = E>, E>(x: U, y: U) -> Result<Self, E> {
-        let x_component = match x.try_into() {
-            Ok(value) => value,
-            Err(e) => return Err(e)
-        };
-
-        let y_component = match y.try_into() {
-            Ok(value) => value,
-            Err(e) => return Err(e)
-        };
+        let x_component = x.try_into()?;
+
+        let y_component = y.try_into()?;
 
         Ok(Vector2::with(x_component, y_component))
     }
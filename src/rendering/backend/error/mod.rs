@@ -14,3 +14,10 @@ pub use {
         HalTextureBindingsError as TextureBindingsError
     }
 };
+
+#[cfg(feature = "no-backend")]
+pub use {
+    crate::rendering::backend::no_backend::error::{
+        NoBackendTextureBindingsError as TextureBindingsError
+    }
+};
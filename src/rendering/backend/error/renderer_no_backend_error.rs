@@ -1,11 +1,23 @@
+use std::{
+    error::{
+        Error
+    },
+    fmt::{
+        self,
+        Display,
+        Formatter
+    }
+};
 
+/// Null backend never fails, this is here only to fill `RendererBackendError`.
 #[derive(Debug)]
 pub enum RendererNoBackendError {
 }
 
 impl Display for RendererNoBackendError {
-    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        None
+    fn fmt(&self, _fmt: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use gfx_hal::{
-    adapter::PhysicalDevice
+    adapter::PhysicalDevice,
+    device::Device
 };
 
 use crate::{
@@ -32,6 +33,12 @@ impl DeviceAdapterBackend {
         &self.adapter
     }
 
+    pub fn wait_idle(&self) {
+        if let Err(e) = self.device.wait_idle() {
+            eprintln!("Failed to wait device to be idle: {}", e);
+        }
+    }
+
     pub fn limits(&self) -> gfx_hal::Limits {
         self.adapter
             .physical_device
@@ -13,9 +13,8 @@ use crate::{
             RendererBackend,
             RendererBackendInterface,
         },
-        GraphicsDevice,
-        ResourceDisposable,
-        panic_if_resource_isnt_disposed
+        resources::GpuResource,
+        GraphicsDevice
     }
 };
 
@@ -24,21 +23,15 @@ type InternalBackend = <RendererBackend as RendererBackendInterface>::InternalBa
 pub struct ShaderBindings {
     descriptors_set_layout: Vec::<pso::DescriptorSetLayoutBinding>,
     descriptors_pool: Vec::<pso::DescriptorRangeDesc>,
-    sampler: ManuallyDrop<<InternalBackend as gfx_hal::Backend>::Sampler>,
-    disposed: bool
+    sampler: ManuallyDrop<<InternalBackend as gfx_hal::Backend>::Sampler>
 }
 
-impl ResourceDisposable for ShaderBindings {
-    fn is_disposed(&self) -> bool {
-        self.disposed
+impl GpuResource for ShaderBindings {
+    fn kind(&self) -> &'static str {
+        "Shader"
     }
 
-    fn dispose(&mut self, device: &GraphicsDevice) {
-        if self.disposed {
-            return;
-        }
-
-        self.disposed = true;
+    fn destroy(&mut self, device: &GraphicsDevice) {
         let device_handle = device.backend().device();
 
         unsafe {
@@ -47,12 +40,6 @@ impl ResourceDisposable for ShaderBindings {
     }
 }
 
-impl Drop for ShaderBindings {
-    fn drop(&mut self) {
-        panic_if_resource_isnt_disposed!(self);
-    }
-}
-
 impl ShaderBindings {
     pub fn new(device: &GraphicsDevice) -> Self {
         Self {
@@ -68,8 +55,7 @@ impl ShaderBindings {
                              )
                          }
                          .expect("Can't create sampler.")
-                     ),
-            disposed: false
+                     )
         }
     }
 
@@ -362,7 +362,7 @@ impl State {
 
         Ok(Self {
             instance,
-            graphics_device: GraphicsDevice::new(DeviceAdapterBackend::new(device, adapter)),
+            graphics_device: GraphicsDevice::new(DeviceAdapterBackend::new(device, adapter), frames_in_flight),
             surface: ManuallyDrop::new(surface),
             format,
             queue_group,
@@ -525,6 +525,7 @@ impl State {
         };
 
         self.current_frame += 1;
+        self.graphics_device.end_frame();
     }
 
     pub fn draw_texture_with_vertices<V, P, U>(&mut self, vertices: &[V], texture: &mut Texture, shader: &Shader) -> Result<(), HalRenderError> where
@@ -633,6 +634,7 @@ impl State {
         };
 
         self.current_frame += 1;
+        self.graphics_device.end_frame();
         Ok(())
     }
 
@@ -1063,10 +1065,11 @@ impl State {
             return;
         }
 
-        let texture_size = *texture.size();
-        let texture_row_pitch = texture.bindings.row_pitch();
-        let texture_image_stride = texture.bindings.image_stride();
-        let texture_upload_buffer = texture.bindings.copy_into_stagging_buffer(vertex_buffer_memory_type, &self.graphics_device);
+        let texture_size = texture.size();
+        let mut texture_bindings = texture.bindings_mut();
+        let texture_row_pitch = texture_bindings.row_pitch();
+        let texture_image_stride = texture_bindings.image_stride();
+        let texture_upload_buffer = texture_bindings.copy_into_stagging_buffer(vertex_buffer_memory_type, &self.graphics_device);
 
         let device = self.graphics_device.backend().device();
         let mut image_object = ManuallyDrop::new(
@@ -1136,7 +1139,7 @@ impl State {
                         binding: 1,
                         array_offset: 0,
                         descriptors: Some(
-                            pso::Descriptor::Sampler(shader.bindings.sampler())
+                            pso::Descriptor::Sampler(shader.bindings().sampler())
                         )
                     }
                 ]
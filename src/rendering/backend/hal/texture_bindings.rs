@@ -21,9 +21,8 @@ use crate::{
             RendererBackend,
             RendererBackendInterface
         },
-        GraphicsDevice,
-        ResourceDisposable,
-        panic_if_resource_isnt_disposed
+        resources::GpuResource,
+        GraphicsDevice
     }
 };
 
@@ -41,23 +40,17 @@ pub struct TextureBindings {
     upload_memory: Option<ManuallyDrop<<InternalBackend as gfx_hal::Backend>::Memory>>,
     size: Size<u32>,
     row_pitch: u32,
-    image_stride: usize,
-    disposed: bool
+    image_stride: usize
 }
 
-impl ResourceDisposable for TextureBindings {
-    fn is_disposed(&self) -> bool {
-        self.disposed
+impl GpuResource for TextureBindings {
+    fn kind(&self) -> &'static str {
+        "Texture"
     }
 
-    fn dispose(&mut self, device: &GraphicsDevice) {
-        if self.disposed {
-            return;
-        }
-
-        self.disposed = true;
+    fn destroy(&mut self, device: &GraphicsDevice) {
+        // registry only destroys it after gpu is done with it, no need to wait idle
         let device_handle = device.backend().device();
-        device_handle.wait_idle().unwrap();
 
         unsafe {
             device_handle.destroy_buffer(ManuallyDrop::take(&mut self.upload_buffer));
@@ -69,12 +62,6 @@ impl ResourceDisposable for TextureBindings {
     }
 }
 
-impl Drop for TextureBindings {
-    fn drop(&mut self) {
-        panic_if_resource_isnt_disposed!(self);
-    }
-}
-
 impl TextureBindings {
     pub fn with<P: AsRef<Path>>(filepath: P, device: &GraphicsDevice) -> Result<Self, HalTextureBindingsError> {
         let dynamic_image = image_handler::open(filepath)
@@ -107,8 +94,7 @@ impl TextureBindings {
             upload_memory: None,
             size: Size::with(img_width, img_height),
             row_pitch,
-            image_stride,
-            disposed: false
+            image_stride
         })
     }
 
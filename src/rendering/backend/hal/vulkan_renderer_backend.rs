@@ -89,4 +89,8 @@ impl RendererBackendInterface for VulkanRendererBackend {
         self.hal_state.draw_texture_with_vertices(vertices, texture, shader)
                       .map_err(|e| RendererBackendError::InternalBackend(e.into()))
     }
+
+    // every draw call is presented on it's own, hal state already ends a frame with each one
+    fn end_frame(&mut self) {
+    }
 }
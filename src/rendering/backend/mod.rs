@@ -2,10 +2,14 @@ mod renderer_backend_interface;
 pub use renderer_backend_interface::RendererBackendInterface;
 
 #[cfg(feature = "no-backend")]
-mod no_renderer_backend;
+pub mod no_backend;
+
 #[cfg(feature = "no-backend")]
 pub use {
-    no_renderer_backend::NoRendererBackend as RendererBackend
+    no_backend::DeviceAdapterBackend    as DeviceAdapterBackend,
+    no_backend::RendererBackend         as RendererBackend,
+    no_backend::ShaderBindings          as ShaderBindings,
+    no_backend::TextureBindings         as TextureBindings,
 };
 
 #[cfg(not(feature = "no-backend"))]
@@ -20,4 +24,3 @@ pub use {
 };
 
 pub mod error;
-
@@ -0,0 +1,19 @@
+/// There is no device to adapt to, it only exists to fill `GraphicsDevice`.
+pub struct DeviceAdapterBackend {
+}
+
+impl Default for DeviceAdapterBackend {
+    fn default() -> Self {
+        DeviceAdapterBackend::new()
+    }
+}
+
+impl DeviceAdapterBackend {
+    pub fn new() -> Self {
+        Self {
+        }
+    }
+
+    pub fn wait_idle(&self) {
+    }
+}
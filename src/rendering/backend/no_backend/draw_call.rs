@@ -0,0 +1,9 @@
+/// Draw request received by the null renderer backend.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCall {
+    ClearFrame([f32; 4]),
+    Texture {
+        texture_uid: u64,
+        vertex_count: usize
+    }
+}
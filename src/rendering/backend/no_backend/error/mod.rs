@@ -0,0 +1,2 @@
+mod no_backend_texture_bindings_error;
+pub use no_backend_texture_bindings_error::NoBackendTextureBindingsError;
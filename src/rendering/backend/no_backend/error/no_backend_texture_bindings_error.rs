@@ -0,0 +1,35 @@
+use std::{
+    error::{
+        Error
+    },
+    fmt::{
+        self,
+        Display,
+        Formatter
+    }
+};
+
+use image_handler;
+
+#[derive(Debug)]
+pub enum NoBackendTextureBindingsError {
+    ImageLoading(image_handler::ImageError)
+}
+
+impl Display for NoBackendTextureBindingsError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NoBackendTextureBindingsError::ImageLoading(err) => {
+                write!(fmt, "Image loader raised an error: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for NoBackendTextureBindingsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NoBackendTextureBindingsError::ImageLoading(err) => Some(err)
+        }
+    }
+}
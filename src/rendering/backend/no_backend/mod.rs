@@ -0,0 +1,16 @@
+mod device_adapter_backend;
+pub use device_adapter_backend::DeviceAdapterBackend;
+
+mod shader_bindings;
+pub use shader_bindings::ShaderBindings;
+
+mod texture_bindings;
+pub use texture_bindings::TextureBindings;
+
+mod draw_call;
+pub use draw_call::DrawCall;
+
+mod no_renderer_backend;
+pub use no_renderer_backend::NoRendererBackend as RendererBackend;
+
+pub mod error;
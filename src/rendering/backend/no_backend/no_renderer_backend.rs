@@ -0,0 +1,141 @@
+use std::{
+    convert::Infallible,
+    mem
+};
+
+use crate::{
+    core::GameLoopInterface,
+    graphics::{
+        shaders::{
+            Shader
+        },
+        Texture
+    },
+    rendering::{
+        backend::{
+            error::{
+                RendererBackendError
+            },
+            RendererBackendInterface
+        },
+        GraphicsDevice,
+        RenderingRequirements,
+        VertexPosition,
+        VertexUV
+    },
+    window::Window
+};
+
+use super::{
+    DeviceAdapterBackend,
+    DrawCall,
+    ShaderBindings,
+    TextureBindings
+};
+
+/// Draws nothing, every draw call is recorded instead, so it can be inspected without a gpu.
+pub struct NoRendererBackend {
+    graphics_device: GraphicsDevice,
+    draw_calls: Vec<DrawCall>,
+    recording: bool
+}
+
+impl NoRendererBackend {
+    pub fn new<L: 'static + GameLoopInterface>(_window: Option<&Window<L>>) -> Result<Self, RendererBackendError> {
+        Ok(Self { 
+            graphics_device: GraphicsDevice::new(DeviceAdapterBackend::new(), 0),
+            draw_calls: Vec::new(),
+            recording: true
+        })
+    }
+
+    /// Draw calls recorded so far, in the order they were made.
+    pub fn draw_calls(&self) -> &[DrawCall] {
+        &self.draw_calls
+    }
+
+    pub fn take_draw_calls(&mut self) -> Vec<DrawCall> {
+        mem::take(&mut self.draw_calls)
+    }
+
+    pub fn clear_draw_calls(&mut self) {
+        self.draw_calls.clear();
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Recording is enabled by default, long running servers should disable it or take draw calls often.
+    pub fn set_recording(&mut self, recording: bool) {
+        self.recording = recording;
+    }
+
+    fn record(&mut self, draw_call: DrawCall) {
+        if self.recording {
+            self.draw_calls.push(draw_call);
+        }
+    }
+}
+
+impl RendererBackendInterface for NoRendererBackend {
+    type InternalBackend = ();
+    type TextureBindings = TextureBindings;
+    type ShaderBindings = ShaderBindings;
+    type DeviceAdapterBackend = DeviceAdapterBackend;
+    type InternalBackendError = Infallible;
+
+    fn name() -> &'static str {
+        "NoBackend"
+    }
+
+    fn has_requirements(_requirements: RenderingRequirements) -> bool {
+        true
+    }
+
+    fn graphics_device(&self) -> &GraphicsDevice {
+        &self.graphics_device
+    }
+
+    fn mut_graphics_device(&mut self) -> &mut GraphicsDevice {
+        &mut self.graphics_device
+    }
+
+    fn draw_clear_frame(&mut self, color: [f32; 4]) {
+        self.record(DrawCall::ClearFrame(color));
+    }
+
+    fn draw_texture_with_vertices<V, P, U>(&mut self, vertices: &[V], texture: &mut Texture, _shader: &Shader) -> Result<(), RendererBackendError> where 
+        V: VertexPosition<P> + VertexUV<U>
+    {
+        self.record(DrawCall::Texture {
+            texture_uid: texture.uid(),
+            vertex_count: vertices.len()
+        });
+
+        Ok(())
+    }
+
+    fn end_frame(&mut self) {
+        self.graphics_device.end_frame();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GameLoop;
+
+    #[test]
+    fn draw_calls_share_a_frame() {
+        let mut backend = NoRendererBackend::new::<GameLoop>(None).unwrap();
+
+        backend.draw_clear_frame([0.0; 4]);
+        backend.draw_clear_frame([1.0; 4]);
+        assert_eq!(0, backend.graphics_device().resources().frame());
+
+        backend.end_frame();
+        assert_eq!(1, backend.graphics_device().resources().frame());
+        assert_eq!(2, backend.draw_calls().len());
+    }
+}
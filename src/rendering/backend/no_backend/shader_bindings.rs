@@ -0,0 +1,25 @@
+use crate::{
+    rendering::{
+        resources::GpuResource,
+        GraphicsDevice
+    }
+};
+
+pub struct ShaderBindings {
+}
+
+impl GpuResource for ShaderBindings {
+    fn kind(&self) -> &'static str {
+        "Shader"
+    }
+
+    fn destroy(&mut self, _device: &GraphicsDevice) {
+    }
+}
+
+impl ShaderBindings {
+    pub fn new(_device: &GraphicsDevice) -> Self {
+        Self {
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::{
+    path::Path
+};
+
+use image_handler;
+
+use crate::{
+    math::Size,
+    rendering::{
+        resources::GpuResource,
+        GraphicsDevice
+    }
+};
+
+use super::{
+    error::{
+        NoBackendTextureBindingsError
+    }
+};
+
+/// Only image size is read, pixels aren't loaded since there is nowhere to upload them.
+pub struct TextureBindings {
+    size: Size<u32>
+}
+
+impl GpuResource for TextureBindings {
+    fn kind(&self) -> &'static str {
+        "Texture"
+    }
+
+    fn destroy(&mut self, _device: &GraphicsDevice) {
+    }
+}
+
+impl TextureBindings {
+    pub fn with<P: AsRef<Path>>(filepath: P, _device: &GraphicsDevice) -> Result<Self, NoBackendTextureBindingsError> {
+        let (width, height) = image_handler::image_dimensions(filepath)
+                                            .map_err(NoBackendTextureBindingsError::ImageLoading)?;
+
+        Ok(Self {
+            size: Size::with(width, height)
+        })
+    }
+
+    pub fn size(&self) -> &Size<u32> {
+        &self.size
+    }
+}
@@ -20,7 +20,7 @@ use super::{
 };
 
 pub trait RendererBackendInterface {
-    type InternalBackend: gfx_hal::Backend;
+    type InternalBackend;
     type TextureBindings;
     type ShaderBindings;
     type DeviceAdapterBackend;
@@ -33,5 +33,8 @@ pub trait RendererBackendInterface {
     //fn draw<T: Graphic>(&self, graphic: &T);
     fn draw_clear_frame(&mut self, color: [f32; 4]);
     fn draw_texture_with_vertices<V, P, U>(&mut self, vertices: &[V], texture: &mut Texture, shader: &Shader) -> Result<(), RendererBackendError> where V: VertexPosition<P> + VertexUV<U>;
+
+    /// Called once per presented frame, after every draw call of it.
+    fn end_frame(&mut self);
 }
 
@@ -2,20 +2,34 @@ use super::{
     backend::{
         RendererBackend,
         RendererBackendInterface
-    }
+    },
+    resources::ResourceRegistry
 };
 
 type DeviceAdapterBackend = <RendererBackend as RendererBackendInterface>::DeviceAdapterBackend;
 
 pub struct GraphicsDevice {
     backend: DeviceAdapterBackend,
+    resources: ResourceRegistry,
     next_texture_uid: u64
 }
 
+impl Drop for GraphicsDevice {
+    fn drop(&mut self) {
+        self.backend.wait_idle();
+        let leaks = self.resources.shutdown(self);
+
+        if !leaks.is_empty() {
+            eprintln!("Graphics device shutdown with leaked resources. {}", leaks);
+        }
+    }
+}
+
 impl GraphicsDevice {
-    pub fn new(backend: DeviceAdapterBackend) -> Self {
+    pub fn new(backend: DeviceAdapterBackend, frames_in_flight: usize) -> Self {
         Self {
             backend,
+            resources: ResourceRegistry::new(frames_in_flight),
             next_texture_uid: 1u64
         }
     }
@@ -24,6 +38,15 @@ impl GraphicsDevice {
         &self.backend
     }
 
+    pub fn resources(&self) -> &ResourceRegistry {
+        &self.resources
+    }
+
+    /// Called by renderer backend after each submission, destroys released resources which gpu is done with.
+    pub fn end_frame(&self) {
+        self.resources.end_frame(self);
+    }
+
     pub fn next_texture_uid(&mut self) -> u64 {
         let uid = self.next_texture_uid;
         self.next_texture_uid += 1;
@@ -17,11 +17,7 @@ pub use renderer::Renderer;
 
 pub mod error;
 
-#[macro_use]
-mod resource_disposable;
-pub use resource_disposable::ResourceDisposable;
-pub use panic_if_resource_isnt_disposed;
-pub use panic_if_resources_isnt_disposed;
+pub mod resources;
 
 mod rendering_requirements;
 pub use rendering_requirements::RenderingRequirements;
@@ -3,15 +3,13 @@ use crate::{
     graphics::{
         shaders::{
             Shader,
+            ShaderBuildError,
             ShaderBuilder
         },
         Texture,
     },
     rendering::{
         backend::{
-            error::{
-                RendererBackendError
-            },
             RendererBackend,
             RendererBackendInterface,
         },
@@ -27,34 +25,31 @@ use crate::{
 };
 
 pub struct Renderer {
-    backend: RendererBackend,
-    shader_builder: ShaderBuilder,
-    default_shader: Shader
+    // dropped before backend, so it's released before graphics device shuts down
+    default_shader: Shader,
+    _shader_builder: ShaderBuilder,
+    backend: RendererBackend
 }
 
 impl Renderer {
     pub fn new<L: 'static + GameLoopInterface>(window: Option<&Window<L>>) -> Result<Self, RendererInitError> {
         let backend = if cfg!(feature = "no-backend") {
             RendererBackend::new::<L>(None)
-                            .map_err(|e| RendererInitError::BackendCreation(e))?
+                            .map_err(RendererInitError::BackendCreation)?
         } else {
             RendererBackend::new(window)
-                            .map_err(|e| RendererInitError::BackendCreation(e))?
+                            .map_err(RendererInitError::BackendCreation)?
         };
 
         let mut shader_builder = ShaderBuilder::new()
-                                               .map_err(|e| RendererInitError::ShaderBuilderCreation(e))?;
+                                               .map_err(RendererInitError::ShaderBuilderCreation)?;
 
-        let default_shader = shader_builder.shader_from_files(
-            "../../src/resources/shaders/basic_shader.vert", 
-            "../../src/resources/shaders/basic_shader.frag", 
-            backend.graphics_device()
-        )
-        .map_err(|e| RendererInitError::DefaultShaderCreation(e))?;
+        let default_shader = Renderer::default_shader(&mut shader_builder, backend.graphics_device())
+                                       .map_err(RendererInitError::DefaultShaderCreation)?;
 
         Ok(Self {
-            backend: backend,
-            shader_builder,
+            backend,
+            _shader_builder: shader_builder,
             default_shader
         })
     }
@@ -63,6 +58,10 @@ impl Renderer {
         &self.backend
     }
 
+    pub fn mut_backend(&mut self) -> &mut RendererBackend {
+        &mut self.backend
+    }
+
     pub fn graphics_device(&self) -> &GraphicsDevice {
         self.backend.graphics_device()
     }
@@ -82,7 +81,27 @@ impl Renderer {
             Some(s) => self.backend.draw_texture_with_vertices(vertices, texture, s),
             None => self.backend.draw_texture_with_vertices(vertices, texture, &self.default_shader)
         }
-        .map_err(|e| RenderError::Backend(e))
+        .map_err(RenderError::Backend)
+    }
+
+    /// Every draw call of current frame was made, resources released before it can be destroyed once it's done.
+    pub fn end_frame(&mut self) {
+        self.backend.end_frame();
+    }
+
+    #[cfg(not(feature = "no-backend"))]
+    fn default_shader(shader_builder: &mut ShaderBuilder, device: &GraphicsDevice) -> Result<Shader, ShaderBuildError> {
+        shader_builder.shader_from_files(
+            "../../src/resources/shaders/basic_shader.vert", 
+            "../../src/resources/shaders/basic_shader.frag", 
+            device
+        )
+    }
+
+    // there is nothing to compile shaders to, so it doesn't depends on shader files
+    #[cfg(feature = "no-backend")]
+    fn default_shader(_shader_builder: &mut ShaderBuilder, device: &GraphicsDevice) -> Result<Shader, ShaderBuildError> {
+        Ok(Shader::new(Vec::new(), Vec::new(), device))
     }
 
     /*
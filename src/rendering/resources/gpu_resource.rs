@@ -0,0 +1,9 @@
+use crate::rendering::GraphicsDevice;
+
+/// Resource which holds gpu memory, only it's registry destroys it.
+pub trait GpuResource : 'static {
+    fn kind(&self) -> &'static str;
+
+    /// Called once, after gpu is done with it.
+    fn destroy(&mut self, device: &GraphicsDevice);
+}
@@ -0,0 +1,52 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter
+};
+
+use crate::rendering::resources::ResourceId;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeakedResource {
+    pub id: ResourceId,
+    pub kind: &'static str,
+    pub label: String
+}
+
+/// Resources still referenced by a handle, when it was made.
+#[derive(Debug, Clone, Default)]
+pub struct LeakReport {
+    resources: Vec<LeakedResource>
+}
+
+impl Display for LeakReport {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{} gpu resource(s) still referenced:", self.resources.len())?;
+
+        for resource in self.resources.iter() {
+            write!(fmt, "\n  {} {} '{}'", resource.kind, resource.id, resource.label)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LeakReport {
+    pub(super) fn new(resources: Vec<LeakedResource>) -> LeakReport {
+        LeakReport {
+            resources
+        }
+    }
+
+    pub fn resources(&self) -> &[LeakedResource] {
+        &self.resources
+    }
+
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+}
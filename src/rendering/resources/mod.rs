@@ -0,0 +1,17 @@
+mod gpu_resource;
+pub use gpu_resource::GpuResource;
+
+mod resource_id;
+pub use resource_id::ResourceId;
+
+mod resource_handle;
+pub use resource_handle::ResourceHandle;
+
+mod resource_registry;
+pub use resource_registry::ResourceRegistry;
+
+mod leak_report;
+pub use leak_report::{
+    LeakedResource,
+    LeakReport
+};
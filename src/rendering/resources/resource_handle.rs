@@ -0,0 +1,83 @@
+use std::{
+    cell::{
+        Ref,
+        RefCell,
+        RefMut
+    },
+    rc::{
+        Rc,
+        Weak
+    }
+};
+
+use crate::rendering::resources::{
+    resource_registry::RegistryState,
+    GpuResource,
+    ResourceId
+};
+
+struct SharedResource<T: GpuResource> {
+    id: ResourceId,
+    resource: RefCell<Option<T>>,
+    registry: Weak<RefCell<RegistryState>>
+}
+
+impl<T: GpuResource> Drop for SharedResource<T> {
+    fn drop(&mut self) {
+        let resource = match self.resource.get_mut().take() {
+            Some(resource) => resource,
+            None => return
+        };
+
+        // without a registry the device is already gone, it was reported as leaked then
+        if let Some(registry) = self.registry.upgrade() {
+            registry.borrow_mut().release(self.id, Box::new(resource));
+        }
+    }
+}
+
+/// Reference counted handle to a registered resource, it's released when the last handle drops.
+pub struct ResourceHandle<T: GpuResource> {
+    shared: Rc<SharedResource<T>>
+}
+
+impl<T: GpuResource> Clone for ResourceHandle<T> {
+    fn clone(&self) -> Self {
+        ResourceHandle {
+            shared: Rc::clone(&self.shared)
+        }
+    }
+}
+
+impl<T: GpuResource> ResourceHandle<T> {
+    pub(super) fn new(id: ResourceId, resource: T, registry: Weak<RefCell<RegistryState>>) -> ResourceHandle<T> {
+        ResourceHandle {
+            shared: Rc::new(SharedResource {
+                id,
+                resource: RefCell::new(Some(resource)),
+                registry
+            })
+        }
+    }
+
+    pub fn id(&self) -> ResourceId {
+        self.shared.id
+    }
+
+    pub fn get(&self) -> Ref<'_, T> {
+        Ref::map(self.shared.resource.borrow(), |resource| {
+            resource.as_ref().expect("Resource is only taken when it's last handle drops.")
+        })
+    }
+
+    pub fn get_mut(&self) -> RefMut<'_, T> {
+        RefMut::map(self.shared.resource.borrow_mut(), |resource| {
+            resource.as_mut().expect("Resource is only taken when it's last handle drops.")
+        })
+    }
+
+    /// How many handles shares this resource.
+    pub fn ref_count(&self) -> usize {
+        Rc::strong_count(&self.shared)
+    }
+}
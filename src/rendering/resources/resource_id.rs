@@ -0,0 +1,20 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ResourceId(pub(super) u64);
+
+impl Display for ResourceId {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "#{}", self.0)
+    }
+}
+
+impl ResourceId {
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
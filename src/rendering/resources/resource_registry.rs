@@ -0,0 +1,148 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    mem,
+    rc::Rc
+};
+
+use crate::rendering::{
+    resources::{
+        GpuResource,
+        LeakedResource,
+        LeakReport,
+        ResourceHandle,
+        ResourceId
+    },
+    GraphicsDevice
+};
+
+struct LiveResource {
+    kind: &'static str,
+    label: String
+}
+
+struct ReleasedResource {
+    resource: Box<dyn GpuResource>,
+    frame: u64
+}
+
+pub(super) struct RegistryState {
+    next_id: u64,
+    live: BTreeMap<ResourceId, LiveResource>,
+    released: Vec<ReleasedResource>,
+    frame: u64,
+    frames_in_flight: u64
+}
+
+impl RegistryState {
+    pub fn release(&mut self, id: ResourceId, resource: Box<dyn GpuResource>) {
+        self.live.remove(&id);
+        self.released.push(ReleasedResource {
+            resource,
+            frame: self.frame
+        });
+    }
+}
+
+/// Owns every gpu resource, they're destroyed only after every frame which could use them is done.
+pub struct ResourceRegistry {
+    state: Rc<RefCell<RegistryState>>
+}
+
+impl ResourceRegistry {
+    pub(crate) fn new(frames_in_flight: usize) -> ResourceRegistry {
+        ResourceRegistry {
+            state: Rc::new(RefCell::new(RegistryState {
+                next_id: 1,
+                live: BTreeMap::new(),
+                released: Vec::new(),
+                frame: 0,
+                frames_in_flight: frames_in_flight as u64
+            }))
+        }
+    }
+
+    pub fn register<T: GpuResource, L: Into<String>>(&self, resource: T, label: L) -> ResourceHandle<T> {
+        let mut state = self.state.borrow_mut();
+        let id = ResourceId(state.next_id);
+        state.next_id += 1;
+
+        state.live.insert(id, LiveResource {
+            kind: resource.kind(),
+            label: label.into()
+        });
+
+        ResourceHandle::new(id, resource, Rc::downgrade(&self.state))
+    }
+
+    /// Resources still referenced by a handle.
+    pub fn len(&self) -> usize {
+        self.state.borrow().live.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state.borrow().live.is_empty()
+    }
+
+    /// Released resources waiting for gpu to be done with them.
+    pub fn pending_destruction(&self) -> usize {
+        self.state.borrow().released.len()
+    }
+
+    pub fn frame(&self) -> u64 {
+        self.state.borrow().frame
+    }
+
+    pub fn frames_in_flight(&self) -> u64 {
+        self.state.borrow().frames_in_flight
+    }
+
+    pub fn leak_report(&self) -> LeakReport {
+        let resources = self.state
+                            .borrow()
+                            .live
+                            .iter()
+                            .map(|(id, live)| LeakedResource {
+                                id: *id,
+                                kind: live.kind,
+                                label: live.label.clone()
+                            })
+                            .collect();
+
+        LeakReport::new(resources)
+    }
+
+    pub(crate) fn end_frame(&self, device: &GraphicsDevice) {
+        self.state.borrow_mut().frame += 1;
+        self.destroy_released(device, false);
+    }
+
+    /// Device must be idle, every released resource is destroyed right away.
+    pub(crate) fn shutdown(&self, device: &GraphicsDevice) -> LeakReport {
+        while self.pending_destruction() > 0 {
+            self.destroy_released(device, true);
+        }
+
+        self.leak_report()
+    }
+
+    fn destroy_released(&self, device: &GraphicsDevice, all: bool) {
+        let ready: Vec<ReleasedResource> = {
+            let mut state = self.state.borrow_mut();
+            let frame = state.frame;
+            let frames_in_flight = state.frames_in_flight;
+
+            let (ready, waiting) = mem::take(&mut state.released)
+                                      .into_iter()
+                                      .partition(|released| all || released.frame + frames_in_flight <= frame);
+
+            state.released = waiting;
+            ready
+        };
+
+        // state isn't borrowed anymore, destroying a resource may release others
+        for mut released in ready {
+            released.resource.destroy(device);
+        }
+    }
+}
@@ -25,7 +25,7 @@ impl Logger {
 
     pub fn write(&mut self, context: &str, msg: &str) -> Result<(), Error> {
         let c = {
-            if context.len() > 0 {
+            if !context.is_empty() {
                 Some(context)
             } else {
                 None
@@ -43,7 +43,7 @@ impl Logger {
         let timestamp = self.get_system_time_now();
         let separation = "  ";
 
-        if context.len() > 0 {
+        if !context.is_empty() {
             for listener in self._listeners.iter_mut() {
                 listener.write(Some("timestamp"), &timestamp)?;
                 listener.write(None, separation)?;
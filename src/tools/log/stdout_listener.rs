@@ -15,9 +15,11 @@ use super::{
     ErrorKind
 };
 
+type ContextProcessor = Box<dyn Fn(&str) -> String>;
+
 pub struct StdoutListener {
     _stdout_handle: Stdout,
-    _contexts: HashMap<String, Box<dyn Fn(&str) -> String>>
+    _contexts: HashMap<String, ContextProcessor>
 }
 
 impl LogListener for StdoutListener {
@@ -46,6 +48,12 @@ impl LogListener for StdoutListener {
     }
 }
 
+impl Default for StdoutListener {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl StdoutListener {
     pub fn new() -> StdoutListener {
         let mut stdout_listener = StdoutListener {
@@ -0,0 +1,76 @@
+use std::{
+    borrow::Borrow,
+    cell::RefCell
+};
+
+use crate::{
+    core::{
+        GameLoopInterface,
+        GameState
+    },
+    events::Event,
+    input::InputEvent,
+    window::WindowEvent
+};
+
+#[derive(Debug, Clone)]
+pub enum BackendEvent {
+    Window(WindowEvent),
+    Input(InputEvent)
+}
+
+impl From<WindowEvent> for BackendEvent {
+    fn from(event: WindowEvent) -> BackendEvent {
+        BackendEvent::Window(event)
+    }
+}
+
+impl From<InputEvent> for BackendEvent {
+    fn from(event: InputEvent) -> BackendEvent {
+        BackendEvent::Input(event)
+    }
+}
+
+impl BackendEvent {
+    pub fn dispatch<L: GameLoopInterface>(self, game_loop: &mut L) {
+        match self {
+            BackendEvent::Window(kind) => {
+                let close_requested = kind == WindowEvent::CloseRequested;
+                let mut event = Event::new(kind);
+                game_loop.handle(&mut event);
+
+                // a listener may consume it to keep game running
+                if close_requested && !event.is_consumed() {
+                    match game_loop.game_state().upgrade() {
+                        Some(game_state_strong_ref) => {
+                            <_ as Borrow<RefCell<GameState>>>::borrow(&game_state_strong_ref)
+                                                              .borrow_mut()
+                                                              .close_game();
+                        },
+                        None => eprintln!("Failed retrieving game state from game loop.")
+                    }
+                }
+            },
+            BackendEvent::Input(kind) => {
+                game_loop.handle(&mut Event::new(kind));
+            }
+        }
+    }
+
+    /// Gamepads aren't reported by window backends, so they are polled once per frame.
+    pub fn dispatch_gamepad_events<L: GameLoopInterface>(game_loop: &mut L) {
+        let events = match game_loop.game_state().upgrade() {
+            Some(game_state_strong_ref) => {
+                <_ as Borrow<RefCell<GameState>>>::borrow(&game_state_strong_ref)
+                                                  .borrow_mut()
+                                                  .gamepads_mut()
+                                                  .poll()
+            },
+            None => return
+        };
+
+        for event in events {
+            BackendEvent::Input(InputEvent::Gamepad(event)).dispatch(game_loop);
+        }
+    }
+}
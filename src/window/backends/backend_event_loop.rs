@@ -1,17 +1,4 @@
-use std::{
-    cell::RefCell,
-    rc::{ Rc, Weak }
-};
-
-
-use crate::{
-    core::{
-        ecs::Realm,
-        GameLoopInterface,
-        GameState
-    },
-    rendering::Renderer
-};
+use crate::core::GameLoopInterface;
 
 pub trait BackendEventLoop<L: GameLoopInterface> {
     fn run(self, game_loop: L);
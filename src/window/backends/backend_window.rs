@@ -1,9 +1,17 @@
+#[cfg(not(feature = "no-backend"))]
 use raw_window_handle::HasRawWindowHandle;
 
 use crate::{
     math::Size
 };
 
+#[cfg(not(feature = "no-backend"))]
 pub trait BackendWindow : HasRawWindowHandle {
     fn inner_size(&self) -> Size<u32>;
 }
+
+// there is no surface to present to, so it doesn't needs a raw window handle
+#[cfg(feature = "no-backend")]
+pub trait BackendWindow {
+    fn inner_size(&self) -> Size<u32>;
+}
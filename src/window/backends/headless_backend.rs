@@ -0,0 +1,284 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    marker::PhantomData,
+    rc::Rc
+};
+
+#[cfg(feature = "no-backend")]
+use std::convert::Infallible;
+
+use crate::{
+    core::GameLoopInterface,
+    window::backends::{
+        BackendEvent,
+        BackendEventLoop
+    }
+};
+
+#[cfg(feature = "no-backend")]
+use crate::{
+    math::Size,
+    window::backends::{
+        BackendInterface,
+        BackendWindow
+    }
+};
+
+// event injector
+
+struct InjectedEvents {
+    frame: u64,
+    queue: VecDeque<(u64, BackendEvent)>
+}
+
+#[derive(Clone)]
+pub struct EventInjector {
+    events: Rc<RefCell<InjectedEvents>>
+}
+
+impl Default for EventInjector {
+    fn default() -> Self {
+        EventInjector::new()
+    }
+}
+
+impl EventInjector {
+    pub fn new() -> EventInjector {
+        EventInjector {
+            events: Rc::new(RefCell::new(InjectedEvents {
+                frame: 0,
+                queue: VecDeque::new()
+            }))
+        }
+    }
+
+    /// Delivers event at the start of next frame.
+    pub fn push<E: Into<BackendEvent>>(&self, event: E) {
+        let frame = self.frame();
+        self.push_at(frame, event);
+    }
+
+    /// Delivers event at the start of the requested frame, or as soon as possible if it already passed.
+    pub fn push_at<E: Into<BackendEvent>>(&self, frame: u64, event: E) {
+        let mut events = self.events.borrow_mut();
+
+        // keep frame order, events at the same frame are delivered as pushed
+        let index = events.queue
+                          .iter()
+                          .position(|(event_frame, _)| *event_frame > frame)
+                          .unwrap_or_else(|| events.queue.len());
+
+        events.queue.insert(index, (frame, event.into()));
+    }
+
+    pub fn frame(&self) -> u64 {
+        self.events.borrow().frame
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.borrow().queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.borrow().queue.is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.events.borrow_mut().queue.clear();
+    }
+
+    fn next_event(&self) -> Option<BackendEvent> {
+        let mut events = self.events.borrow_mut();
+        let frame = events.frame;
+
+        match events.queue.front() {
+            Some((event_frame, _)) if *event_frame <= frame => events.queue.pop_front().map(|(_, event)| event),
+            _ => None
+        }
+    }
+
+    fn advance_frame(&self) {
+        self.events.borrow_mut().frame += 1;
+    }
+}
+
+// event loop
+
+pub struct HeadlessEventLoop<L: GameLoopInterface> {
+    injector: EventInjector,
+    max_frames: Option<u64>,
+    phantom: PhantomData<L>
+}
+
+impl<L: GameLoopInterface> BackendEventLoop<L> for HeadlessEventLoop<L> {
+    fn run(self, game_loop: L) {
+        self.run_and_return(game_loop);
+    }
+}
+
+impl<L: GameLoopInterface> HeadlessEventLoop<L> {
+    pub fn new(injector: EventInjector) -> Self {
+        Self {
+            injector,
+            max_frames: None,
+            phantom: PhantomData
+        }
+    }
+
+    /// Stops after running the given frame count, even if game is still running.
+    pub fn with_max_frames(mut self, max_frames: u64) -> Self {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    pub fn injector(&self) -> &EventInjector {
+        &self.injector
+    }
+
+    /// Runs as `run` does, but game loop is given back once it ends.
+    pub fn run_and_return(self, mut game_loop: L) -> L {
+        let game_state_weak = game_loop.game_state();
+        let is_running = || {
+            match game_state_weak.upgrade() {
+                Some(game_state_strong_ref) => {
+                    game_state_strong_ref.borrow().is_running()
+                },
+                None => {
+                    eprintln!("Failed retrieving game state from game loop.");
+                    false
+                }
+            }
+        };
+
+        if let Some(game_state_strong_ref) = game_state_weak.upgrade() {
+            game_state_strong_ref.borrow_mut().start();
+        }
+
+        let mut redraw_request = false;
+
+        while is_running() {
+            if let Some(max_frames) = self.max_frames {
+                if self.injector.frame() >= max_frames {
+                    break;
+                }
+            }
+
+            while let Some(event) = self.injector.next_event() {
+                event.dispatch(&mut game_loop);
+            }
+
+            BackendEvent::dispatch_gamepad_events(&mut game_loop);
+
+            if !is_running() {
+                break;
+            }
+
+            game_loop.step(&mut redraw_request);
+
+            if redraw_request {
+                game_loop.render();
+                redraw_request = false;
+            }
+
+            self.injector.advance_frame();
+        }
+
+        game_loop.finalize();
+        game_loop
+    }
+}
+
+// window
+
+/// Window without any surface, it only keeps the requested title and size.
+#[cfg(feature = "no-backend")]
+pub struct HeadlessWindow {
+    title: String,
+    size: Size<u32>
+}
+
+#[cfg(feature = "no-backend")]
+impl BackendWindow for HeadlessWindow {
+    fn inner_size(&self) -> Size<u32> {
+        self.size
+    }
+}
+
+#[cfg(feature = "no-backend")]
+impl HeadlessWindow {
+    pub fn new<T: Into<String>>(title: T, size: Size<u32>) -> Self {
+        Self {
+            title: title.into(),
+            size
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// There is no one to resize it, so it's done manually, a `WindowEvent::Resized` should be injected as well.
+    pub fn set_inner_size(&mut self, size: Size<u32>) {
+        self.size = size;
+    }
+}
+
+// backend
+
+#[cfg(feature = "no-backend")]
+pub struct Backend<L: GameLoopInterface> {
+    window: HeadlessWindow,
+    injector: EventInjector,
+    max_frames: Option<u64>,
+    phantom: PhantomData<L>
+}
+
+#[cfg(feature = "no-backend")]
+impl<L: 'static + GameLoopInterface> BackendInterface<L> for Backend<L> {
+    type Window = HeadlessWindow;
+    type EventLoop = HeadlessEventLoop<L>;
+
+    fn window(&self) -> &Self::Window {
+        &self.window
+    }
+
+    fn window_mut(&mut self) -> &mut Self::Window {
+        &mut self.window
+    }
+
+    fn event_loop(&mut self) -> Self::EventLoop {
+        let event_loop = HeadlessEventLoop::new(self.injector.clone());
+
+        match self.max_frames {
+            Some(max_frames) => event_loop.with_max_frames(max_frames),
+            None => event_loop
+        }
+    }
+}
+
+#[cfg(feature = "no-backend")]
+impl<L: GameLoopInterface> Backend<L> {
+    pub fn new<T: Into<String>>(window_title: T, size: Size<u32>) -> Result<Self, Infallible> {
+        Ok(Self {
+            window: HeadlessWindow::new(window_title, size),
+            injector: EventInjector::new(),
+            max_frames: None,
+            phantom: PhantomData
+        })
+    }
+
+    /// Synthetic input source, events pushed to it are delivered to game loop frame by frame.
+    pub fn injector(&self) -> &EventInjector {
+        &self.injector
+    }
+
+    pub fn max_frames(&self) -> Option<u64> {
+        self.max_frames
+    }
+
+    /// Frame count the next event loop will run at most, counted from injector's first frame.
+    pub fn set_max_frames(&mut self, max_frames: Option<u64>) {
+        self.max_frames = max_frames;
+    }
+}
@@ -7,5 +7,14 @@ pub use backend_event_loop::BackendEventLoop;
 mod backend_window;
 pub use backend_window::BackendWindow;
 
+mod backend_event;
+pub use backend_event::BackendEvent;
+
+#[cfg(not(feature = "no-backend"))]
 pub mod winit_backend;
+#[cfg(not(feature = "no-backend"))]
 pub use winit_backend as backend;
+
+pub mod headless_backend;
+#[cfg(feature = "no-backend")]
+pub use headless_backend as backend;
@@ -21,6 +21,8 @@ use winit::{
         OsError
     },
     event::{
+        ElementState,
+        ModifiersState,
         VirtualKeyCode
     },
     event_loop::{
@@ -58,6 +60,7 @@ use crate::{
     },
     window::{
         backends::{
+            BackendEvent,
             BackendEventLoop,
             BackendInterface,
             BackendWindow
@@ -125,6 +128,13 @@ impl<L: 'static + GameLoopInterface> BackendEventLoop<L> for WinitEventLoop<L> {
                                         .expect("Expecting winit event loop.");
 
         let mut redraw_request = false;
+        let mut modifiers = KeyModifiers::default();
+        let mut ime_position = None;
+        let mut scale_factor = match self.window.upgrade() {
+            Some(w) => w.scale_factor(),
+            None => 1.0
+        };
+
         let game_state_weak = game_loop.game_state();
 
         event_loop.run(move |event, _, control_flow| {
@@ -133,26 +143,18 @@ impl<L: 'static + GameLoopInterface> BackendEventLoop<L> for WinitEventLoop<L> {
             match event {
                 winit::event::Event::WindowEvent { window_id: _,  event } => {
                     match event {
-                        winit::event::WindowEvent::CloseRequested => {
-                            println!("Close was requested by system.");
-
-                            match game_state_weak.upgrade() {
-                                Some(ref mut game_state_strong_ref) => {
-                                    let mut game_state = <_ as Borrow<RefCell<GameState>>>::borrow(game_state_strong_ref)
-                                                                                           .borrow_mut();
-
-                                    game_state.close_game();
-                                },
-                                None => {
-                                    eprintln!("Failed retrieving game state from game loop.");
-                                    *control_flow = ControlFlow::Exit;
-                                }
-                            }
-                        },
+                        // winit reports modifiers apart, keep them to stamp next input events
+                        winit::event::WindowEvent::ModifiersChanged(new_modifiers) => modifiers = new_modifiers.into(),
+                        winit::event::WindowEvent::ScaleFactorChanged { scale_factor: new_scale_factor, .. } => scale_factor = new_scale_factor,
                         _ => ()
                     }
+
+                    if let Some(backend_event) = translate_window_event(event, modifiers, scale_factor) {
+                        backend_event.dispatch(&mut game_loop);
+                    }
                 },
                 winit::event::Event::MainEventsCleared => {
+                    BackendEvent::dispatch_gamepad_events(&mut game_loop);
                     game_loop.step(&mut redraw_request);
 
                     if redraw_request {
@@ -176,17 +178,22 @@ impl<L: 'static + GameLoopInterface> BackendEventLoop<L> for WinitEventLoop<L> {
 
             match game_state_weak.upgrade() {
                 Some(ref mut game_state_strong_ref) => {
-                    let mut game_state = <_ as Borrow<RefCell<GameState>>>::borrow(game_state_strong_ref)
-                                                                           .borrow_mut();
+                    let game_state = <_ as Borrow<RefCell<GameState>>>::borrow(game_state_strong_ref)
+                                                                       .borrow();
 
                     if !game_state.is_running() {
-                        if let ControlFlow::Exit = *control_flow {
-                        } else {
-                            println!("Close was requested by user.");
-                        }
-
                         *control_flow = ControlFlow::Exit;
                     }
+
+                    // winit only lets candidate window be placed, composition events aren't reported
+                    let text_input = game_state.input().text_input();
+                    if text_input.is_enabled() && text_input.ime_position() != ime_position {
+                        ime_position = text_input.ime_position();
+
+                        if let (Some(position), Some(w)) = (ime_position, self.window.upgrade()) {
+                            w.set_ime_position(LogicalPosition::new(position.x(), position.y()));
+                        }
+                    }
                 },
                 None => {
                     eprintln!("Failed retrieving game state from game loop.");
@@ -195,166 +202,93 @@ impl<L: 'static + GameLoopInterface> BackendEventLoop<L> for WinitEventLoop<L> {
             }
         });
     }
+}
 
-    /*
-    fn poll_events(&mut self) {
-        let window_events = &mut self.window_events;
-        let input_events = &mut self.input_events;
+// modifiers and scale factor are tracked by the event loop, as winit reports them apart
+fn translate_window_event(event: winit::event::WindowEvent, modifiers: KeyModifiers, scale_factor: f64) -> Option<BackendEvent> {
+    let backend_event = match event {
+        // window events
 
-        self.events_loop.poll_events(|e| {
-            match e {
-                winit::Event::WindowEvent { window_id: _, event } => {
-                    match event {
-                        // window events
-
-                        winit::WindowEvent::Resized(new_size) => {
-                            window_events.push(Event::new(WindowEvent::Resized(new_size.into())));
-                        },
-                        winit::WindowEvent::Moved(new_pos) => {
-                            window_events.push(Event::new(WindowEvent::Moved(new_pos.into())));
-                        },
-                        winit::WindowEvent::CloseRequested => {
-                            window_events.push(Event::new(WindowEvent::CloseRequested));
-                        },
-                        winit::WindowEvent::Destroyed => {
-                            window_events.push(Event::new(WindowEvent::Destroyed));
-                        },
-                        winit::WindowEvent::DroppedFile(file_path) => {
-                            window_events.push(Event::new(WindowEvent::DroppedFile(file_path)));
-                        },
-                        winit::WindowEvent::HoveredFile(file_path) => {
-                            window_events.push(Event::new(WindowEvent::HoveredFile(file_path)));
-                        },
-                        winit::WindowEvent::HoveredFileCancelled => {
-                            window_events.push(Event::new(WindowEvent::HoveredFileCancelled));
-                        },
-                        winit::WindowEvent::Focused(received_focus) => {
-                            window_events.push(Event::new(WindowEvent::Focused(received_focus)));
-                        },
-                        winit::WindowEvent::Refresh => {
-                            window_events.push(Event::new(WindowEvent::Refresh));
-                        },
-                        winit::WindowEvent::HiDpiFactorChanged(dpi) => {
-                            window_events.push(Event::new(WindowEvent::HiDpiFactorChanged(dpi)));
-                        },
-
-                        // input events
-                        winit::WindowEvent::KeyboardInput { device_id: _, input } => {
-                            input_events.push(Event::new(InputEvent::Keyboard(
-                                KeyboardEvent::new(
-                                    input.scancode,
-                                    match input.state {
-                                        winit::ElementState::Pressed => ButtonState::Pressed,
-                                        winit::ElementState::Released => ButtonState::Released,
-                                    },
-                                    match input.virtual_keycode {
-                                        Some(winit_keycode) => Some(winit_keycode.into()),
-                                        None => None
-                                    },
-                                    input.modifiers.into()
-                                )
-                            )));
-                        },
-
-                        winit::WindowEvent::ReceivedCharacter(c) => {
-                            input_events.push(Event::new(InputEvent::ReceivedChar(c)));
-                        },
-
-                        winit::WindowEvent::MouseInput { device_id: _, state, button, modifiers } => {
-                            input_events.push(Event::new(InputEvent::MouseButton(
-                                MouseButtonEvent::new(
-                                    match state {
-                                        winit::ElementState::Pressed => ButtonState::Pressed,
-                                        winit::ElementState::Released => ButtonState::Released
-                                    },
-                                    match button {
-                                        winit::MouseButton::Left => MouseButton::Left,
-                                        winit::MouseButton::Right => MouseButton::Right,
-                                        winit::MouseButton::Middle => MouseButton::Middle,
-                                        winit::MouseButton::Other(id) => MouseButton::Other(id)
-                                    },
-                                    modifiers.into()
-                                )
-                            )));
-                        },
-
-                        winit::WindowEvent::MouseWheel { device_id: _, delta, phase, modifiers } => {
-                            input_events.push(Event::new(
-                                InputEvent::MouseWheel {
-                                    delta: match delta {
-                                        winit::MouseScrollDelta::LineDelta(x, y) => MouseScrollDelta::Line { horizontal: x, vertical: y },
-                                        winit::MouseScrollDelta::PixelDelta(value) => {
-                                            MouseScrollDelta::Pixel(value.into())
-                                        }
-                                    },
-                                    phase: phase.into(), 
-                                    modifiers: modifiers.into()
-                                }
-                            ));
-                        },
-
-                        winit::WindowEvent::CursorEntered { device_id: _ } => {
-                            input_events.push(Event::new(
-                                InputEvent::CursorEntered
-                            ));
-                        },
-
-                        winit::WindowEvent::CursorLeft { device_id: _ } => {
-                            input_events.push(Event::new(
-                                InputEvent::CursorLeft
-                            ));
-                        },
-
-                        winit::WindowEvent::CursorMoved { device_id: _, position, modifiers  } => {
-                            input_events.push(Event::new(
-                                InputEvent::CursorMoved {
-                                    position: position.into(),
-                                    modifiers: modifiers.into()
-                                },
-                            ));
-                        },
-
-                        winit::WindowEvent::TouchpadPressure { device_id: _, pressure, stage } => {
-                            input_events.push(Event::new(
-                                InputEvent::TouchpadPressure {
-                                    pressure,
-                                    stage
-                                }
-                            ));
-                        },
-
-                        winit::WindowEvent::Touch(touch) => {
-                            input_events.push(Event::new(
-                                InputEvent::Touch(TouchEvent::new(
-                                    touch.phase.into(),
-                                    touch.location.into(),
-                                    touch.id
-                                ))
-                            ));
-                        },
-
-                        winit::WindowEvent::AxisMotion { device_id: _, axis, value } => {
-                            input_events.push(Event::new(
-                                InputEvent::AxisMotion {
-                                    axis, 
-                                    value
-                                }
-                            ));
-                        }
-                    }
-                },
-                _ => ()
+        winit::event::WindowEvent::Resized(new_size) => {
+            WindowEvent::Resized(new_size.to_logical::<u32>(scale_factor).into()).into()
+        },
+        winit::event::WindowEvent::Moved(new_position) => {
+            WindowEvent::Moved(new_position.to_logical::<f64>(scale_factor).into()).into()
+        },
+        winit::event::WindowEvent::CloseRequested => WindowEvent::CloseRequested.into(),
+        winit::event::WindowEvent::Destroyed => WindowEvent::Destroyed.into(),
+        winit::event::WindowEvent::DroppedFile(file_path) => WindowEvent::DroppedFile(file_path).into(),
+        winit::event::WindowEvent::HoveredFile(file_path) => WindowEvent::HoveredFile(file_path).into(),
+        winit::event::WindowEvent::HoveredFileCancelled => WindowEvent::HoveredFileCancelled.into(),
+        winit::event::WindowEvent::Focused(received_focus) => WindowEvent::Focused(received_focus).into(),
+        winit::event::WindowEvent::ScaleFactorChanged { scale_factor: new_scale_factor, .. } => {
+            WindowEvent::HiDpiFactorChanged(new_scale_factor).into()
+        },
+
+        // input events
+
+        winit::event::WindowEvent::ModifiersChanged(_) => return None,
+        winit::event::WindowEvent::KeyboardInput { input, .. } => {
+            InputEvent::Keyboard(KeyboardEvent::new(
+                input.scancode,
+                input.state.into(),
+                input.virtual_keycode.map(KeyCode::from),
+                modifiers
+            ))
+            .into()
+        },
+        winit::event::WindowEvent::ReceivedCharacter(c) => InputEvent::ReceivedChar(c).into(),
+        winit::event::WindowEvent::MouseInput { state, button, .. } => {
+            InputEvent::MouseButton(MouseButtonEvent::new(
+                state.into(),
+                button.into(),
+                modifiers
+            ))
+            .into()
+        },
+        winit::event::WindowEvent::MouseWheel { delta, phase, .. } => {
+            InputEvent::MouseWheel {
+                delta: delta.into(),
+                phase: phase.into(),
+                modifiers
             }
-        });
-    }
-    */
+            .into()
+        },
+        winit::event::WindowEvent::CursorEntered { .. } => InputEvent::CursorEntered.into(),
+        winit::event::WindowEvent::CursorLeft { .. } => InputEvent::CursorLeft.into(),
+        winit::event::WindowEvent::CursorMoved { position, .. } => {
+            InputEvent::CursorMoved {
+                position: position.to_logical::<f64>(scale_factor).into(),
+                modifiers
+            }
+            .into()
+        },
+        winit::event::WindowEvent::TouchpadPressure { pressure, stage, .. } => {
+            InputEvent::TouchpadPressure {
+                pressure,
+                stage
+            }
+            .into()
+        },
+        winit::event::WindowEvent::Touch(touch) => {
+            InputEvent::Touch(TouchEvent::new(
+                touch.phase.into(),
+                touch.location.to_logical::<f64>(scale_factor).into(),
+                touch.id
+            ))
+            .into()
+        },
+        winit::event::WindowEvent::AxisMotion { axis, value, .. } => {
+            InputEvent::AxisMotion {
+                axis,
+                value
+            }
+            .into()
+        },
+        winit::event::WindowEvent::ThemeChanged(_) => return None
+    };
 
-    /*
-    fn redirect_input_events<T, H: InputEventsIndirectHandler<T>>(&mut self, handler: &mut H, listeners: Vec<T>) {
-        handler.handle_multiple(listeners, &mut self.input_events);
-        self.input_events.clear();
-    }
-    */
+    Some(backend_event)
 }
 
 /*
@@ -663,18 +597,45 @@ impl From<VirtualKeyCode> for KeyCode {
     }
 }
 
-/*
-impl From<winit::ModifiersState> for KeyModifiers {
-    fn from(modifiers: winit::ModifiersState) -> KeyModifiers {
+impl From<ModifiersState> for KeyModifiers {
+    fn from(modifiers: ModifiersState) -> KeyModifiers {
         KeyModifiers {
-            ctrl:    modifiers.ctrl,
-            shift:   modifiers.shift,
-            alt:     modifiers.alt,
-            command: modifiers.logo
+            ctrl:    modifiers.ctrl(),
+            shift:   modifiers.shift(),
+            alt:     modifiers.alt(),
+            command: modifiers.logo()
+        }
+    }
+}
+
+impl From<ElementState> for ButtonState {
+    fn from(state: ElementState) -> ButtonState {
+        match state {
+            ElementState::Pressed  => ButtonState::Pressed,
+            ElementState::Released => ButtonState::Released
+        }
+    }
+}
+
+impl From<winit::event::MouseButton> for MouseButton {
+    fn from(button: winit::event::MouseButton) -> MouseButton {
+        match button {
+            winit::event::MouseButton::Left      => MouseButton::Left,
+            winit::event::MouseButton::Right     => MouseButton::Right,
+            winit::event::MouseButton::Middle    => MouseButton::Middle,
+            winit::event::MouseButton::Other(id) => MouseButton::Other(id)
+        }
+    }
+}
+
+impl From<winit::event::MouseScrollDelta> for MouseScrollDelta {
+    fn from(delta: winit::event::MouseScrollDelta) -> MouseScrollDelta {
+        match delta {
+            winit::event::MouseScrollDelta::LineDelta(horizontal, vertical) => MouseScrollDelta::Line { horizontal, vertical },
+            winit::event::MouseScrollDelta::PixelDelta(value) => MouseScrollDelta::Pixel(value.into())
         }
     }
 }
-*/
 
 /*
 impl From<LogicalPosition<i32>> for Vector2<i32> {
@@ -685,6 +646,13 @@ impl From<LogicalPosition<i32>> for Vector2<i32> {
 }
 */
 
+impl From<LogicalPosition<f64>> for Vector2<f64> {
+    fn from(logical_pos: LogicalPosition<f64>) -> Vector2<f64> {
+        let decomposed_position: (f64, f64) = logical_pos.into();
+        Vector2::from(decomposed_position)
+    }
+}
+
 impl From<LogicalPosition<f64>> for Vector2<i32> {
     fn from(logical_pos: LogicalPosition<f64>) -> Vector2<i32> {
         let decomposed_position: (i32, i32) = logical_pos.into();
@@ -715,15 +683,129 @@ impl From<Size<u32>> for LogicalSize<f64> {
     }
 }
 
-/*
-impl From<winit::TouchPhase> for TouchPhase {
-    fn from(phase: winit::TouchPhase) -> TouchPhase {
+impl From<winit::event::TouchPhase> for TouchPhase {
+    fn from(phase: winit::event::TouchPhase) -> TouchPhase {
         match phase {
-            winit::TouchPhase::Started   => TouchPhase::Started,
-            winit::TouchPhase::Moved     => TouchPhase::Moved,
-            winit::TouchPhase::Ended     => TouchPhase::Ended,
-            winit::TouchPhase::Cancelled => TouchPhase::Cancelled
+            winit::event::TouchPhase::Started   => TouchPhase::Started,
+            winit::event::TouchPhase::Moved     => TouchPhase::Moved,
+            winit::event::TouchPhase::Ended     => TouchPhase::Ended,
+            winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled
         }
     }
 }
-*/
+
+#[cfg(test)]
+mod tests {
+    use winit::{
+        dpi::{
+            PhysicalPosition,
+            PhysicalSize
+        },
+        event::{
+            DeviceId,
+            KeyboardInput,
+            Touch
+        }
+    };
+
+    use super::*;
+
+    const SHIFT: KeyModifiers = KeyModifiers {
+        ctrl: false,
+        shift: true,
+        alt: false,
+        command: false
+    };
+
+    fn device_id() -> DeviceId {
+        unsafe { DeviceId::dummy() }
+    }
+
+    fn input_event(event: Option<BackendEvent>) -> InputEvent {
+        match event {
+            Some(BackendEvent::Input(event)) => event,
+            other => panic!("Expecting an input event, got {:?}.", other)
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn keyboard_input_is_stamped_with_tracked_modifiers() {
+        let event = winit::event::WindowEvent::KeyboardInput {
+            device_id: device_id(),
+            input: KeyboardInput {
+                scancode: 57,
+                state: ElementState::Pressed,
+                virtual_keycode: Some(VirtualKeyCode::Space),
+                modifiers: ModifiersState::empty()
+            },
+            is_synthetic: false
+        };
+
+        match input_event(translate_window_event(event, SHIFT, 1.0)) {
+            InputEvent::Keyboard(keyboard) => {
+                assert_eq!(57, keyboard.scan_code);
+                assert_eq!(ButtonState::Pressed, keyboard.state);
+                assert_eq!(Some(KeyCode::Space), keyboard.key);
+                assert_eq!(SHIFT, keyboard.modifiers);
+            },
+            other => panic!("Expecting a keyboard event, got {:?}.", other)
+        }
+    }
+
+    #[test]
+    fn modifiers_change_isnt_forwarded() {
+        let event = winit::event::WindowEvent::ModifiersChanged(ModifiersState::SHIFT);
+
+        assert_eq!(SHIFT, ModifiersState::SHIFT.into());
+        assert!(translate_window_event(event, KeyModifiers::default(), 1.0).is_none());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn positions_are_converted_to_logical() {
+        let event = winit::event::WindowEvent::CursorMoved {
+            device_id: device_id(),
+            position: PhysicalPosition::new(200.0, 100.0),
+            modifiers: ModifiersState::empty()
+        };
+
+        match input_event(translate_window_event(event, KeyModifiers::default(), 2.0)) {
+            InputEvent::CursorMoved { position, .. } => assert_eq!(Vector2::with(100.0, 50.0), position),
+            other => panic!("Expecting a cursor event, got {:?}.", other)
+        }
+
+        let event = winit::event::WindowEvent::Touch(Touch {
+            device_id: device_id(),
+            phase: winit::event::TouchPhase::Started,
+            location: PhysicalPosition::new(30.0, 60.0),
+            force: None,
+            id: 4
+        });
+
+        match input_event(translate_window_event(event, KeyModifiers::default(), 1.5)) {
+            InputEvent::Touch(touch) => {
+                assert_eq!(TouchPhase::Started, touch.phase());
+                assert_eq!(Vector2::with(20.0, 40.0), touch.position());
+                assert_eq!(4, touch.id());
+            },
+            other => panic!("Expecting a touch event, got {:?}.", other)
+        }
+    }
+
+    #[test]
+    fn window_events_are_translated() {
+        let resized = translate_window_event(winit::event::WindowEvent::Resized(PhysicalSize::new(800, 600)), KeyModifiers::default(), 2.0);
+        assert!(matches!(resized, Some(BackendEvent::Window(WindowEvent::Resized(size))) if size == Size::with(400, 300)));
+
+        let mut inner_size = PhysicalSize::new(800, 600);
+        let scale_changed = winit::event::WindowEvent::ScaleFactorChanged {
+            scale_factor: 2.0,
+            new_inner_size: &mut inner_size
+        };
+        assert!(matches!(translate_window_event(scale_changed, KeyModifiers::default(), 1.0), Some(BackendEvent::Window(WindowEvent::HiDpiFactorChanged(factor))) if factor == 2.0));
+
+        let close = translate_window_event(winit::event::WindowEvent::CloseRequested, KeyModifiers::default(), 1.0);
+        assert!(matches!(close, Some(BackendEvent::Window(WindowEvent::CloseRequested))));
+    }
+}
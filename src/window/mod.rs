@@ -3,5 +3,6 @@ pub mod backends;
 mod window_event;
 pub use window_event::WindowEvent;
 
+#[allow(clippy::module_inception)]
 mod window;
 pub use window::Window;
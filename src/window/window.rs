@@ -1,30 +1,18 @@
-use std::{
-    borrow::Borrow,
-    cell::{ RefCell, RefMut },
-    rc::{ Rc, Weak }
-};
-
+#[cfg(not(feature = "no-backend"))]
 use raw_window_handle::{
     HasRawWindowHandle,
     RawWindowHandle
 };
 
 use crate::{
-    core::{
-        ecs::Realm,
-        GameLoopInterface,
-        GameState,
-    },
+    core::GameLoopInterface,
     math::{
         Size
     },
-    window::{
-        backends::{
-            backend,
-            BackendEventLoop,
-            BackendInterface,
-            BackendWindow
-        }
+    window::backends::{
+        backend,
+        BackendInterface,
+        BackendWindow
     }
 };
 
@@ -44,6 +32,7 @@ impl<L: 'static + GameLoopInterface> Default for Window<L> {
     }
 }
 
+#[cfg(not(feature = "no-backend"))]
 unsafe impl<L: 'static + GameLoopInterface> HasRawWindowHandle for Window<L> {
     fn raw_window_handle(&self) -> RawWindowHandle {
         self.backend.window().raw_window_handle()
@@ -51,6 +40,7 @@ unsafe impl<L: 'static + GameLoopInterface> HasRawWindowHandle for Window<L> {
 }
 
 impl<L: 'static + GameLoopInterface> Window<L> {
+    #[allow(clippy::result_unit_err)]
     pub fn new<T: Into<String>>(title: T, size: Size<u32>) -> Result<Self, ()> {
         match backend::Backend::new(title, size) {
             Ok(backend) => Ok(
@@ -58,7 +48,7 @@ impl<L: 'static + GameLoopInterface> Window<L> {
                     backend,
                 }
             ),
-            Err(e) => Err(())
+            Err(_) => Err(())
         }
     }
 
@@ -66,6 +56,14 @@ impl<L: 'static + GameLoopInterface> Window<L> {
         self.backend.window().inner_size()
     }
 
+    pub fn backend(&self) -> &backend::Backend<L> {
+        &self.backend
+    }
+
+    pub fn backend_mut(&mut self) -> &mut backend::Backend<L> {
+        &mut self.backend
+    }
+
     pub fn event_loop(&mut self) -> <backend::Backend<L> as BackendInterface<L>>::EventLoop {
         self.backend.event_loop()
     }
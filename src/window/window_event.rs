@@ -7,7 +7,7 @@ use crate::{
     }
 };
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum WindowEvent {
     Resized(Size<u32>),
     Moved(Vector2<i32>),
@@ -34,9 +34,18 @@ fn main() {
                  .with_component(GraphicRendererComponent::new())
                  .build();
 
-            game.run(realm);
+            // there is no window to close, so it's bounded by frames
+            if let Err(e) = game.run_frames(&mut realm, 60) {
+                panic!("{}", e);
+            }
+
+            println!("Entities after run: {}", realm.storage().entity_count());
+
+            if let Some(renderer) = game.renderer() {
+                println!("Draw calls recorded: {}", renderer.get_backend().draw_calls().len());
+            }
         },
-        Err(e) => panic!(e)
+        Err(e) => panic!("{}", e)
     };
 
     println!("No backend test end!");